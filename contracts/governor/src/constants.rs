@@ -17,3 +17,15 @@ pub(crate) const MAX_GRACE_PERIOD: u32 = 7 * ONE_DAY_LEDGERS;
 pub(crate) const MIN_GRACE_PERIOD: u32 = ONE_DAY_LEDGERS;
 /// The minimum number of tokens required to create a proposal
 pub(crate) const MIN_VOTE_THRESHOLD: i128 = 1;
+/// The maximum length (in bytes) of the title of a proposal with off-chain content
+pub(crate) const MAX_TITLE_LENGTH: u32 = 256;
+/// The maximum length (in bytes) of a URI pointing to off-chain proposal content
+pub(crate) const MAX_URI_LENGTH: u32 = 256;
+/// The maximum number of tokens the treasury can track
+pub(crate) const MAX_TREASURY_TOKENS: u32 = 10;
+/// The maximum number of ledgers a council spending limit period can span (31 days)
+pub(crate) const MAX_SPENDING_PERIOD: u32 = 31 * ONE_DAY_LEDGERS;
+/// The maximum number of council spends of a token within a single spending limit period
+pub(crate) const MAX_SPENDS_PER_PERIOD: u32 = 50;
+/// The maximum number of members of a multi-sig council
+pub(crate) const MAX_COUNCIL_MEMBERS: u32 = 20;
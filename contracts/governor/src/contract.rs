@@ -1,18 +1,23 @@
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, unwrap::UnwrapOptimized, Address, Env, String,
+    contract, contractimpl, panic_with_error, token::TokenClient, unwrap::UnwrapOptimized, Address,
+    Bytes, BytesN, Env, String, Vec,
 };
 
 use crate::{
+    council,
     dependencies::VotesClient,
     errors::GovernorError,
     events::GovernorEvents,
     governor::Governor,
+    proposal_outcome::record_outcome,
     settings::require_valid_settings,
-    storage,
+    spending_limit, storage, treasury,
     types::{
-        GovernorSettings, Proposal, ProposalAction, ProposalConfig, ProposalData, ProposalStatus,
-        VoteCount,
+        CouncilAction, CouncilActionData, CouncilConfig, GovernorSettings, Proposal,
+        ProposalAction, ProposalConfig, ProposalContent, ProposalData, ProposalOutcome,
+        ProposalStatus, SpendingLimit, Stream, TreasuryBalance, VoteCount, VoteSettings,
     },
+    vote_count::quadratic_weight,
 };
 
 #[contract]
@@ -36,14 +41,30 @@ impl Governor for GovernorContract {
         storage::get_settings(&e)
     }
 
+    fn vote_settings(e: Env) -> VoteSettings {
+        storage::get_vote_settings(&e)
+    }
+
     fn council(e: Env) -> Address {
         storage::get_council_address(&e)
     }
 
+    fn council_config(e: Env) -> CouncilConfig {
+        CouncilConfig::load(&e)
+    }
+
     fn vote_token(e: Env) -> Address {
         storage::get_voter_token_address(&e)
     }
 
+    fn treasury_tokens(e: Env) -> Vec<Address> {
+        storage::get_treasury_tokens(&e)
+    }
+
+    fn treasury_balances(e: Env) -> Vec<TreasuryBalance> {
+        treasury::get_treasury_balances(&e)
+    }
+
     fn propose(
         e: Env,
         creator: Address,
@@ -52,66 +73,21 @@ impl Governor for GovernorContract {
         action: ProposalAction,
     ) -> u32 {
         creator.require_auth();
-        storage::extend_instance(&e);
-
-        if storage::has_open_proposal(&e, &creator) {
-            panic_with_error!(&e, GovernorError::ProposalAlreadyOpenError);
-        }
-
-        match action {
-            ProposalAction::Upgrade(_) => {
-                let council = storage::get_council_address(&e);
-                if creator != council {
-                    panic_with_error!(&e, GovernorError::UnauthorizedError);
-                }
-            }
-            _ => {}
-        };
-        let settings = storage::get_settings(&e);
-        let votes_client = VotesClient::new(&e, &storage::get_voter_token_address(&e));
-        let creater_votes = votes_client.get_votes(&creator);
-        if creater_votes < settings.proposal_threshold {
-            panic_with_error!(&e, GovernorError::InsufficientVotingUnitsError)
-        }
-
-        let proposal_config =
-            ProposalConfig::new(&e, title.clone(), description.clone(), action.clone());
-        let proposal_id = storage::get_next_proposal_id(&e);
-        let vote_start = match action {
-            // no vote delay for snapshot proposals as they cannot be executed
-            ProposalAction::Snapshot => e.ledger().sequence(),
-            // all other proposals have a vote delay
-            _ => e.ledger().sequence() + settings.vote_delay,
-        };
-        let vote_end = vote_start + settings.vote_period;
-        let proposal_data = ProposalData {
-            creator: creator.clone(),
-            vote_start,
-            vote_end,
-            eta: 0,
-            status: ProposalStatus::Open,
-            executable: proposal_config.is_executable(),
-        };
-        storage::set_next_proposal_id(&e, proposal_id + 1);
-
-        storage::create_proposal_config(&e, proposal_id, &proposal_config);
-        storage::create_proposal_data(&e, proposal_id, &proposal_data);
-        storage::create_proposal_vote_count(&e, proposal_id);
-        storage::create_open_proposal(&e, &creator);
-
-        votes_client.set_vote_sequence(&vote_start);
+        let proposal_config = ProposalConfig::new(&e, title, description, action);
+        create_proposal(&e, creator, proposal_config, None)
+    }
 
-        GovernorEvents::proposal_created(
-            &e,
-            proposal_id,
-            creator,
-            title,
-            description,
-            action,
-            vote_start,
-            vote_end,
-        );
-        proposal_id
+    fn propose_hashed(
+        e: Env,
+        creator: Address,
+        title: String,
+        uri: String,
+        content_hash: BytesN<32>,
+        action: ProposalAction,
+    ) -> u32 {
+        creator.require_auth();
+        let proposal_config = ProposalConfig::new_off_chain(&e, title, uri, action);
+        create_proposal(&e, creator, proposal_config, Some(content_hash))
     }
 
     fn get_proposal(e: Env, proposal_id: u32) -> Option<Proposal> {
@@ -128,6 +104,25 @@ impl Governor for GovernorContract {
         }
     }
 
+    fn verify_description(e: Env, proposal_id: u32, content: Bytes) -> bool {
+        // the content hash is removed with the proposal, so fall back to the recorded outcome
+        let content_hash = if storage::get_proposal_config(&e, proposal_id).is_some() {
+            storage::get_proposal_content_hash(&e, proposal_id)
+        } else {
+            match storage::get_proposal_outcome(&e, proposal_id)
+                .unwrap_or_else(|| panic_with_error!(&e, GovernorError::NonExistentProposalError))
+                .content
+            {
+                ProposalContent::OffChain(content_hash) => Some(content_hash),
+                ProposalContent::OnChain => None,
+            }
+        };
+        match content_hash {
+            Some(content_hash) => e.crypto().sha256(&content) == content_hash,
+            None => false,
+        }
+    }
+
     fn close(e: Env, proposal_id: u32) {
         storage::extend_instance(&e);
         let mut proposal_data = storage::get_proposal_data(&e, proposal_id)
@@ -149,8 +144,15 @@ impl Governor for GovernorContract {
             GovernorEvents::proposal_expired(&e, proposal_id);
         } else {
             // proposal closed in time. Check if it passed or failed.
+            let vote_settings = storage::get_vote_settings(&e);
             let votes_client = VotesClient::new(&e, &storage::get_voter_token_address(&e));
-            let total_vote_supply = votes_client.get_past_total_supply(&proposal_data.vote_start);
+            let total_vote_supply = if vote_settings.quadratic_voting {
+                vote_settings.quadratic_quorum_supply
+            } else if vote_settings.exclude_abstain_quorum {
+                votes_client.get_past_active_supply(&proposal_data.vote_start)
+            } else {
+                votes_client.get_past_total_supply(&proposal_data.vote_start)
+            };
 
             let passed_quorum = vote_count.is_over_quorum(
                 settings.quorum,
@@ -163,6 +165,7 @@ impl Governor for GovernorContract {
                 proposal_data.status = ProposalStatus::Successful;
                 if proposal_data.executable {
                     proposal_data.eta = e.ledger().sequence() + settings.timelock;
+                    treasury::add_queued_proposal(&e, proposal_id);
                 }
             } else {
                 proposal_data.status = ProposalStatus::Defeated;
@@ -171,6 +174,7 @@ impl Governor for GovernorContract {
 
         storage::set_proposal_data(&e, proposal_id, &proposal_data);
         storage::del_open_proposal(&e, &proposal_data.creator);
+        record_outcome(&e, proposal_id, &proposal_data);
         GovernorEvents::proposal_voting_closed(
             &e,
             proposal_id,
@@ -207,43 +211,47 @@ impl Governor for GovernorContract {
             GovernorEvents::proposal_executed(&e, proposal_id);
         }
         storage::set_proposal_data(&e, proposal_id, &proposal_data);
+        treasury::remove_queued_proposal(&e, proposal_id);
+        record_outcome(&e, proposal_id, &proposal_data);
+    }
+
+    fn amend(e: Env, proposal_id: u32, title: String, description: String, action: ProposalAction) {
+        let proposal_config = ProposalConfig::new(&e, title, description, action);
+        amend_proposal(&e, proposal_id, proposal_config, None);
+    }
+
+    fn amend_hashed(
+        e: Env,
+        proposal_id: u32,
+        title: String,
+        uri: String,
+        content_hash: BytesN<32>,
+        action: ProposalAction,
+    ) {
+        let proposal_config = ProposalConfig::new_off_chain(&e, title, uri, action);
+        amend_proposal(&e, proposal_id, proposal_config, Some(content_hash));
     }
 
     fn cancel(e: Env, from: Address, proposal_id: u32) {
         storage::extend_instance(&e);
         from.require_auth();
 
-        let mut proposal_data = storage::get_proposal_data(&e, proposal_id)
+        let proposal_data = storage::get_proposal_data(&e, proposal_id)
             .unwrap_or_else(|| panic_with_error!(&e, GovernorError::NonExistentProposalError));
 
         // require from to be the creator or the council
         if from != proposal_data.creator {
-            let council = storage::get_council_address(&e);
-            if from != council {
-                panic_with_error!(&e, GovernorError::UnauthorizedError);
-            } else {
-                // block the security council from canceling council proposals
-                let proposal_config =
-                    storage::get_proposal_config(&e, proposal_id).unwrap_optimized();
-                match proposal_config.action {
-                    ProposalAction::Council(_) => {
-                        panic_with_error!(&e, GovernorError::UnauthorizedError);
-                    }
-                    _ => {}
-                }
-            }
+            council::require_council(&e, &from);
+            require_not_council_proposal(&e, proposal_id);
         }
+        cancel_proposal(&e, proposal_id, proposal_data);
+    }
 
-        if proposal_data.status != ProposalStatus::Open {
-            panic_with_error!(&e, GovernorError::ProposalClosedError);
-        }
-        if proposal_data.vote_start <= e.ledger().sequence() {
-            panic_with_error!(&e, GovernorError::ProposalVotePeriodStartedError);
-        }
-        proposal_data.status = ProposalStatus::Canceled;
-        storage::set_proposal_data(&e, proposal_id, &proposal_data);
-        storage::del_open_proposal(&e, &proposal_data.creator);
-        GovernorEvents::proposal_canceled(&e, proposal_id);
+    fn veto(e: Env, from: Address, proposal_id: u32) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        council::require_council(&e, &from);
+        veto_proposal(&e, proposal_id);
     }
 
     fn vote(e: Env, voter: Address, proposal_id: u32, support: u32) {
@@ -264,12 +272,26 @@ impl Governor for GovernorContract {
             panic_with_error!(&e, GovernorError::AlreadyVotedError);
         }
 
-        let voter_power = VotesClient::new(&e, &storage::get_voter_token_address(&e))
-            .get_past_votes(&voter, &proposal_data.vote_start);
+        let votes_client = VotesClient::new(&e, &storage::get_voter_token_address(&e));
+        let voter_power = votes_client.get_past_votes(&voter, &proposal_data.vote_start);
         if voter_power <= 0 {
             panic_with_error!(&e, GovernorError::InsufficientVotingUnitsError);
         }
 
+        let vote_settings = storage::get_vote_settings(&e);
+        if vote_settings.vote_lock {
+            // lock the voter's balance until the proposal can no longer be executed
+            let settings = storage::get_settings(&e);
+            let lock_until = proposal_data.vote_end + settings.timelock + settings.grace_period;
+            votes_client.lock(&voter, &lock_until);
+        }
+
+        let voter_power = if vote_settings.quadratic_voting {
+            quadratic_weight(voter_power)
+        } else {
+            voter_power
+        };
+
         let mut vote_count = storage::get_proposal_vote_count(&e, proposal_id).unwrap_optimized();
         vote_count.add_vote(&e, support, voter_power);
 
@@ -286,4 +308,307 @@ impl Governor for GovernorContract {
     fn get_proposal_votes(e: Env, proposal_id: u32) -> Option<VoteCount> {
         storage::get_proposal_vote_count(&e, proposal_id)
     }
+
+    fn get_proposal_outcome(e: Env, proposal_id: u32) -> Option<ProposalOutcome> {
+        storage::get_proposal_outcome(&e, proposal_id)
+    }
+
+    fn withdraw_stream(e: Env, stream_id: u32) -> i128 {
+        storage::extend_instance(&e);
+        let mut stream = Stream::load(&e, stream_id);
+        stream.config.recipient.require_auth();
+
+        let amount = stream.withdrawable(&e);
+        if amount > 0 {
+            stream.withdrawn += amount;
+            storage::set_stream(&e, stream_id, &stream);
+            TokenClient::new(&e, &stream.config.token).transfer(
+                &e.current_contract_address(),
+                &stream.config.recipient,
+                &amount,
+            );
+            GovernorEvents::stream_withdrawn(&e, stream_id, stream.config.recipient, amount);
+        }
+        amount
+    }
+
+    fn get_stream(e: Env, stream_id: u32) -> Option<Stream> {
+        storage::get_stream(&e, stream_id)
+    }
+
+    fn council_spend(e: Env, token: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        let council = storage::get_council_address(&e);
+        council.require_auth();
+        council::require_council(&e, &council);
+        spend_as_council(&e, token, to, amount);
+    }
+
+    fn get_spending_limit(e: Env, token: Address) -> Option<SpendingLimit> {
+        storage::get_spending_limit(&e, &token)
+    }
+
+    fn get_council_spent(e: Env, token: Address) -> i128 {
+        match storage::get_spending_limit(&e, &token) {
+            Some(limit) => spending_limit::get_recent_spends(&e, &token, limit.period)
+                .iter()
+                .map(|spend| spend.amount)
+                .sum(),
+            None => 0,
+        }
+    }
+
+    fn council_submit(e: Env, member: Address, action: CouncilAction) -> u32 {
+        storage::extend_instance(&e);
+        member.require_auth();
+        let action_id = council::submit(&e, &member, action);
+        if let Some(action_data) = council::approve(&e, &member, action_id) {
+            execute_council_action(&e, action_id, action_data);
+        }
+        action_id
+    }
+
+    fn council_approve(e: Env, member: Address, action_id: u32) {
+        storage::extend_instance(&e);
+        member.require_auth();
+        if let Some(action_data) = council::approve(&e, &member, action_id) {
+            execute_council_action(&e, action_id, action_data);
+        }
+    }
+
+    fn get_council_action(e: Env, action_id: u32) -> Option<CouncilActionData> {
+        storage::get_council_action(&e, action_id)
+    }
+}
+
+/// Create a new proposal
+///
+/// Returns the id of the new proposal
+///
+/// ### Arguments
+/// * `creator` - The address of the account creating the proposal
+/// * `proposal_config` - The config of the proposal
+/// * `content_hash` - The hash of the description content if it is stored off-chain
+fn create_proposal(
+    e: &Env,
+    creator: Address,
+    proposal_config: ProposalConfig,
+    content_hash: Option<BytesN<32>>,
+) -> u32 {
+    storage::extend_instance(e);
+
+    if storage::has_open_proposal(e, &creator) {
+        panic_with_error!(e, GovernorError::ProposalAlreadyOpenError);
+    }
+    council::require_can_propose(e, &creator, &proposal_config.action);
+    store_new_proposal(e, creator, proposal_config, content_hash)
+}
+
+/// Store a new proposal for `creator` without checking if `creator` can create a proposal with `action`
+///
+/// Returns the id of the new proposal
+fn store_new_proposal(
+    e: &Env,
+    creator: Address,
+    proposal_config: ProposalConfig,
+    content_hash: Option<BytesN<32>>,
+) -> u32 {
+    let settings = storage::get_settings(e);
+    let votes_client = VotesClient::new(e, &storage::get_voter_token_address(e));
+    let creater_votes = votes_client.get_votes(&creator);
+    if creater_votes < settings.proposal_threshold {
+        panic_with_error!(e, GovernorError::InsufficientVotingUnitsError)
+    }
+
+    let proposal_id = storage::get_next_proposal_id(e);
+    let vote_start = match proposal_config.action {
+        // no vote delay for snapshot proposals as they cannot be executed
+        ProposalAction::Snapshot => e.ledger().sequence(),
+        // all other proposals have a vote delay
+        _ => e.ledger().sequence() + settings.vote_delay,
+    };
+    let vote_end = vote_start + settings.vote_period;
+    let proposal_data = ProposalData {
+        creator: creator.clone(),
+        vote_start,
+        vote_end,
+        eta: 0,
+        status: ProposalStatus::Open,
+        executable: proposal_config.is_executable(),
+    };
+    storage::set_next_proposal_id(e, proposal_id + 1);
+
+    storage::create_proposal_config(e, proposal_id, &proposal_config);
+    if let Some(ref content_hash) = content_hash {
+        storage::create_proposal_content_hash(e, proposal_id, content_hash);
+    }
+    storage::create_proposal_data(e, proposal_id, &proposal_data);
+    storage::create_proposal_vote_count(e, proposal_id);
+    storage::create_open_proposal(e, &creator);
+
+    votes_client.set_vote_sequence(&vote_start);
+
+    GovernorEvents::proposal_created(
+        e,
+        proposal_id,
+        creator,
+        proposal_config.title,
+        proposal_config.description,
+        proposal_config.action,
+        vote_start,
+        vote_end,
+        content_hash,
+    );
+    proposal_id
+}
+
+/// Amend a proposal that has not started voting
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+/// * `proposal_config` - The new config of the proposal
+/// * `content_hash` - The hash of the new description content if it is stored off-chain
+///
+/// ### Panics
+/// * If the proposal does not exist, is not open, or the vote period has started
+fn amend_proposal(
+    e: &Env,
+    proposal_id: u32,
+    proposal_config: ProposalConfig,
+    content_hash: Option<BytesN<32>>,
+) {
+    storage::extend_instance(e);
+    let mut proposal_data = storage::get_proposal_data(e, proposal_id)
+        .unwrap_or_else(|| panic_with_error!(e, GovernorError::NonExistentProposalError));
+    proposal_data.creator.require_auth();
+
+    if proposal_data.status != ProposalStatus::Open {
+        panic_with_error!(e, GovernorError::ProposalClosedError);
+    }
+    if proposal_data.vote_start <= e.ledger().sequence() {
+        panic_with_error!(e, GovernorError::ProposalVotePeriodStartedError);
+    }
+
+    council::require_can_propose(e, &proposal_data.creator, &proposal_config.action);
+    proposal_data.executable = proposal_config.is_executable();
+
+    storage::set_proposal_config(e, proposal_id, &proposal_config);
+    match content_hash {
+        Some(ref content_hash) => {
+            storage::create_proposal_content_hash(e, proposal_id, content_hash)
+        }
+        None => storage::del_proposal_content_hash(e, proposal_id),
+    }
+    storage::set_proposal_data(e, proposal_id, &proposal_data);
+    GovernorEvents::proposal_amended(e, proposal_id, proposal_config, content_hash);
+}
+
+/// Cancel a proposal that has not started voting
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+/// * `proposal_data` - The data of the proposal
+///
+/// ### Panics
+/// * If the proposal is not open or the vote period has started
+fn cancel_proposal(e: &Env, proposal_id: u32, mut proposal_data: ProposalData) {
+    if proposal_data.status != ProposalStatus::Open {
+        panic_with_error!(e, GovernorError::ProposalClosedError);
+    }
+    if proposal_data.vote_start <= e.ledger().sequence() {
+        panic_with_error!(e, GovernorError::ProposalVotePeriodStartedError);
+    }
+    proposal_data.status = ProposalStatus::Canceled;
+    storage::set_proposal_data(e, proposal_id, &proposal_data);
+    storage::del_open_proposal(e, &proposal_data.creator);
+    record_outcome(e, proposal_id, &proposal_data);
+    GovernorEvents::proposal_canceled(e, proposal_id);
+}
+
+/// Veto a proposal that has not been executed
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+///
+/// ### Panics
+/// * If the proposal does not exist
+/// * If the proposal is a `Council` proposal
+/// * If the proposal is not open or successful
+fn veto_proposal(e: &Env, proposal_id: u32) {
+    let mut proposal_data = storage::get_proposal_data(e, proposal_id)
+        .unwrap_or_else(|| panic_with_error!(e, GovernorError::NonExistentProposalError));
+    require_not_council_proposal(e, proposal_id);
+
+    let prev_status = proposal_data.status;
+    if prev_status != ProposalStatus::Open && prev_status != ProposalStatus::Successful {
+        panic_with_error!(e, GovernorError::ProposalClosedError);
+    }
+    proposal_data.status = ProposalStatus::Vetoed;
+    storage::set_proposal_data(e, proposal_id, &proposal_data);
+    if prev_status == ProposalStatus::Open {
+        storage::del_open_proposal(e, &proposal_data.creator);
+    } else {
+        treasury::remove_queued_proposal(e, proposal_id);
+    }
+    record_outcome(e, proposal_id, &proposal_data);
+    GovernorEvents::proposal_vetoed(e, proposal_id);
+}
+
+/// Block the council from using its powers against a `Council` or `CouncilMultisig` proposal
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+fn require_not_council_proposal(e: &Env, proposal_id: u32) {
+    let proposal_config = storage::get_proposal_config(e, proposal_id).unwrap_optimized();
+    if let ProposalAction::Council(_) | ProposalAction::CouncilMultisig(_) = proposal_config.action
+    {
+        panic_with_error!(e, GovernorError::UnauthorizedError);
+    }
+}
+
+/// Transfer tokens held by the governor on behalf of the council, within the council's spending limit
+///
+/// ### Arguments
+/// * `token` - The address of the token to spend
+/// * `to` - The address of the account receiving the tokens
+/// * `amount` - The amount of tokens to spend
+///
+/// ### Panics
+/// * If the amount is not positive
+/// * If the spend would exceed the spending limit for the token
+fn spend_as_council(e: &Env, token: Address, to: Address, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, GovernorError::NegativeAmountError);
+    }
+
+    spending_limit::record_spend(e, &token, amount);
+    TokenClient::new(e, &token).transfer(&e.current_contract_address(), &to, &amount);
+    GovernorEvents::council_spend(e, token, to, amount);
+}
+
+/// Execute a council action approved by a multi-sig council
+///
+/// ### Arguments
+/// * `action_id` - The id of the council action
+/// * `action_data` - The approved council action
+fn execute_council_action(e: &Env, action_id: u32, action_data: CouncilActionData) {
+    match action_data.action {
+        CouncilAction::Cancel(proposal_id) => {
+            let proposal_data = storage::get_proposal_data(e, proposal_id)
+                .unwrap_or_else(|| panic_with_error!(e, GovernorError::NonExistentProposalError));
+            require_not_council_proposal(e, proposal_id);
+            cancel_proposal(e, proposal_id, proposal_data);
+        }
+        CouncilAction::Veto(proposal_id) => veto_proposal(e, proposal_id),
+        CouncilAction::Upgrade(title, description, wasm_hash) => {
+            if storage::has_open_proposal(e, &action_data.proposer) {
+                panic_with_error!(e, GovernorError::ProposalAlreadyOpenError);
+            }
+            let proposal_config =
+                ProposalConfig::new(e, title, description, ProposalAction::Upgrade(wasm_hash));
+            store_new_proposal(e, action_data.proposer, proposal_config, None);
+        }
+        CouncilAction::Spend(token, to, amount) => spend_as_council(e, token, to, amount),
+    }
+    GovernorEvents::council_action_executed(e, action_id);
 }
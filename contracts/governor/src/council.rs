@@ -0,0 +1,156 @@
+use soroban_sdk::{panic_with_error, vec, Address, Env};
+
+use crate::{
+    constants::MAX_COUNCIL_MEMBERS,
+    errors::GovernorError,
+    events::GovernorEvents,
+    storage,
+    types::{CouncilAction, CouncilActionData, CouncilConfig, ProposalAction},
+};
+
+impl CouncilConfig {
+    /// Validate the council configuration
+    ///
+    /// ### Panics
+    /// * If the council has no members and a non-zero threshold
+    /// * If the council has more than the maximum number of members, or a member is included more than once
+    /// * If the council has members and the threshold is zero or greater than the number of members
+    pub fn require_valid(&self, e: &Env) {
+        if self.members.is_empty() {
+            if self.threshold != 0 {
+                panic_with_error!(e, GovernorError::InvalidProposalActionError);
+            }
+            return;
+        }
+        if self.members.len() > MAX_COUNCIL_MEMBERS
+            || self.threshold == 0
+            || self.threshold > self.members.len()
+        {
+            panic_with_error!(e, GovernorError::InvalidProposalActionError);
+        }
+        for (index, member) in self.members.iter().enumerate() {
+            if self.members.first_index_of(&member) != Some(index as u32) {
+                panic_with_error!(e, GovernorError::InvalidProposalActionError);
+            }
+        }
+    }
+
+    /// Load the current council configuration
+    pub fn load(e: &Env) -> CouncilConfig {
+        CouncilConfig {
+            address: storage::get_council_address(e),
+            members: storage::get_council_members(e),
+            threshold: storage::get_council_threshold(e),
+        }
+    }
+
+    /// Store the council configuration
+    pub fn store(&self, e: &Env) {
+        storage::set_council_address(e, &self.address);
+        storage::set_council_members(e, &self.members, self.threshold);
+    }
+}
+
+/// Check if the council is a multi-sig council
+pub fn is_multisig(e: &Env) -> bool {
+    !storage::get_council_members(e).is_empty()
+}
+
+/// Require that `from` can use a council power directly
+///
+/// ### Panics
+/// * If `from` is not the council address
+/// * If the council is a multi-sig council, as council powers require member approvals
+pub fn require_council(e: &Env, from: &Address) {
+    if *from != storage::get_council_address(e) || is_multisig(e) {
+        panic_with_error!(e, GovernorError::UnauthorizedError);
+    }
+}
+
+/// Require that `creator` can create a proposal with `action`
+///
+/// ### Panics
+/// * If the action is an upgrade and `creator` cannot use council powers directly
+pub fn require_can_propose(e: &Env, creator: &Address, action: &ProposalAction) {
+    if let ProposalAction::Upgrade(_) = action {
+        require_council(e, creator);
+    }
+}
+
+/// Submit a council action on behalf of a member of a multi-sig council. The action
+/// has no approvals once submitted.
+///
+/// Returns the id of the new council action
+///
+/// ### Arguments
+/// * `member` - The address of the member submitting the action
+/// * `action` - The council action
+///
+/// ### Panics
+/// * If `member` is not a member of the council
+pub fn submit(e: &Env, member: &Address, action: CouncilAction) -> u32 {
+    require_member(e, member);
+    let action_id = storage::get_next_council_action_id(e);
+    let action_data = CouncilActionData {
+        action: action.clone(),
+        proposer: member.clone(),
+        approvals: vec![e],
+        executed: false,
+    };
+    storage::set_next_council_action_id(e, action_id + 1);
+    storage::set_council_action(e, action_id, &action_data);
+    GovernorEvents::council_action_submitted(e, action_id, member.clone(), action);
+    action_id
+}
+
+/// Approve a council action on behalf of a member of a multi-sig council
+///
+/// Returns the council action if it has received enough approvals from current members
+/// to be executed. The action is marked as executed.
+///
+/// ### Arguments
+/// * `member` - The address of the member approving the action
+/// * `action_id` - The id of the council action
+///
+/// ### Panics
+/// * If `member` is not a member of the council
+/// * If the council action does not exist or was already executed
+/// * If `member` already approved the action
+pub fn approve(e: &Env, member: &Address, action_id: u32) -> Option<CouncilActionData> {
+    require_member(e, member);
+    let mut action_data = storage::get_council_action(e, action_id)
+        .unwrap_or_else(|| panic_with_error!(e, GovernorError::NonExistentCouncilActionError));
+    if action_data.executed {
+        panic_with_error!(e, GovernorError::CouncilActionClosedError);
+    }
+    if action_data.approvals.contains(member) {
+        panic_with_error!(e, GovernorError::AlreadyApprovedError);
+    }
+    action_data.approvals.push_back(member.clone());
+    GovernorEvents::council_action_approved(e, action_id, member.clone());
+
+    // only approvals from current members count towards the threshold
+    let members = storage::get_council_members(e);
+    let approvals = action_data
+        .approvals
+        .iter()
+        .filter(|approver| members.contains(approver))
+        .count() as u32;
+    let is_approved = approvals >= storage::get_council_threshold(e);
+    if is_approved {
+        action_data.executed = true;
+    }
+    storage::set_council_action(e, action_id, &action_data);
+    if is_approved {
+        Some(action_data)
+    } else {
+        None
+    }
+}
+
+/// Require that `member` is a member of the multi-sig council
+fn require_member(e: &Env, member: &Address) {
+    if !storage::get_council_members(e).contains(member) {
+        panic_with_error!(e, GovernorError::UnauthorizedError);
+    }
+}
@@ -29,4 +29,10 @@ pub enum GovernorError {
     ProposalAlreadyOpenError = 211,
     OutsideOfVotePeriodError = 212,
     InvalidProposalActionError = 213,
+    InvalidProposalContentError = 214,
+    NonExistentStreamError = 215,
+    SpendingLimitExceededError = 216,
+    NonExistentCouncilActionError = 217,
+    CouncilActionClosedError = 218,
+    AlreadyApprovedError = 219,
 }
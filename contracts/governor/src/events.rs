@@ -1,6 +1,6 @@
-use soroban_sdk::{Address, Env, String, Symbol};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol};
 
-use crate::types::{ProposalAction, VoteCount};
+use crate::types::{CouncilAction, ProposalAction, ProposalConfig, StreamConfig, VoteCount};
 
 pub struct GovernorEvents {}
 
@@ -10,8 +10,11 @@ impl GovernorEvents {
     /// Note: The size limit for an event is 8kB. Title and calldata must be within the limit
     /// to create the proposal.
     ///
+    /// If the proposal description is stored off-chain, `desc` is the URI of the description content
+    /// and `content_hash` is the sha256 hash of the description content. Otherwise, `content_hash` is None.
+    ///
     /// - topics - `["proposal_created", proposal_id: u32, proposer: Address]`
-    /// - data - `[title: String, desc: String, action: ProposalAction, vote_start: u32, vote_end: u32]`
+    /// - data - `[title: String, desc: String, action: ProposalAction, vote_start: u32, vote_end: u32, content_hash: Option<BytesN<32>>]`
     pub fn proposal_created(
         e: &Env,
         proposal_id: u32,
@@ -21,10 +24,33 @@ impl GovernorEvents {
         action: ProposalAction,
         vote_start: u32,
         vote_end: u32,
+        content_hash: Option<BytesN<32>>,
     ) {
         let topics = (Symbol::new(&e, "proposal_created"), proposal_id, proposer);
-        e.events()
-            .publish(topics, (title, desc, action, vote_start, vote_end));
+        e.events().publish(
+            topics,
+            (title, desc, action, vote_start, vote_end, content_hash),
+        );
+    }
+
+    /// Emitted when a proposal is amended by its creator
+    ///
+    /// Note: The size limit for an event is 8kB. The amended config must be within the limit
+    /// to amend the proposal.
+    ///
+    /// If the amended description is stored off-chain, `content_hash` is the sha256 hash of the
+    /// description content. Otherwise, `content_hash` is None.
+    ///
+    /// - topics - `["proposal_amended", proposal_id: u32]`
+    /// - data - `[config: ProposalConfig, content_hash: Option<BytesN<32>>]`
+    pub fn proposal_amended(
+        e: &Env,
+        proposal_id: u32,
+        config: ProposalConfig,
+        content_hash: Option<BytesN<32>>,
+    ) {
+        let topics = (Symbol::new(e, "proposal_amended"), proposal_id);
+        e.events().publish(topics, (config, content_hash));
     }
 
     /// Emitted when a proposal is canceled
@@ -74,6 +100,87 @@ impl GovernorEvents {
         e.events().publish(topics, ());
     }
 
+    /// Emitted when a payment stream is created
+    ///
+    /// - topics - `["stream_created", stream_id: u32]`
+    /// - data - `config: StreamConfig`
+    pub fn stream_created(e: &Env, stream_id: u32, config: StreamConfig) {
+        let topics = (Symbol::new(e, "stream_created"), stream_id);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when a payment stream is canceled
+    ///
+    /// - topics - `["stream_canceled", stream_id: u32]`
+    /// - data - Void
+    pub fn stream_canceled(e: &Env, stream_id: u32) {
+        let topics = (Symbol::new(e, "stream_canceled"), stream_id);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when tokens are withdrawn from a payment stream
+    ///
+    /// - topics - `["stream_withdrawn", stream_id: u32, recipient: Address]`
+    /// - data - `amount: i128`
+    pub fn stream_withdrawn(e: &Env, stream_id: u32, recipient: Address, amount: i128) {
+        let topics = (Symbol::new(e, "stream_withdrawn"), stream_id, recipient);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when the council spends tokens held by the governor
+    ///
+    /// - topics - `["council_spend", token: Address, to: Address]`
+    /// - data - `amount: i128`
+    pub fn council_spend(e: &Env, token: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, "council_spend"), token, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a proposal is vetoed by the council
+    ///
+    /// - topics - `["proposal_vetoed", proposal_id: u32]`
+    /// - data - Void
+    pub fn proposal_vetoed(e: &Env, proposal_id: u32) {
+        let topics = (Symbol::new(e, "proposal_vetoed"), proposal_id);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a member of a multi-sig council submits a council action
+    ///
+    /// - topics - `["council_action_submitted", action_id: u32, member: Address]`
+    /// - data - `action: CouncilAction`
+    pub fn council_action_submitted(
+        e: &Env,
+        action_id: u32,
+        member: Address,
+        action: CouncilAction,
+    ) {
+        let topics = (
+            Symbol::new(e, "council_action_submitted"),
+            action_id,
+            member,
+        );
+        e.events().publish(topics, action);
+    }
+
+    /// Emitted when a member of a multi-sig council approves a council action
+    ///
+    /// - topics - `["council_action_approved", action_id: u32, member: Address]`
+    /// - data - Void
+    pub fn council_action_approved(e: &Env, action_id: u32, member: Address) {
+        let topics = (Symbol::new(e, "council_action_approved"), action_id, member);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a council action is executed
+    ///
+    /// - topics - `["council_action_executed", action_id: u32]`
+    /// - data - Void
+    pub fn council_action_executed(e: &Env, action_id: u32) {
+        let topics = (Symbol::new(e, "council_action_executed"), action_id);
+        e.events().publish(topics, ());
+    }
+
     /// Emitted when a vote is cast
     ///
     /// - topics - `["vote_cast", proposal_id: u32, voter: Address]`
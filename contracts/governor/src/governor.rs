@@ -1,6 +1,9 @@
-use soroban_sdk::{contractclient, Address, Env, String};
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String, Vec};
 
-use crate::types::{GovernorSettings, Proposal, ProposalAction, VoteCount};
+use crate::types::{
+    CouncilAction, CouncilActionData, CouncilConfig, GovernorSettings, Proposal, ProposalAction,
+    ProposalOutcome, SpendingLimit, Stream, TreasuryBalance, VoteCount, VoteSettings,
+};
 
 #[contractclient(name = "GovernorClient")]
 pub trait Governor {
@@ -15,12 +18,28 @@ pub trait Governor {
     /// Get the current settings of the governor
     fn settings(e: Env) -> GovernorSettings;
 
+    /// Get the current vote settings of the governor
+    fn vote_settings(e: Env) -> VoteSettings;
+
     /// Get the address of the security council for the DAO
     fn council(e: Env) -> Address;
 
+    /// Get the configuration of the security council, including the members and threshold of a multi-sig council
+    fn council_config(e: Env) -> CouncilConfig;
+
     /// Get the address of the votes token contract
     fn vote_token(e: Env) -> Address;
 
+    /// Get the addresses of the tokens tracked by the treasury
+    fn treasury_tokens(e: Env) -> Vec<Address>;
+
+    /// Get the holdings of each token tracked by the treasury
+    ///
+    /// Returns the balance of each tracked token held by the governor, along with the amount of the
+    /// token that proposals queued for execution will transfer out of the governor. Outflows are decoded
+    /// from `transfer` calls from the governor, either invoked directly or authorized by a `Calldata` proposal.
+    fn treasury_balances(e: Env) -> Vec<TreasuryBalance>;
+
     /// Create a new proposal
     ///
     /// Returns the id of the new proposal
@@ -41,6 +60,29 @@ pub trait Governor {
         action: ProposalAction,
     ) -> u32;
 
+    /// Create a new proposal whose description content is stored off-chain
+    ///
+    /// Returns the id of the new proposal
+    ///
+    /// ### Arguments
+    /// * `creator` - The address of the account creating the proposal
+    /// * `title` - The title of the proposal
+    /// * `uri` - The URI where the description content of the proposal can be fetched
+    /// * `content_hash` - The sha256 hash of the description content
+    /// * `action` - The action the proposal will take if passed
+    ///
+    /// ### Panics
+    /// * If the title or URI exceed 256 bytes, or the URI is empty
+    /// * If the proposal is not created successfully
+    fn propose_hashed(
+        e: Env,
+        creator: Address,
+        title: String,
+        uri: String,
+        content_hash: BytesN<32>,
+        action: ProposalAction,
+    ) -> u32;
+
     /// Get a proposal by its id
     ///
     /// Returns None if the proposal does not exist
@@ -49,6 +91,20 @@ pub trait Governor {
     /// * `proposal_id` - The id of the proposal to get
     fn get_proposal(e: Env, proposal_id: u32) -> Option<Proposal>;
 
+    /// Verify the off-chain description content of a proposal against its content hash
+    ///
+    /// Returns true if the sha256 hash of `content` matches the content hash of the proposal, or false
+    /// if it does not match or the proposal description is stored on-chain. Once the proposal has been
+    /// removed, the content hash recorded in the proposal outcome is used.
+    ///
+    /// ### Arguments
+    /// * `proposal_id` - The id of the proposal
+    /// * `content` - The description content to verify
+    ///
+    /// ### Panics
+    /// * If the proposal_id is invalid
+    fn verify_description(e: Env, proposal_id: u32, content: Bytes) -> bool;
+
     /// Close the voting period for a proposal. Closing a proposal requires the quorum to be reached or the voting
     /// period to have ended. The proposal will be queued for execution if the quorum is reached and the vote passes.
     /// Otherwise, the proposal will be marked as failed.
@@ -71,6 +127,48 @@ pub trait Governor {
     /// * If the proposal is not ready to be executed
     fn execute(e: Env, proposal_id: u32);
 
+    /// Amend a proposal. Amending a proposal requires the proposal to not have opened for voting yet. The
+    /// amended proposal keeps its original voting period, and stores the new description on-chain.
+    ///
+    /// Requires auth from the proposal creator.
+    ///
+    /// ### Arguments
+    /// * `proposal_id` - The id of the proposal to amend
+    /// * `title` - The new title of the proposal
+    /// * `description` - The new description of the proposal
+    /// * `action` - The new action the proposal will take if passed
+    ///
+    /// ### Panics
+    /// * If the `proposal_id` is invalid
+    /// * If the proposal has already started voting
+    /// * If the new proposal config is invalid
+    fn amend(e: Env, proposal_id: u32, title: String, description: String, action: ProposalAction);
+
+    /// Amend a proposal and store its new description content off-chain. Amending a proposal requires
+    /// the proposal to not have opened for voting yet. The amended proposal keeps its original voting period.
+    ///
+    /// Requires auth from the proposal creator.
+    ///
+    /// ### Arguments
+    /// * `proposal_id` - The id of the proposal to amend
+    /// * `title` - The new title of the proposal
+    /// * `uri` - The URI where the new description content of the proposal can be fetched
+    /// * `content_hash` - The sha256 hash of the new description content
+    /// * `action` - The new action the proposal will take if passed
+    ///
+    /// ### Panics
+    /// * If the `proposal_id` is invalid
+    /// * If the proposal has already started voting
+    /// * If the new proposal config is invalid
+    fn amend_hashed(
+        e: Env,
+        proposal_id: u32,
+        title: String,
+        uri: String,
+        content_hash: BytesN<32>,
+        action: ProposalAction,
+    );
+
     /// Cancel a proposal. Canceling a proposal requires the proposal to not have opened for voting yet.
     ///
     /// ### Arguments
@@ -83,6 +181,19 @@ pub trait Governor {
     /// * If from did not authorize the cancel or does not have the ability to cancel the proposal
     fn cancel(e: Env, from: Address, proposal_id: u32);
 
+    /// Veto a proposal that has not been executed. The proposal is moved to the `Vetoed` status.
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the council
+    /// * `proposal_id` - The id of the proposal to veto
+    ///
+    /// ### Panics
+    /// * If `from` is not the council, or the council is a multi-sig council
+    /// * If the proposal_id is invalid
+    /// * If the proposal is a `Council` proposal
+    /// * If the proposal is not open or successful
+    fn veto(e: Env, from: Address, proposal_id: u32);
+
     /// Vote on a proposal with the voter's voting power at the time of the proposals voting checkpoint.
     ///
     /// ### Arguments
@@ -115,4 +226,101 @@ pub trait Governor {
     /// ### Arguments
     /// * `proposal_id` - The id of the proposal to get the vote count for
     fn get_proposal_votes(e: Env, proposal_id: u32) -> Option<VoteCount>;
+
+    /// Get the outcome of a proposal.
+    ///
+    /// The outcome is recorded in persistent storage when a proposal is closed, executed, canceled or
+    /// vetoed, and remains available after the proposal has been removed. If none of these have been
+    /// called for the proposal, returns None. Outcomes are kept for 120 days, and are bumped each time
+    /// they are read. An outcome that has not been read within this period is archived.
+    ///
+    /// ### Arguments
+    /// * `proposal_id` - The id of the proposal to get the outcome for
+    fn get_proposal_outcome(e: Env, proposal_id: u32) -> Option<ProposalOutcome>;
+
+    /// Withdraw all vested tokens from a payment stream to the recipient of the stream.
+    ///
+    /// Returns the amount of tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `stream_id` - The id of the stream to withdraw from
+    ///
+    /// ### Panics
+    /// * If the stream_id is invalid
+    /// * If the governor does not hold enough of the streamed token
+    fn withdraw_stream(e: Env, stream_id: u32) -> i128;
+
+    /// Get a payment stream.
+    ///
+    /// Returns the stream, or None if the stream does not exist
+    ///
+    /// ### Arguments
+    /// * `stream_id` - The id of the stream
+    fn get_stream(e: Env, stream_id: u32) -> Option<Stream>;
+
+    /// Transfer tokens held by the governor as the council, within the spending limit approved by the DAO.
+    /// A multi-sig council must spend through a `Spend` council action instead.
+    ///
+    /// ### Arguments
+    /// * `token` - The address of the token to spend
+    /// * `to` - The address of the account receiving the tokens
+    /// * `amount` - The amount of tokens to spend
+    ///
+    /// ### Panics
+    /// * If the caller is not the council, or the council is a multi-sig council
+    /// * If the amount is not positive
+    /// * If the council has no spending limit for the token
+    /// * If the spend would exceed the spending limit within its rolling window
+    fn council_spend(e: Env, token: Address, to: Address, amount: i128);
+
+    /// Get the council's spending limit for a token.
+    ///
+    /// Returns the spending limit, or None if the council cannot spend the token
+    ///
+    /// ### Arguments
+    /// * `token` - The address of the token
+    fn get_spending_limit(e: Env, token: Address) -> Option<SpendingLimit>;
+
+    /// Get the amount of a token the council has spent within the rolling window of its current spending limit.
+    ///
+    /// Returns zero if the council has no spending limit for the token
+    ///
+    /// ### Arguments
+    /// * `token` - The address of the token
+    fn get_council_spent(e: Env, token: Address) -> i128;
+
+    /// Submit a council action as a member of a multi-sig council. The submission is also an approval
+    /// from the member, and the action is executed once it has enough member approvals.
+    ///
+    /// Returns the id of the council action
+    ///
+    /// ### Arguments
+    /// * `member` - The address of the member submitting the action
+    /// * `action` - The council action
+    ///
+    /// ### Panics
+    /// * If `member` is not a member of the council
+    fn council_submit(e: Env, member: Address, action: CouncilAction) -> u32;
+
+    /// Approve a council action as a member of a multi-sig council. The action is executed once
+    /// it has enough approvals from current members.
+    ///
+    /// ### Arguments
+    /// * `member` - The address of the member approving the action
+    /// * `action_id` - The id of the council action
+    ///
+    /// ### Panics
+    /// * If `member` is not a member of the council
+    /// * If the council action does not exist or was already executed
+    /// * If `member` already approved the action
+    /// * If the approved action fails to execute
+    fn council_approve(e: Env, member: Address, action_id: u32);
+
+    /// Get a council action.
+    ///
+    /// Returns the council action, or None if it does not exist
+    ///
+    /// ### Arguments
+    /// * `action_id` - The id of the council action
+    fn get_council_action(e: Env, action_id: u32) -> Option<CouncilActionData>;
 }
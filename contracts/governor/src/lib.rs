@@ -5,13 +5,18 @@ extern crate std;
 
 pub mod constants;
 pub mod contract;
+pub mod council;
 pub mod dependencies;
 pub mod errors;
 pub mod events;
 pub mod governor;
 pub mod proposal_config;
+pub mod proposal_outcome;
 pub mod settings;
+pub mod spending_limit;
 pub mod storage;
+pub mod stream;
+pub mod treasury;
 pub mod types;
 pub mod vote_count;
 
@@ -4,10 +4,12 @@ use soroban_sdk::{
 };
 
 use crate::{
+    constants::{MAX_TITLE_LENGTH, MAX_URI_LENGTH},
     errors::GovernorError,
-    settings::require_valid_settings,
+    settings::{require_valid_quorum, require_valid_settings, require_valid_vote_settings},
     storage,
-    types::{Calldata, ProposalAction, ProposalConfig},
+    treasury::require_valid_tokens,
+    types::{Calldata, CouncilConfig, ProposalAction, ProposalConfig, Stream},
 };
 
 impl ProposalConfig {
@@ -24,9 +26,21 @@ impl ProposalConfig {
                     panic_with_error!(e, GovernorError::InvalidProposalActionError);
                 }
             }
-            ProposalAction::Settings(ref settings) => require_valid_settings(e, settings),
+            ProposalAction::Settings(ref settings) => {
+                require_valid_settings(e, settings);
+                require_valid_quorum(e, settings, &storage::get_vote_settings(e));
+            }
+            ProposalAction::VoteSettings(ref settings) => {
+                require_valid_vote_settings(e, settings);
+                require_valid_quorum(e, &storage::get_settings(e), settings);
+            }
             ProposalAction::Upgrade(_) => (),
             ProposalAction::Council(_) => (),
+            ProposalAction::CouncilMultisig(ref council) => council.require_valid(e),
+            ProposalAction::TreasuryTokens(ref tokens) => require_valid_tokens(e, tokens),
+            ProposalAction::Stream(ref config) => config.require_valid(e),
+            ProposalAction::CancelStream(_) => (),
+            ProposalAction::SpendingLimit(ref limit) => limit.require_valid(e),
             ProposalAction::Snapshot => (),
         }
 
@@ -37,6 +51,30 @@ impl ProposalConfig {
         }
     }
 
+    /// Creates a new proposal configuration whose description content is stored off-chain, and
+    /// performs light validation on the title, URI and action
+    ///
+    /// ### Arguments
+    /// * `title` - The title of the proposal
+    /// * `uri` - The URI where the description content of the proposal can be fetched
+    /// * `action` - The action the proposal will take if passed
+    ///
+    /// ### Panics
+    /// * If the title exceeds its maximum length
+    /// * If the URI is empty or exceeds its maximum length
+    /// * If the action is invalid
+    pub fn new_off_chain(
+        e: &Env,
+        title: String,
+        uri: String,
+        action: ProposalAction,
+    ) -> ProposalConfig {
+        if title.len() > MAX_TITLE_LENGTH || uri.len() == 0 || uri.len() > MAX_URI_LENGTH {
+            panic_with_error!(e, GovernorError::InvalidProposalContentError);
+        }
+        ProposalConfig::new(e, title, uri, action)
+    }
+
     /// Execute the proposal based on the configuration
     pub fn execute(&self, e: &Env) {
         match self.action {
@@ -50,13 +88,39 @@ impl ProposalConfig {
                 );
             }
             ProposalAction::Settings(ref settings) => {
+                // the vote settings may have changed since the proposal was created
+                require_valid_quorum(e, settings, &storage::get_vote_settings(e));
                 storage::set_settings(e, settings);
             }
+            ProposalAction::VoteSettings(ref settings) => {
+                require_valid_quorum(e, &storage::get_settings(e), settings);
+                storage::set_vote_settings(e, settings);
+            }
             ProposalAction::Upgrade(ref wasm_hash) => {
                 e.deployer().update_current_contract_wasm(wasm_hash.clone());
             }
             ProposalAction::Council(ref council) => {
-                storage::set_council_address(e, council);
+                CouncilConfig {
+                    address: council.clone(),
+                    members: vec![e],
+                    threshold: 0,
+                }
+                .store(e);
+            }
+            ProposalAction::CouncilMultisig(ref council) => {
+                council.store(e);
+            }
+            ProposalAction::TreasuryTokens(ref tokens) => {
+                storage::set_treasury_tokens(e, tokens);
+            }
+            ProposalAction::Stream(ref config) => {
+                Stream::create(e, config);
+            }
+            ProposalAction::CancelStream(stream_id) => {
+                Stream::load(e, stream_id).cancel(e);
+            }
+            ProposalAction::SpendingLimit(ref limit) => {
+                limit.store(e);
             }
             ProposalAction::Snapshot => {
                 panic_with_error!(e, GovernorError::InvalidProposalType)
@@ -0,0 +1,35 @@
+use soroban_sdk::{unwrap::UnwrapOptimized, xdr::ToXdr, Env};
+
+use crate::{
+    storage,
+    types::{ProposalContent, ProposalData, ProposalOutcome},
+};
+
+/// Record the outcome of a proposal in persistent storage based on its current data, such that
+/// the result of the proposal is available after the proposal itself has been removed. Must be called
+/// by every path that finalizes a proposal.
+///
+/// Expects the proposal config and vote count to exist for `proposal_id`.
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+/// * `proposal_data` - The data of the proposal
+pub fn record_outcome(e: &Env, proposal_id: u32, proposal_data: &ProposalData) {
+    let proposal_config = storage::get_proposal_config(e, proposal_id).unwrap_optimized();
+    let votes = storage::get_proposal_vote_count(e, proposal_id).unwrap_optimized();
+    let outcome = ProposalOutcome {
+        id: proposal_id,
+        action_hash: e.crypto().sha256(&proposal_config.action.to_xdr(e)),
+        votes,
+        status: proposal_data.status,
+        vote_start: proposal_data.vote_start,
+        vote_end: proposal_data.vote_end,
+        eta: proposal_data.eta,
+        ledger: e.ledger().sequence(),
+        content: match storage::get_proposal_content_hash(e, proposal_id) {
+            Some(content_hash) => ProposalContent::OffChain(content_hash),
+            None => ProposalContent::OnChain,
+        },
+    };
+    storage::set_proposal_outcome(e, proposal_id, &outcome);
+}
@@ -6,7 +6,7 @@ use crate::{
         MIN_VOTE_PERIOD, MIN_VOTE_THRESHOLD,
     },
     errors::GovernorError,
-    types::GovernorSettings,
+    types::{GovernorSettings, VoteSettings},
 };
 
 /// Set the governor settings and validate they fit within the maximums
@@ -42,6 +42,37 @@ pub fn require_valid_settings(e: &Env, settings: &GovernorSettings) {
     }
 }
 
+/// Validate the governor vote settings
+///
+/// ### Arguments
+/// * `settings` - The vote settings for the governor
+///
+/// ### Panics
+/// * If the quadratic_quorum_supply is negative
+pub fn require_valid_vote_settings(e: &Env, settings: &VoteSettings) {
+    if settings.quadratic_quorum_supply < 0 {
+        panic_with_error!(&e, GovernorError::InvalidSettingsError)
+    }
+}
+
+/// Validate the quorum the governor settings and vote settings create together. Must be checked
+/// whenever either of them is updated.
+///
+/// ### Arguments
+/// * `settings` - The settings for the governor
+/// * `vote_settings` - The vote settings for the governor
+///
+/// ### Panics
+/// * If quadratic voting is enabled and the quorum of the quadratic_quorum_supply rounds down to 0
+pub fn require_valid_quorum(e: &Env, settings: &GovernorSettings, vote_settings: &VoteSettings) {
+    if vote_settings.quadratic_voting
+        && vote_settings.quadratic_quorum_supply * settings.quorum as i128 / (BPS_SCALAR as i128)
+            == 0
+    {
+        panic_with_error!(&e, GovernorError::InvalidSettingsError)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::constants::{ONE_DAY_LEDGERS, ONE_HOUR_LEDGERS};
@@ -283,4 +314,106 @@ mod tests {
 
         require_valid_settings(&e, &settings);
     }
+
+    #[test]
+    fn test_require_valid_vote_settings_is_valid() {
+        let e = Env::default();
+        let settings = VoteSettings {
+            vote_lock: true,
+            exclude_abstain_quorum: true,
+            quadratic_voting: true,
+            quadratic_quorum_supply: 1_000_000,
+        };
+
+        require_valid_vote_settings(&e, &settings);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #200)")]
+    fn test_require_valid_vote_settings_invalid_quadratic_quorum_supply() {
+        let e = Env::default();
+        let settings = VoteSettings {
+            vote_lock: false,
+            exclude_abstain_quorum: false,
+            quadratic_voting: true,
+            quadratic_quorum_supply: -1,
+        };
+
+        require_valid_vote_settings(&e, &settings);
+    }
+
+    #[test]
+    fn test_require_valid_quorum_is_valid() {
+        let e = Env::default();
+        let settings = GovernorSettings {
+            proposal_threshold: 1_0000000,
+            vote_delay: ONE_DAY_LEDGERS,
+            vote_period: ONE_DAY_LEDGERS * 5,
+            timelock: ONE_DAY_LEDGERS,
+            grace_period: ONE_DAY_LEDGERS * 7,
+            quorum: 100,
+            counting_type: 2,
+            vote_threshold: 5100,
+        };
+        let mut vote_settings = VoteSettings {
+            vote_lock: false,
+            exclude_abstain_quorum: false,
+            quadratic_voting: true,
+            quadratic_quorum_supply: 100,
+        };
+        require_valid_quorum(&e, &settings, &vote_settings);
+
+        // the quadratic quorum supply is ignored without quadratic voting
+        vote_settings.quadratic_voting = false;
+        vote_settings.quadratic_quorum_supply = 0;
+        require_valid_quorum(&e, &settings, &vote_settings);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #200)")]
+    fn test_require_valid_quorum_quadratic_without_quorum_supply() {
+        let e = Env::default();
+        let settings = GovernorSettings {
+            proposal_threshold: 1_0000000,
+            vote_delay: ONE_DAY_LEDGERS,
+            vote_period: ONE_DAY_LEDGERS * 5,
+            timelock: ONE_DAY_LEDGERS,
+            grace_period: ONE_DAY_LEDGERS * 7,
+            quorum: 100,
+            counting_type: 2,
+            vote_threshold: 5100,
+        };
+        let vote_settings = VoteSettings {
+            vote_lock: false,
+            exclude_abstain_quorum: false,
+            quadratic_voting: true,
+            quadratic_quorum_supply: 0,
+        };
+
+        require_valid_quorum(&e, &settings, &vote_settings);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #200)")]
+    fn test_require_valid_quorum_quadratic_quorum_rounds_to_zero() {
+        let e = Env::default();
+        let settings = GovernorSettings {
+            proposal_threshold: 1_0000000,
+            vote_delay: ONE_DAY_LEDGERS,
+            vote_period: ONE_DAY_LEDGERS * 5,
+            timelock: ONE_DAY_LEDGERS,
+            grace_period: ONE_DAY_LEDGERS * 7,
+            quorum: 100,
+            counting_type: 2,
+            vote_threshold: 5100,
+        };
+        let vote_settings = VoteSettings {
+            vote_lock: false,
+            exclude_abstain_quorum: false,
+            quadratic_voting: true,
+            quadratic_quorum_supply: 99,
+        };
+
+        require_valid_quorum(&e, &settings, &vote_settings);
+    }
 }
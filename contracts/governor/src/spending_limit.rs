@@ -0,0 +1,79 @@
+use soroban_sdk::{panic_with_error, vec, Address, Env, Vec};
+
+use crate::{
+    constants::{MAX_SPENDING_PERIOD, MAX_SPENDS_PER_PERIOD},
+    errors::GovernorError,
+    storage,
+    types::{CouncilSpend, SpendingLimit},
+};
+
+impl SpendingLimit {
+    /// Validate the spending limit
+    ///
+    /// ### Panics
+    /// * If the token is the governor
+    /// * If the amount is negative
+    /// * If the period is zero or longer than the maximum spending period
+    pub fn require_valid(&self, e: &Env) {
+        if self.token == e.current_contract_address()
+            || self.amount < 0
+            || self.period == 0
+            || self.period > MAX_SPENDING_PERIOD
+        {
+            panic_with_error!(e, GovernorError::InvalidProposalActionError);
+        }
+    }
+
+    /// Store the spending limit, or remove the council's allowance for the token if the amount is zero
+    pub fn store(&self, e: &Env) {
+        if self.amount == 0 {
+            storage::del_spending_limit(e, &self.token);
+        } else {
+            storage::set_spending_limit(e, &self.token, self);
+        }
+    }
+}
+
+/// Get the council's spends of `token` within the rolling window of `period` ledgers ending at the
+/// current ledger
+///
+/// ### Arguments
+/// * `token` - The address of the token
+/// * `period` - The length (in ledgers) of the rolling window
+pub fn get_recent_spends(e: &Env, token: &Address, period: u32) -> Vec<CouncilSpend> {
+    let window_start = e.ledger().sequence().saturating_sub(period);
+    let mut recent: Vec<CouncilSpend> = vec![e];
+    for spend in storage::get_council_spends(e, token).iter() {
+        if spend.ledger > window_start {
+            recent.push_back(spend);
+        }
+    }
+    recent
+}
+
+/// Record a council spend of `amount` of `token` against the council's spending limit
+///
+/// ### Arguments
+/// * `token` - The address of the token
+/// * `amount` - The amount being spent
+///
+/// ### Panics
+/// * If the council has no spending limit for the token
+/// * If the spend would exceed the spending limit within the rolling window
+pub fn record_spend(e: &Env, token: &Address, amount: i128) {
+    let limit = storage::get_spending_limit(e, token)
+        .unwrap_or_else(|| panic_with_error!(e, GovernorError::SpendingLimitExceededError));
+    let mut spends = get_recent_spends(e, token, limit.period);
+    let mut spent = amount;
+    for spend in spends.iter() {
+        spent += spend.amount;
+    }
+    if spent > limit.amount || spends.len() >= MAX_SPENDS_PER_PERIOD {
+        panic_with_error!(e, GovernorError::SpendingLimitExceededError);
+    }
+    spends.push_back(CouncilSpend {
+        ledger: e.ledger().sequence(),
+        amount,
+    });
+    storage::set_council_spends(e, token, &spends);
+}
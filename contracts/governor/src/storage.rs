@@ -1,10 +1,14 @@
 use soroban_sdk::{
-    contracttype, unwrap::UnwrapOptimized, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+    contracttype, unwrap::UnwrapOptimized, vec, Address, BytesN, Env, IntoVal, Symbol, TryFromVal,
+    Val, Vec,
 };
 
 use crate::{
     constants::{MAX_PROPOSAL_LIFETIME, ONE_DAY_LEDGERS},
-    types::{GovernorSettings, ProposalConfig, ProposalData, VoteCount},
+    types::{
+        CouncilActionData, CouncilSpend, GovernorSettings, ProposalConfig, ProposalData,
+        ProposalOutcome, SpendingLimit, Stream, VoteCount, VoteSettings,
+    },
 };
 
 const VOTER_TOKEN_ADDRESS_KEY: &str = "Votes";
@@ -12,12 +16,31 @@ const SETTINGS_KEY: &str = "Settings";
 const IS_INIT_KEY: &str = "IsInit";
 const PROPOSAL_ID_KEY: &str = "PropId";
 const COUNCIL_KEY: &str = "Council";
+const COUNCIL_MEMBERS_KEY: &str = "CouncilMem";
+const COUNCIL_THRESHOLD_KEY: &str = "CouncilThr";
+const COUNCIL_ACTION_ID_KEY: &str = "CouncilId";
+const TREASURY_TOKENS_KEY: &str = "TrsyTokens";
+const VOTE_LOCK_KEY: &str = "VoteLock";
+const ABSTAIN_QUORUM_KEY: &str = "AbstainQrm";
+const QUADRATIC_KEY: &str = "Quadratic";
+const QUADRATIC_SUPPLY_KEY: &str = "QuadSupply";
+const QUEUED_KEY: &str = "Queued";
+const STREAM_ID_KEY: &str = "StreamId";
 
 // All stored data is used on a per proposal basis outside of the instance. Extend past the max possible
 // proposal lifetime to ensure all data is available after the proposal is concluced.
 const LEDGER_BUMP: u32 = 14 * ONE_DAY_LEDGERS + MAX_PROPOSAL_LIFETIME;
 const LEDGER_THRESHOLD: u32 = LEDGER_BUMP - 3 * ONE_DAY_LEDGERS;
 
+// Proposal outcomes are kept well past the lifetime of the proposal data. They are bumped when read,
+// and are archived if not read within the bump period.
+const OUTCOME_LEDGER_BUMP: u32 = 120 * ONE_DAY_LEDGERS;
+const OUTCOME_LEDGER_THRESHOLD: u32 = OUTCOME_LEDGER_BUMP - 10 * ONE_DAY_LEDGERS;
+
+// Streams are bumped for 120 days on each interaction, as they can outlive any proposal
+const STREAM_LEDGER_BUMP: u32 = 120 * ONE_DAY_LEDGERS;
+const STREAM_LEDGER_THRESHOLD: u32 = STREAM_LEDGER_BUMP - 10 * ONE_DAY_LEDGERS;
+
 //********** Storage Keys **********//
 
 // Key for storing Voter's decision
@@ -41,6 +64,18 @@ pub enum GovernorDataKey {
     Votes(u32),
     // A flag for an active proposal by a creator
     Open(Address),
+    // A map of proposal id to the proposal outcome
+    Outcome(u32),
+    // A map of stream id to the stream
+    Stream(u32),
+    // A map of token to the council's spending limit
+    SpendLimit(Address),
+    // A map of token to the council's recent spends
+    Spends(Address),
+    // A map of council action id to the council action
+    CouncilAct(u32),
+    // A map of proposal id to the hash of its off-chain description content
+    ContentHash(u32),
 }
 
 //********** Storage Utils **********//
@@ -138,6 +173,91 @@ pub fn get_council_address(e: &Env) -> Address {
         .unwrap_optimized()
 }
 
+/// Set the members of a multi-sig council and the number of approvals they require
+///
+/// ### Arguments
+/// * `members` - The addresses of the council members
+/// * `threshold` - The number of member approvals required to use a council power
+pub fn set_council_members(e: &Env, members: &Vec<Address>, threshold: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, COUNCIL_MEMBERS_KEY), members);
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, COUNCIL_THRESHOLD_KEY), &threshold);
+}
+
+/// Get the members of a multi-sig council, or an empty vec if the council has no members
+pub fn get_council_members(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Vec<Address>>(&Symbol::new(e, COUNCIL_MEMBERS_KEY))
+        .unwrap_or_else(|| vec![e])
+}
+
+/// Get the number of member approvals required to use a council power
+pub fn get_council_threshold(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u32>(&Symbol::new(e, COUNCIL_THRESHOLD_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the tokens tracked by the treasury
+///
+/// ### Arguments
+/// * `tokens` - The addresses of the tokens
+pub fn set_treasury_tokens(e: &Env, tokens: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, TREASURY_TOKENS_KEY), tokens);
+}
+
+/// Get the tokens tracked by the treasury
+pub fn get_treasury_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Vec<Address>>(&Symbol::new(e, TREASURY_TOKENS_KEY))
+        .unwrap_or_else(|| vec![e])
+}
+
+/// Set the governor vote settings. Each setting is stored under its own key.
+///
+/// ### Arguments
+/// * `settings` - The vote settings
+pub fn set_vote_settings(e: &Env, settings: &VoteSettings) {
+    let instance = e.storage().instance();
+    instance.set::<Symbol, bool>(&Symbol::new(e, VOTE_LOCK_KEY), &settings.vote_lock);
+    instance.set::<Symbol, bool>(
+        &Symbol::new(e, ABSTAIN_QUORUM_KEY),
+        &settings.exclude_abstain_quorum,
+    );
+    instance.set::<Symbol, bool>(&Symbol::new(e, QUADRATIC_KEY), &settings.quadratic_voting);
+    instance.set::<Symbol, i128>(
+        &Symbol::new(e, QUADRATIC_SUPPLY_KEY),
+        &settings.quadratic_quorum_supply,
+    );
+}
+
+/// Get the governor vote settings. Any setting that has not been set is disabled.
+pub fn get_vote_settings(e: &Env) -> VoteSettings {
+    let instance = e.storage().instance();
+    VoteSettings {
+        vote_lock: instance
+            .get::<Symbol, bool>(&Symbol::new(e, VOTE_LOCK_KEY))
+            .unwrap_or(false),
+        exclude_abstain_quorum: instance
+            .get::<Symbol, bool>(&Symbol::new(e, ABSTAIN_QUORUM_KEY))
+            .unwrap_or(false),
+        quadratic_voting: instance
+            .get::<Symbol, bool>(&Symbol::new(e, QUADRATIC_KEY))
+            .unwrap_or(false),
+        quadratic_quorum_supply: instance
+            .get::<Symbol, i128>(&Symbol::new(e, QUADRATIC_SUPPLY_KEY))
+            .unwrap_or(0),
+    }
+}
+
 /********** Persistent **********/
 
 /// Set the next proposal id and bump if necessary
@@ -160,6 +280,231 @@ pub fn get_next_proposal_id(e: &Env) -> u32 {
     get_persistent_default::<Symbol, u32>(&e, &key, 0_u32, LEDGER_THRESHOLD, LEDGER_BUMP)
 }
 
+/// Set the ids of the proposals queued for execution and bump if necessary
+///
+/// ### Arguments
+/// * `proposal_ids` - The ids of the queued proposals
+pub fn set_queued_proposals(e: &Env, proposal_ids: &Vec<u32>) {
+    let key = Symbol::new(e, QUEUED_KEY);
+    e.storage()
+        .persistent()
+        .set::<Symbol, Vec<u32>>(&key, proposal_ids);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the ids of the proposals queued for execution
+pub fn get_queued_proposals(e: &Env) -> Vec<u32> {
+    let key = Symbol::new(e, QUEUED_KEY);
+    get_persistent_default::<Symbol, Vec<u32>>(e, &key, vec![e], LEDGER_THRESHOLD, LEDGER_BUMP)
+}
+
+/// Get the outcome of the proposal at `proposal_id`
+///
+/// ### Arguments
+/// * `proposal_id` - The proposal id
+pub fn get_proposal_outcome(e: &Env, proposal_id: u32) -> Option<ProposalOutcome> {
+    let key = GovernorDataKey::Outcome(proposal_id);
+    get_persistent_default::<GovernorDataKey, Option<ProposalOutcome>>(
+        e,
+        &key,
+        None,
+        OUTCOME_LEDGER_THRESHOLD,
+        OUTCOME_LEDGER_BUMP,
+    )
+}
+
+/// Set the outcome of the proposal at `proposal_id` and bump it
+///
+/// ### Arguments
+/// * `proposal_id` - The proposal id
+/// * `outcome` - The outcome of the proposal
+pub fn set_proposal_outcome(e: &Env, proposal_id: u32, outcome: &ProposalOutcome) {
+    let key = GovernorDataKey::Outcome(proposal_id);
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, ProposalOutcome>(&key, outcome);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, OUTCOME_LEDGER_THRESHOLD, OUTCOME_LEDGER_BUMP);
+}
+
+/// Set the next stream id and bump if necessary
+///
+/// ### Arguments
+/// * `stream_id` - The new stream id
+pub fn set_next_stream_id(e: &Env, stream_id: u32) {
+    let key = Symbol::new(e, STREAM_ID_KEY);
+    e.storage()
+        .persistent()
+        .set::<Symbol, u32>(&key, &stream_id);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, STREAM_LEDGER_THRESHOLD, STREAM_LEDGER_BUMP);
+}
+
+/// Get the next stream id
+pub fn get_next_stream_id(e: &Env) -> u32 {
+    let key = Symbol::new(e, STREAM_ID_KEY);
+    get_persistent_default::<Symbol, u32>(
+        e,
+        &key,
+        0_u32,
+        STREAM_LEDGER_THRESHOLD,
+        STREAM_LEDGER_BUMP,
+    )
+}
+
+/// Get the stream at `stream_id`
+///
+/// ### Arguments
+/// * `stream_id` - The stream id
+pub fn get_stream(e: &Env, stream_id: u32) -> Option<Stream> {
+    let key = GovernorDataKey::Stream(stream_id);
+    get_persistent_default::<GovernorDataKey, Option<Stream>>(
+        e,
+        &key,
+        None,
+        STREAM_LEDGER_THRESHOLD,
+        STREAM_LEDGER_BUMP,
+    )
+}
+
+/// Set the stream at `stream_id` and bump it
+///
+/// ### Arguments
+/// * `stream_id` - The stream id
+/// * `stream` - The stream
+pub fn set_stream(e: &Env, stream_id: u32, stream: &Stream) {
+    let key = GovernorDataKey::Stream(stream_id);
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, Stream>(&key, stream);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, STREAM_LEDGER_THRESHOLD, STREAM_LEDGER_BUMP);
+}
+
+/// Get the council's spending limit for `token`
+///
+/// ### Arguments
+/// * `token` - The address of the token
+pub fn get_spending_limit(e: &Env, token: &Address) -> Option<SpendingLimit> {
+    let key = GovernorDataKey::SpendLimit(token.clone());
+    get_persistent_default::<GovernorDataKey, Option<SpendingLimit>>(
+        e,
+        &key,
+        None,
+        LEDGER_THRESHOLD,
+        LEDGER_BUMP,
+    )
+}
+
+/// Set the council's spending limit for `token` and bump it
+///
+/// ### Arguments
+/// * `token` - The address of the token
+/// * `limit` - The spending limit
+pub fn set_spending_limit(e: &Env, token: &Address, limit: &SpendingLimit) {
+    let key = GovernorDataKey::SpendLimit(token.clone());
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, SpendingLimit>(&key, limit);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Remove the council's spending limit for `token`
+///
+/// ### Arguments
+/// * `token` - The address of the token
+pub fn del_spending_limit(e: &Env, token: &Address) {
+    let key = GovernorDataKey::SpendLimit(token.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/// Get the council's recent spends of `token`
+///
+/// ### Arguments
+/// * `token` - The address of the token
+pub fn get_council_spends(e: &Env, token: &Address) -> Vec<CouncilSpend> {
+    let key = GovernorDataKey::Spends(token.clone());
+    get_persistent_default::<GovernorDataKey, Vec<CouncilSpend>>(
+        e,
+        &key,
+        vec![e],
+        LEDGER_THRESHOLD,
+        LEDGER_BUMP,
+    )
+}
+
+/// Set the council's recent spends of `token` and bump them
+///
+/// ### Arguments
+/// * `token` - The address of the token
+/// * `spends` - The recent spends
+pub fn set_council_spends(e: &Env, token: &Address, spends: &Vec<CouncilSpend>) {
+    let key = GovernorDataKey::Spends(token.clone());
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, Vec<CouncilSpend>>(&key, spends);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Set the next council action id and bump if necessary
+///
+/// ### Arguments
+/// * `action_id` - The new council action id
+pub fn set_next_council_action_id(e: &Env, action_id: u32) {
+    let key = Symbol::new(e, COUNCIL_ACTION_ID_KEY);
+    e.storage()
+        .persistent()
+        .set::<Symbol, u32>(&key, &action_id);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the next council action id
+pub fn get_next_council_action_id(e: &Env) -> u32 {
+    let key = Symbol::new(e, COUNCIL_ACTION_ID_KEY);
+    get_persistent_default::<Symbol, u32>(e, &key, 0_u32, LEDGER_THRESHOLD, LEDGER_BUMP)
+}
+
+/// Get the council action at `action_id`
+///
+/// ### Arguments
+/// * `action_id` - The council action id
+pub fn get_council_action(e: &Env, action_id: u32) -> Option<CouncilActionData> {
+    let key = GovernorDataKey::CouncilAct(action_id);
+    get_persistent_default::<GovernorDataKey, Option<CouncilActionData>>(
+        e,
+        &key,
+        None,
+        LEDGER_THRESHOLD,
+        LEDGER_BUMP,
+    )
+}
+
+/// Set the council action at `action_id` and bump it
+///
+/// ### Arguments
+/// * `action_id` - The council action id
+/// * `action_data` - The council action
+pub fn set_council_action(e: &Env, action_id: u32, action_data: &CouncilActionData) {
+    let key = GovernorDataKey::CouncilAct(action_id);
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, CouncilActionData>(&key, action_data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
 /********** Temporary **********/
 
 /***** Proposal Config *****/
@@ -190,6 +535,59 @@ pub fn create_proposal_config(e: &Env, proposal_id: u32, proposal_data: &Proposa
         .extend_ttl(&key, LEDGER_BUMP, LEDGER_BUMP);
 }
 
+/// Set the proposal config at `proposal_id`.
+///
+/// Does not perform a ledger ttl bump.
+///
+/// ### Arguments
+/// * `proposal_id` - The proposal id
+/// * `proposal_config` - The proposal config to store
+pub fn set_proposal_config(e: &Env, proposal_id: u32, proposal_config: &ProposalConfig) {
+    let key = GovernorDataKey::Config(proposal_id);
+    e.storage()
+        .temporary()
+        .set::<GovernorDataKey, ProposalConfig>(&key, proposal_config);
+}
+
+/***** Proposal Content Hash *****/
+
+/// Fetch the off-chain description content hash of the proposal at `proposal_id`
+///
+/// Returns None if the proposal description is stored on-chain
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal to fetch
+pub fn get_proposal_content_hash(e: &Env, proposal_id: u32) -> Option<BytesN<32>> {
+    let key = GovernorDataKey::ContentHash(proposal_id);
+    e.storage()
+        .temporary()
+        .get::<GovernorDataKey, BytesN<32>>(&key)
+}
+
+/// Create the off-chain description content hash at `proposal_id` and bump it for the life of the proposal.
+///
+/// ### Arguments
+/// * `proposal_id` - The proposal id
+/// * `content_hash` - The sha256 hash of the description content
+pub fn create_proposal_content_hash(e: &Env, proposal_id: u32, content_hash: &BytesN<32>) {
+    let key = GovernorDataKey::ContentHash(proposal_id);
+    e.storage()
+        .temporary()
+        .set::<GovernorDataKey, BytesN<32>>(&key, content_hash);
+    e.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_BUMP, LEDGER_BUMP);
+}
+
+/// Delete the off-chain description content hash at `proposal_id`
+///
+/// ### Arguments
+/// * `proposal_id` - The proposal id
+pub fn del_proposal_content_hash(e: &Env, proposal_id: u32) {
+    let key = GovernorDataKey::ContentHash(proposal_id);
+    e.storage().temporary().remove(&key);
+}
+
 /***** Proposal Data *****/
 
 // Get the proposal data for proposal at `proposal_id`
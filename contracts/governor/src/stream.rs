@@ -0,0 +1,94 @@
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::{
+    errors::GovernorError,
+    events::GovernorEvents,
+    storage,
+    types::{Stream, StreamConfig},
+};
+
+impl StreamConfig {
+    /// Validate the stream configuration
+    ///
+    /// ### Panics
+    /// * If the token or recipient is the governor
+    /// * If the amount per ledger is not positive
+    /// * If the stream does not end after it starts
+    /// * If the total amount of the stream overflows
+    pub fn require_valid(&self, e: &Env) {
+        if self.token == e.current_contract_address()
+            || self.recipient == e.current_contract_address()
+            || self.amount_per_ledger <= 0
+            || self.end_ledger <= self.start_ledger
+            || self
+                .amount_per_ledger
+                .checked_mul((self.end_ledger - self.start_ledger) as i128)
+                .is_none()
+        {
+            panic_with_error!(e, GovernorError::InvalidProposalActionError);
+        }
+    }
+}
+
+impl Stream {
+    /// Create a new stream from `config` and store it
+    ///
+    /// Returns the id of the new stream
+    ///
+    /// ### Arguments
+    /// * `config` - The configuration of the stream
+    pub fn create(e: &Env, config: &StreamConfig) -> u32 {
+        let stream_id = storage::get_next_stream_id(e);
+        let stream = Stream {
+            id: stream_id,
+            config: config.clone(),
+            withdrawn: 0,
+            canceled: false,
+        };
+        storage::set_next_stream_id(e, stream_id + 1);
+        storage::set_stream(e, stream_id, &stream);
+        GovernorEvents::stream_created(e, stream_id, config.clone());
+        stream_id
+    }
+
+    /// Load the stream at `stream_id`
+    ///
+    /// ### Panics
+    /// * If the stream does not exist
+    pub fn load(e: &Env, stream_id: u32) -> Stream {
+        match storage::get_stream(e, stream_id) {
+            Some(stream) => stream,
+            None => panic_with_error!(e, GovernorError::NonExistentStreamError),
+        }
+    }
+
+    /// Get the total amount of tokens vested to the recipient as of the current ledger
+    pub fn vested(&self, e: &Env) -> i128 {
+        let sequence = e.ledger().sequence();
+        if sequence <= self.config.start_ledger {
+            return 0;
+        }
+        let end = sequence.min(self.config.end_ledger);
+        self.config.amount_per_ledger * (end - self.config.start_ledger) as i128
+    }
+
+    /// Get the amount of vested tokens the recipient has not withdrawn yet
+    pub fn withdrawable(&self, e: &Env) -> i128 {
+        self.vested(e) - self.withdrawn
+    }
+
+    /// Cancel the stream. Tokens vested before the current ledger remain withdrawable by the recipient,
+    /// and the unvested tokens stay with the governor.
+    pub fn cancel(&mut self, e: &Env) {
+        if self.canceled {
+            return;
+        }
+        let sequence = e.ledger().sequence();
+        if sequence < self.config.end_ledger {
+            self.config.end_ledger = sequence.max(self.config.start_ledger);
+        }
+        self.canceled = true;
+        storage::set_stream(e, self.id, self);
+        GovernorEvents::stream_canceled(e, self.id);
+    }
+}
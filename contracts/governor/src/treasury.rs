@@ -0,0 +1,171 @@
+use soroban_sdk::{
+    panic_with_error, token::TokenClient, vec, Address, Env, Symbol, TryFromVal, Vec,
+};
+
+use crate::{
+    constants::MAX_TREASURY_TOKENS,
+    errors::GovernorError,
+    storage,
+    types::{Calldata, ProposalAction, ProposalStatus, TreasuryBalance},
+};
+
+/// Validate a list of tokens to be tracked by the treasury
+///
+/// ### Arguments
+/// * `tokens` - The addresses of the tokens
+///
+/// ### Panics
+/// * If more than the maximum number of tokens are included
+/// * If a token is included more than once, or is the governor itself
+/// * If a token does not return a balance for the governor
+pub fn require_valid_tokens(e: &Env, tokens: &Vec<Address>) {
+    if tokens.len() > MAX_TREASURY_TOKENS {
+        panic_with_error!(e, GovernorError::InvalidProposalActionError);
+    }
+    let governor = e.current_contract_address();
+    for (index, token) in tokens.iter().enumerate() {
+        if token == governor
+            || tokens.first_index_of(&token) != Some(index as u32)
+            || try_get_balance(e, &token, &governor).is_none()
+        {
+            panic_with_error!(e, GovernorError::InvalidProposalActionError);
+        }
+    }
+}
+
+/// Add a proposal to the list of proposals queued for execution, and prune any proposals that
+/// can no longer be executed
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+pub fn add_queued_proposal(e: &Env, proposal_id: u32) {
+    let mut queued = prune_queued_proposals(e);
+    queued.push_back(proposal_id);
+    storage::set_queued_proposals(e, &queued);
+}
+
+/// Remove a proposal from the list of proposals queued for execution, and prune any proposals that
+/// can no longer be executed
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+pub fn remove_queued_proposal(e: &Env, proposal_id: u32) {
+    let mut queued = prune_queued_proposals(e);
+    if let Some(index) = queued.first_index_of(proposal_id) {
+        queued.remove(index);
+    }
+    storage::set_queued_proposals(e, &queued);
+}
+
+/// Get the holdings of each token tracked by the treasury, including the amount of each token
+/// that queued proposals will transfer out of the governor. Tokens that fail to return a balance
+/// are skipped.
+pub fn get_treasury_balances(e: &Env) -> Vec<TreasuryBalance> {
+    let tokens = storage::get_treasury_tokens(e);
+    let governor = e.current_contract_address();
+    let mut balances: Vec<TreasuryBalance> = vec![e];
+    for token in tokens.iter() {
+        if let Some(balance) = try_get_balance(e, &token, &governor) {
+            balances.push_back(TreasuryBalance {
+                token,
+                balance,
+                pending_outflow: 0,
+            });
+        }
+    }
+    if balances.is_empty() {
+        return balances;
+    }
+
+    let grace_period = storage::get_settings(e).grace_period;
+    for proposal_id in storage::get_queued_proposals(e).iter() {
+        if !is_executable(e, proposal_id, grace_period) {
+            continue;
+        }
+        if let Some(config) = storage::get_proposal_config(e, proposal_id) {
+            if let ProposalAction::Calldata(calldata) = config.action {
+                add_outflows(e, &governor, &calldata, &mut balances);
+            }
+        }
+    }
+    balances
+}
+
+/// Get the list of queued proposals with any proposals that can no longer be executed removed
+fn prune_queued_proposals(e: &Env) -> Vec<u32> {
+    let grace_period = storage::get_settings(e).grace_period;
+    let mut pruned: Vec<u32> = vec![e];
+    for proposal_id in storage::get_queued_proposals(e).iter() {
+        if is_executable(e, proposal_id, grace_period) {
+            pruned.push_back(proposal_id);
+        }
+    }
+    pruned
+}
+
+/// Check if a queued proposal can still be executed
+///
+/// ### Arguments
+/// * `proposal_id` - The id of the proposal
+/// * `grace_period` - The grace period of the governor
+fn is_executable(e: &Env, proposal_id: u32, grace_period: u32) -> bool {
+    match storage::get_proposal_data(e, proposal_id) {
+        Some(data) => {
+            data.status == ProposalStatus::Successful
+                && e.ledger().sequence() <= data.eta + grace_period
+        }
+        None => false,
+    }
+}
+
+/// Get the balance of `id` for `token`
+///
+/// Returns None if `token` is not a token contract, or fails to return a balance
+fn try_get_balance(e: &Env, token: &Address, id: &Address) -> Option<i128> {
+    match TokenClient::new(e, token).try_balance(id) {
+        Ok(Ok(balance)) => Some(balance),
+        _ => None,
+    }
+}
+
+/// Add the amount of tracked tokens transferred from the governor by `calldata`, or by any
+/// invocation the governor authorizes for it, to the pending outflows of `balances`
+fn add_outflows(
+    e: &Env,
+    governor: &Address,
+    calldata: &Calldata,
+    balances: &mut Vec<TreasuryBalance>,
+) {
+    if let Some(amount) = decode_transfer(e, governor, calldata) {
+        for index in 0..balances.len() {
+            let mut balance = balances.get_unchecked(index);
+            if balance.token == calldata.contract_id {
+                balance.pending_outflow += amount;
+                balances.set(index, balance);
+                break;
+            }
+        }
+    }
+    for auth in calldata.auths.iter() {
+        add_outflows(e, governor, &auth, balances);
+    }
+}
+
+/// Decode the amount of a SEP-41 `transfer` from the governor
+///
+/// Returns None if `calldata` is not a transfer from the governor
+fn decode_transfer(e: &Env, governor: &Address, calldata: &Calldata) -> Option<i128> {
+    if calldata.function != Symbol::new(e, "transfer") || calldata.args.len() != 3 {
+        return None;
+    }
+    let from = Address::try_from_val(e, &calldata.args.get_unchecked(0)).ok()?;
+    if from != *governor {
+        return None;
+    }
+    let amount = i128::try_from_val(e, &calldata.args.get_unchecked(2)).ok()?;
+    if amount > 0 {
+        Some(amount)
+    } else {
+        None
+    }
+}
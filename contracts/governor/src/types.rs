@@ -27,6 +27,27 @@ pub struct GovernorSettings {
     pub vote_threshold: u32,
 }
 
+/// Optional vote counting settings for the governor. Each setting is stored separately from the
+/// `GovernorSettings`, and defaults to disabled if it has never been set.
+#[derive(Clone)]
+#[contracttype]
+pub struct VoteSettings {
+    /// Whether to lock the voting token balance of each voter until the proposal's grace period ends. This
+    /// prevents a voter from transferring or withdrawing their tokens while the proposal can still be executed.
+    pub vote_lock: bool,
+    /// Whether to exclude the balances of accounts that abstain from delegating from the supply quorum is
+    /// measured against. Accounts that have never delegated are self delegated, so their balances still count.
+    /// Has no effect if quadratic voting is enabled.
+    pub exclude_abstain_quorum: bool,
+    /// Whether to count each vote with the integer square root of the voter's votes. When enabled,
+    /// quorum is measured against the quadratic quorum supply.
+    pub quadratic_voting: bool,
+    /// The supply to measure quorum against when quadratic voting is enabled, such as the sum of the
+    /// integer square roots of the expected voters' votes. If quadratic voting is enabled, the quorum
+    /// of this supply must be at least 1 vote.
+    pub quadratic_quorum_supply: i128,
+}
+
 /// Object for storing call data
 #[derive(Clone)]
 #[contracttype]
@@ -51,6 +72,7 @@ pub struct Proposal {
 #[contracttype]
 pub struct ProposalConfig {
     pub title: String,
+    /// The description of the proposal, or a URI to the description if the content is stored off-chain
     pub description: String,
     pub action: ProposalAction,
 }
@@ -66,8 +88,26 @@ pub struct ProposalConfig {
 /// ### Settings
 /// The proposal will update the governor settings on execute.
 ///
+/// ### VoteSettings
+/// The proposal will update the governor vote settings on execute.
+///
 /// ### Council
-/// The proposal will update the council address on execute.
+/// The proposal will update the council address on execute, and remove any council members.
+///
+/// ### CouncilMultisig
+/// The proposal will update the council address, members and threshold on execute.
+///
+/// ### TreasuryTokens
+/// The proposal will update the list of tokens tracked by the treasury on execute.
+///
+/// ### Stream
+/// The proposal will create a payment stream from the governor on execute.
+///
+/// ### CancelStream
+/// The proposal will cancel the payment stream with the given id on execute.
+///
+/// ### SpendingLimit
+/// The proposal will set the council's spending limit for a token on execute.
 ///
 /// ### Snapshot
 /// There is no action to be taken by the proposal.
@@ -77,7 +117,13 @@ pub enum ProposalAction {
     Calldata(Calldata),
     Upgrade(BytesN<32>),
     Settings(GovernorSettings),
+    VoteSettings(VoteSettings),
     Council(Address),
+    CouncilMultisig(CouncilConfig),
+    TreasuryTokens(Vec<Address>),
+    Stream(StreamConfig),
+    CancelStream(u32),
+    SpendingLimit(SpendingLimit),
     Snapshot,
 }
 
@@ -99,6 +145,160 @@ pub struct ProposalData {
     pub executable: bool,
 }
 
+/// Where the description content of a proposal is stored.
+///
+/// ### OnChain
+/// The full description is stored in the proposal config.
+///
+/// ### OffChain
+/// The description is stored off-chain at the URI stored in the proposal config, and can be
+/// verified against the sha256 hash of the content.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ProposalContent {
+    OnChain,
+    OffChain(BytesN<32>),
+}
+
+/// A compact record of the outcome of a proposal that outlives the proposal itself
+#[derive(Clone)]
+#[contracttype]
+pub struct ProposalOutcome {
+    /// The id of the proposal
+    pub id: u32,
+    /// The sha256 hash of the XDR encoded proposal action
+    pub action_hash: BytesN<32>,
+    /// The final vote count of the proposal
+    pub votes: VoteCount,
+    /// The status of the proposal when the outcome was recorded
+    pub status: ProposalStatus,
+    /// The ledger sequence when the voting period began
+    pub vote_start: u32,
+    /// The ledger sequence when the voting period ended
+    pub vote_end: u32,
+    /// The ledger sequence when the proposal was scheduled to be executed, or zero if no execution was scheduled
+    pub eta: u32,
+    /// The ledger sequence when the outcome was recorded
+    pub ledger: u32,
+    /// Where the description content of the proposal was stored
+    pub content: ProposalContent,
+}
+
+/// The configuration of the security council
+#[derive(Clone)]
+#[contracttype]
+pub struct CouncilConfig {
+    /// The address of the council. Used directly for council powers if the council has no members.
+    pub address: Address,
+    /// The members of a multi-sig council. If not empty, council powers require `threshold` member approvals.
+    pub members: Vec<Address>,
+    /// The number of member approvals required to use a council power, or zero if the council has no members
+    pub threshold: u32,
+}
+
+/// A council power that requires member approvals when the council is a multi-sig council
+///
+/// ### Cancel
+/// Cancel the proposal with the given id.
+///
+/// ### Veto
+/// Veto the proposal with the given id.
+///
+/// ### Upgrade
+/// Create an `Upgrade` proposal with the given title, description and WASM hash. The member that
+/// submitted the action is used as the creator of the proposal.
+///
+/// ### Spend
+/// Transfer the given amount of a token held by the governor to an address, within the council's
+/// spending limit for the token. The arguments are the token, the recipient and the amount.
+#[derive(Clone)]
+#[contracttype]
+pub enum CouncilAction {
+    Cancel(u32),
+    Veto(u32),
+    Upgrade(String, String, BytesN<32>),
+    Spend(Address, Address, i128),
+}
+
+/// A council action awaiting member approvals
+#[derive(Clone)]
+#[contracttype]
+pub struct CouncilActionData {
+    /// The action to take once approved
+    pub action: CouncilAction,
+    /// The address of the member that submitted the action
+    pub proposer: Address,
+    /// The addresses of the members that approved the action
+    pub approvals: Vec<Address>,
+    /// Whether the action has been executed
+    pub executed: bool,
+}
+
+/// The configuration of a payment stream from the governor
+#[derive(Clone)]
+#[contracttype]
+pub struct StreamConfig {
+    /// The address of the token being streamed
+    pub token: Address,
+    /// The address of the account receiving the stream
+    pub recipient: Address,
+    /// The amount of tokens vested to the recipient each ledger
+    pub amount_per_ledger: i128,
+    /// The ledger sequence when tokens begin vesting
+    pub start_ledger: u32,
+    /// The ledger sequence when tokens stop vesting
+    pub end_ledger: u32,
+}
+
+/// A payment stream from the governor
+#[derive(Clone)]
+#[contracttype]
+pub struct Stream {
+    /// The id of the stream
+    pub id: u32,
+    /// The configuration of the stream
+    pub config: StreamConfig,
+    /// The amount of tokens withdrawn by the recipient
+    pub withdrawn: i128,
+    /// Whether the stream has been canceled
+    pub canceled: bool,
+}
+
+/// An allowance for the council to spend a token held by the governor without a proposal
+#[derive(Clone)]
+#[contracttype]
+pub struct SpendingLimit {
+    /// The address of the token
+    pub token: Address,
+    /// The maximum amount of the token the council can spend within any window of `period` ledgers. An
+    /// amount of zero revokes the council's allowance for the token.
+    pub amount: i128,
+    /// The length (in ledgers) of the rolling window the limit applies to
+    pub period: u32,
+}
+
+/// A record of a council spend
+#[derive(Clone)]
+#[contracttype]
+pub struct CouncilSpend {
+    /// The ledger sequence the spend occurred at
+    pub ledger: u32,
+    /// The amount spent
+    pub amount: i128,
+}
+
+/// The holdings of a token tracked by the treasury
+#[derive(Clone)]
+#[contracttype]
+pub struct TreasuryBalance {
+    /// The address of the token
+    pub token: Address,
+    /// The balance of the token held by the governor
+    pub balance: i128,
+    /// The amount of the token queued proposals will transfer out of the governor
+    pub pending_outflow: i128,
+}
+
 /// The types of votes that can be cast
 #[repr(u8)]
 pub enum VoteType {
@@ -131,4 +331,6 @@ pub enum ProposalStatus {
     Executed = 4,
     /// The proposal has been canceled
     Canceled = 5,
+    /// The proposal has been vetoed by the council
+    Vetoed = 6,
 }
@@ -90,6 +90,26 @@ impl VoteCount {
     }
 }
 
+/// Calculate the quadratic weight of an amount of votes, the integer square root of the amount
+///
+/// ### Arguments
+/// * `amount` - The amount of votes
+///
+/// ### Returns
+/// * The largest value `x` such that `x * x <= amount`, or 0 if the amount is not positive
+pub fn quadratic_weight(amount: i128) -> i128 {
+    if amount <= 0 {
+        return 0;
+    }
+    let mut x = amount;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + amount / x) / 2;
+    }
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +177,18 @@ mod tests {
         vote_count.add_vote(&e, 1, 1);
         assert!(vote_count.is_over_threshold(5000));
     }
+
+    #[test]
+    fn test_quadratic_weight() {
+        assert_eq!(quadratic_weight(-1), 0);
+        assert_eq!(quadratic_weight(0), 0);
+        assert_eq!(quadratic_weight(1), 1);
+        assert_eq!(quadratic_weight(2), 1);
+        assert_eq!(quadratic_weight(3), 1);
+        assert_eq!(quadratic_weight(4), 2);
+        assert_eq!(quadratic_weight(99), 9);
+        assert_eq!(quadratic_weight(100), 10);
+        assert_eq!(quadratic_weight(1_000 * 10_i128.pow(7)), 100_000);
+        assert_eq!(quadratic_weight(i128::MAX), 13043817825332782212);
+    }
 }
@@ -0,0 +1,34 @@
+use soroban_sdk::{contract, contracterror, contractimpl, panic_with_error, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ContractError {
+    AlreadyMinted = 256,
+    NotOwner = 257,
+}
+
+/// A minimal NFT collection where each token id is owned by a single address
+#[contract]
+pub struct MockNftContract;
+
+#[contractimpl]
+impl MockNftContract {
+    pub fn mint(e: Env, to: Address, token_id: u32) {
+        if e.storage().persistent().has(&token_id) {
+            panic_with_error!(&e, ContractError::AlreadyMinted);
+        }
+        e.storage().persistent().set(&token_id, &to);
+    }
+
+    pub fn owner_of(e: Env, token_id: u32) -> Option<Address> {
+        e.storage().persistent().get(&token_id)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, token_id: u32) {
+        from.require_auth();
+        if Self::owner_of(e.clone(), token_id) != Some(from) {
+            panic_with_error!(&e, ContractError::NotOwner);
+        }
+        e.storage().persistent().set(&token_id, &to);
+    }
+}
@@ -0,0 +1,8 @@
+#![no_std]
+
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
+pub mod contract;
+
+pub use contract::*;
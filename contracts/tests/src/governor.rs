@@ -1,10 +1,11 @@
 use soroban_governor::{
-    types::{Calldata, GovernorSettings, ProposalAction},
+    storage,
+    types::{Calldata, GovernorSettings, ProposalAction, VoteSettings},
     GovernorContract, GovernorContractClient,
 };
 use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal, String, Symbol};
 
-use crate::{common, votes, ONE_DAY_LEDGERS};
+use crate::{common, env::EnvTestUtils, votes, ONE_DAY_LEDGERS};
 
 mod governor_contract_wasm {
     soroban_sdk::contractimport!(
@@ -114,6 +115,25 @@ pub fn default_governor_settings() -> GovernorSettings {
     }
 }
 
+/// Default governor vote settings
+pub fn default_vote_settings() -> VoteSettings {
+    VoteSettings {
+        vote_lock: false,
+        exclude_abstain_quorum: false,
+        quadratic_voting: false,
+        quadratic_quorum_supply: 0,
+    }
+}
+
+/// Set the vote settings of a governor contract
+///
+/// ### Arguments
+/// * `governor` - The address of the governor contract
+/// * `vote_settings` - The vote settings for the governor
+pub fn set_vote_settings(e: &Env, governor: &Address, vote_settings: &VoteSettings) {
+    e.as_contract(governor, || storage::set_vote_settings(e, vote_settings));
+}
+
 /// Default test proposal information
 pub fn default_proposal_data(e: &Env) -> (String, String, ProposalAction) {
     let calldata = Calldata {
@@ -135,3 +155,34 @@ pub fn default_proposal_data(e: &Env) -> (String, String, ProposalAction) {
 
     (title, description, ProposalAction::Calldata(calldata))
 }
+
+/// Create a proposal with `action` and pass and execute it with the votes of `creator`
+///
+/// Returns the id of the proposal
+///
+/// ### Arguments
+/// * `settings` - The settings of the governor
+/// * `creator` - The address of the account creating and voting for the proposal
+/// * `action` - The action of the proposal
+pub fn pass_proposal(
+    e: &Env,
+    governor_client: &GovernorContractClient,
+    settings: &GovernorSettings,
+    creator: &Address,
+    action: &ProposalAction,
+) -> u32 {
+    let (title, description, _) = default_proposal_data(e);
+    let proposal_id =
+        governor_client
+            .mock_all_auths()
+            .propose(creator, &title, &description, action);
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(creator, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.close(&proposal_id);
+    e.jump(settings.timelock);
+    governor_client.execute(&proposal_id);
+    proposal_id
+}
@@ -21,3 +21,17 @@ pub fn create_mock_subcall_contract_wasm<'a>(
     vote_token_client.initialize(&token, &governor);
     (vote_token_id, vote_token_client)
 }
+
+mod mock_nft_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/mock_nft.wasm"
+    );
+}
+pub use mock_nft_wasm::Client as MockNftClient;
+
+/// Create a WASM mock NFT collection contract
+pub fn create_mock_nft_wasm<'a>(e: &Env) -> (Address, MockNftClient<'a>) {
+    let nft_id = e.register_contract_wasm(None, mock_nft_wasm::WASM);
+    let nft_client = MockNftClient::new(e, &nft_id);
+    (nft_id, nft_client)
+}
@@ -8,6 +8,13 @@ mod token_votes_wasm {
 }
 pub use token_votes_wasm::Client as SorobanVotesClient;
 
+mod extended_token_votes_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/optimized/soroban_votes_extended.wasm"
+    );
+}
+pub use extended_token_votes_wasm::{Client as ExtendedVotesClient, DelegationMode};
+
 mod admin_token_votes_wasm {
     soroban_sdk::contractimport!(
         file = "../../target/wasm32-unknown-unknown/optimized/soroban_admin_votes.wasm"
@@ -20,7 +27,28 @@ mod bonding_token_votes_wasm {
         file = "../../target/wasm32-unknown-unknown/optimized/soroban_votes_bonding.wasm"
     );
 }
-pub use bonding_token_votes_wasm::Client as BondingVotesClient;
+pub use bonding_token_votes_wasm::{Client as BondingVotesClient, EmissionPayout};
+
+mod escrow_token_votes_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/optimized/soroban_votes_escrow.wasm"
+    );
+}
+pub use escrow_token_votes_wasm::Client as EscrowVotesClient;
+
+mod soulbound_token_votes_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/optimized/soroban_votes_soulbound.wasm"
+    );
+}
+pub use soulbound_token_votes_wasm::Client as SoulboundVotesClient;
+
+mod nft_token_votes_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/optimized/soroban_votes_nft.wasm"
+    );
+}
+pub use nft_token_votes_wasm::Client as NftVotesClient;
 
 /// Create a voting token contract for an underyling token
 ///
@@ -83,6 +111,27 @@ pub fn create_soroban_token_votes_wasm<'a>(
     (vote_token_id, vote_token_client)
 }
 
+/// Create a WASM soroban voting token contract with extended delegation
+///
+/// ### Arguments
+/// * `admin` - The admin of the voting token
+pub fn create_extended_token_votes_wasm<'a>(
+    e: &Env,
+    admin: &Address,
+    governor: &Address,
+) -> (Address, ExtendedVotesClient<'a>) {
+    let vote_token_id = e.register_contract_wasm(None, extended_token_votes_wasm::WASM);
+    let vote_token_client = ExtendedVotesClient::new(e, &vote_token_id);
+    vote_token_client.initialize(
+        admin,
+        governor,
+        &7,
+        &String::from_str(e, "Voting Token"),
+        &String::from_str(e, "VOTES"),
+    );
+    (vote_token_id, vote_token_client)
+}
+
 /// Create a WASM soroban voting token contract
 ///
 /// ### Arguments
@@ -103,3 +152,65 @@ pub fn create_soroban_admin_votes_wasm<'a>(
     );
     (vote_token_id, vote_token_client)
 }
+
+/// Create a WASM vote-escrow voting token contract for an underlying token
+///
+/// ### Arguments
+/// * `token` - The underlying token address
+pub fn create_escrow_token_votes_wasm<'a>(
+    e: &Env,
+    token: &Address,
+    governor: &Address,
+) -> (Address, EscrowVotesClient<'a>) {
+    let vote_token_id = e.register_contract_wasm(None, escrow_token_votes_wasm::WASM);
+    let vote_token_client = EscrowVotesClient::new(e, &vote_token_id);
+    vote_token_client.initialize(
+        token,
+        governor,
+        &String::from_str(e, "Test Token"),
+        &String::from_str(e, "TEST"),
+    );
+    (vote_token_id, vote_token_client)
+}
+
+/// Create a WASM soulbound voting token contract
+///
+/// ### Arguments
+/// * `admin` - The admin of the voting token
+pub fn create_soulbound_token_votes_wasm<'a>(
+    e: &Env,
+    admin: &Address,
+    governor: &Address,
+) -> (Address, SoulboundVotesClient<'a>) {
+    let vote_token_id = e.register_contract_wasm(None, soulbound_token_votes_wasm::WASM);
+    let vote_token_client = SoulboundVotesClient::new(e, &vote_token_id);
+    vote_token_client.initialize(
+        admin,
+        governor,
+        &7,
+        &String::from_str(e, "Reputation"),
+        &String::from_str(e, "REP"),
+    );
+    (vote_token_id, vote_token_client)
+}
+
+/// Create a WASM voting token contract for an NFT collection
+///
+/// ### Arguments
+/// * `collection` - The NFT collection contract
+/// * `governor` - The governor address
+pub fn create_nft_token_votes_wasm<'a>(
+    e: &Env,
+    collection: &Address,
+    governor: &Address,
+) -> (Address, NftVotesClient<'a>) {
+    let vote_token_id = e.register_contract_wasm(None, nft_token_votes_wasm::WASM);
+    let vote_token_client = NftVotesClient::new(e, &vote_token_id);
+    vote_token_client.initialize(
+        collection,
+        governor,
+        &String::from_str(e, "NFT Votes"),
+        &String::from_str(e, "NFTV"),
+    );
+    (vote_token_id, vote_token_client)
+}
@@ -1,6 +1,12 @@
+mod test_amend;
 mod test_cancel;
 mod test_close;
+mod test_council;
 mod test_execute;
 mod test_initialize;
+mod test_outcome;
 mod test_propose;
+mod test_spending_limit;
+mod test_stream;
+mod test_treasury;
 mod test_vote;
@@ -0,0 +1,323 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::{
+    types::{Calldata, ProposalAction, ProposalStatus},
+    GovernorContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, Events},
+    vec, Address, Bytes, BytesN, Env, Error, IntoVal, String, Symbol, TryIntoVal,
+};
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data},
+    votes::BondingVotesClient,
+};
+
+#[test]
+fn test_amend() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    // setup a proposal
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    e.jump(settings.vote_delay / 2);
+
+    let new_title = String::from_str(&e, "Test Title Amended");
+    let new_description = String::from_str(&e, "# This is a cooler proposal");
+    let new_action = ProposalAction::Snapshot;
+    governor_client.amend(&proposal_id, &new_title, &new_description, &new_action);
+
+    // verify auths
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    governor_address.clone(),
+                    Symbol::new(&e, "amend"),
+                    vec![
+                        &e,
+                        proposal_id.try_into_val(&e).unwrap(),
+                        new_title.to_val(),
+                        new_description.to_val(),
+                        new_action.try_into_val(&e).unwrap()
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // verify chain results
+    let amended_proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(amended_proposal.config.title, new_title);
+    assert_eq!(amended_proposal.config.description, new_description);
+    assert!(matches!(
+        amended_proposal.config.action,
+        ProposalAction::Snapshot
+    ));
+    assert_eq!(amended_proposal.data.creator, samwise);
+    assert_eq!(amended_proposal.data.vote_start, proposal.data.vote_start);
+    assert_eq!(amended_proposal.data.vote_end, proposal.data.vote_end);
+    assert_eq!(amended_proposal.data.status, ProposalStatus::Open);
+    assert!(!amended_proposal.data.executable);
+
+    // verify events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "proposal_amended"), proposal_id).into_val(&e),
+                (amended_proposal.config, None::<BytesN<32>>)
+                    .try_into_val(&e)
+                    .unwrap()
+            )
+        ]
+    );
+
+    // verify proposal id was not consumed
+    governor_client.cancel(&samwise, &proposal_id);
+    let proposal_id_new = governor_client.propose(&samwise, &title, &description, &action);
+    assert_eq!(proposal_id_new, proposal_id + 1);
+}
+
+#[test]
+fn test_amend_hashed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, _, action) = default_proposal_data(&e);
+    let uri = String::from_str(
+        &e,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let content = Bytes::from_slice(&e, "# This is a very long and detailed proposal".as_bytes());
+    let content_hash = e.crypto().sha256(&content);
+
+    // setup a proposal
+    let proposal_id =
+        governor_client.propose_hashed(&samwise, &title, &uri, &content_hash, &action);
+    e.jump(settings.vote_delay / 2);
+
+    let new_content = Bytes::from_slice(&e, "# This is a cooler proposal".as_bytes());
+    let new_content_hash = e.crypto().sha256(&new_content);
+    governor_client.amend_hashed(&proposal_id, &title, &uri, &new_content_hash, &action);
+
+    // verify auths
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    governor_address.clone(),
+                    Symbol::new(&e, "amend_hashed"),
+                    vec![
+                        &e,
+                        proposal_id.try_into_val(&e).unwrap(),
+                        title.to_val(),
+                        uri.to_val(),
+                        new_content_hash.to_val(),
+                        action.try_into_val(&e).unwrap()
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // verify chain results
+    let amended_proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(amended_proposal.config.description, uri);
+    assert!(governor_client.verify_description(&proposal_id, &new_content));
+    assert!(!governor_client.verify_description(&proposal_id, &content));
+
+    // verify events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "proposal_amended"), proposal_id).into_val(&e),
+                (amended_proposal.config, Some(new_content_hash))
+                    .try_into_val(&e)
+                    .unwrap()
+            )
+        ]
+    );
+
+    // amending with an on-chain description removes the content hash
+    let description = String::from_str(&e, "# This is an on-chain proposal");
+    governor_client.amend(&proposal_id, &title, &description, &action);
+    assert!(!governor_client.verify_description(&proposal_id, &new_content));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_amend_nonexistent_proposal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, _, _) = create_governor(&e, &bombadil, &bombadil, &settings);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let (title, description, action) = default_proposal_data(&e);
+    governor_client.amend(&1, &title, &description, &action);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_amend_proposal_active() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    // setup a proposal and start the voting period
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay);
+
+    governor_client.amend(&proposal_id, &title, &description, &action);
+}
+
+#[test]
+fn test_amend_already_closed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay / 2);
+    governor_client.cancel(&samwise, &proposal_id);
+
+    let result = governor_client.try_amend(&proposal_id, &title, &description, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(202))));
+}
+
+#[test]
+fn test_amend_validates_config() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay / 2);
+
+    // calldata cannot target the governor
+    let calldata = Calldata {
+        contract_id: governor_address.clone(),
+        function: Symbol::new(&e, "test"),
+        args: (1, 2, 3).into_val(&e),
+        auths: vec![&e],
+    };
+    let result = governor_client.try_amend(
+        &proposal_id,
+        &title,
+        &description,
+        &ProposalAction::Calldata(calldata),
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(213))));
+
+    // only the council can create upgrade proposals
+    let upgrade = ProposalAction::Upgrade(BytesN::<32>::random(&e));
+    let result = governor_client.try_amend(&proposal_id, &title, &description, &upgrade);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // title length is validated for off-chain content
+    let long_title = String::from_str(&e, &"a".repeat(257));
+    let uri = String::from_str(
+        &e,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let content_hash = BytesN::<32>::random(&e);
+    let result =
+        governor_client.try_amend_hashed(&proposal_id, &long_title, &uri, &content_hash, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(214))));
+}
@@ -8,7 +8,10 @@ use soroban_sdk::{
 };
 use tests::{
     env::EnvTestUtils,
-    governor::{create_governor, default_governor_settings, default_proposal_data},
+    governor::{
+        create_governor, default_governor_settings, default_proposal_data, default_vote_settings,
+        set_vote_settings,
+    },
     votes::BondingVotesClient,
 };
 
@@ -161,6 +164,226 @@ fn test_close_defeated_quorum_not_met() {
     assert_eq!(proposal_id_new, proposal_id + 1);
 }
 
+#[test]
+fn test_close_successful_exclude_abstain_quorum() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let pippin = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let mut vote_settings = default_vote_settings();
+    vote_settings.exclude_abstain_quorum = true;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 99 * 10i128.pow(7); // quorum is 1% of total supply
+    let pippin_votes = 10 * 10i128.pow(7);
+    let total_votes: i128 = 10_000 * 10i128.pow(7);
+    let frodo_votes = total_votes - samwise_votes - pippin_votes;
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    token_client.mint(&pippin, &pippin_votes);
+    votes_client.deposit(&pippin, &pippin_votes);
+
+    // frodo's balance does not count towards the active supply
+    votes_client.abstain(&frodo);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    governor_client.vote(&pippin, &proposal_id, &0);
+
+    // delegating after the vote starts does not change the active supply used for quorum
+    votes_client.delegate(&frodo, &frodo);
+    e.jump(settings.vote_period);
+
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(
+        votes_client.get_past_total_supply(&proposal.data.vote_start),
+        total_votes
+    );
+    assert_eq!(
+        votes_client.get_past_active_supply(&proposal.data.vote_start),
+        samwise_votes + pippin_votes
+    );
+    assert_eq!(votes_client.active_supply(), total_votes);
+
+    governor_client.close(&proposal_id);
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Successful);
+    assert_eq!(proposal.data.eta, e.ledger().sequence() + settings.timelock);
+}
+
+#[test]
+fn test_close_defeated_exclude_abstain_quorum_idle_supply() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let pippin = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let mut vote_settings = default_vote_settings();
+    vote_settings.exclude_abstain_quorum = true;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 99 * 10i128.pow(7); // quorum is 1% of total supply
+    let pippin_votes = 10 * 10i128.pow(7);
+    let total_votes: i128 = 10_000 * 10i128.pow(7);
+    let frodo_votes = total_votes - samwise_votes - pippin_votes;
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    token_client.mint(&pippin, &pippin_votes);
+    votes_client.deposit(&pippin, &pippin_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    governor_client.vote(&pippin, &proposal_id, &0);
+    e.jump(settings.vote_period);
+
+    // frodo is self delegated and never votes, but only explicit abstention is excluded,
+    // so frodo's balance still counts towards the active supply
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(
+        votes_client.get_past_active_supply(&proposal.data.vote_start),
+        total_votes
+    );
+
+    governor_client.close(&proposal_id);
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Defeated);
+    assert_eq!(proposal.data.eta, 0);
+}
+
+#[test]
+fn test_close_successful_quadratic_quorum() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let mut vote_settings = default_vote_settings();
+    vote_settings.quadratic_voting = true;
+    vote_settings.quadratic_quorum_supply = 316_227;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    // quorum is 1% of the configured quorum supply, or 3162 votes
+    let samwise_votes = 2 * 10i128.pow(7);
+    let total_votes: i128 = 10_000 * 10i128.pow(7);
+    let frodo_votes = total_votes - samwise_votes;
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+
+    let vote_count = governor_client.get_proposal_votes(&proposal_id).unwrap();
+    assert_eq!(vote_count._for, 4472);
+
+    governor_client.close(&proposal_id);
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Successful);
+    assert_eq!(proposal.data.eta, e.ledger().sequence() + settings.timelock);
+}
+
+#[test]
+fn test_close_defeated_quadratic_quorum_supply() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let mut vote_settings = default_vote_settings();
+    vote_settings.quadratic_voting = true;
+    vote_settings.quadratic_quorum_supply = 1_000_000;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    // quorum is 1% of the configured quorum supply, or 10000 votes
+    let samwise_votes = 2 * 10i128.pow(7);
+    let total_votes: i128 = 10_000 * 10i128.pow(7);
+    let frodo_votes = total_votes - samwise_votes;
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+
+    governor_client.close(&proposal_id);
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Defeated);
+    assert_eq!(proposal.data.eta, 0);
+}
+
 #[test]
 fn test_close_defeated_threshold_not_met() {
     let e = Env::default();
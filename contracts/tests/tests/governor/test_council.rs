@@ -0,0 +1,397 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::{
+    types::{CouncilAction, CouncilConfig, ProposalAction, ProposalStatus},
+    GovernorContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _, Events},
+    vec, Address, BytesN, Env, Error, IntoVal, String, Symbol,
+};
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data, pass_proposal},
+    votes::BondingVotesClient,
+};
+
+#[test]
+fn test_veto() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    // setup a proposal that is queued for execution
+    let (title, description, action) = default_proposal_data(&e);
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.close(&proposal_id);
+
+    governor_client.veto(&bombadil, &proposal_id);
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Vetoed);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.status, ProposalStatus::Vetoed);
+
+    // verify events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "proposal_vetoed"), proposal_id).into_val(&e),
+                ().into_val(&e)
+            )
+        ]
+    );
+
+    // verify the proposal can't be executed
+    e.jump(settings.timelock);
+    let result = governor_client.try_execute(&proposal_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(205))));
+}
+
+#[test]
+fn test_veto_requires_council() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    let result = governor_client.try_veto(&samwise, &proposal_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // council proposals cannot be vetoed
+    token_client.mint(&bombadil, &samwise_votes);
+    votes_client.deposit(&bombadil, &samwise_votes);
+    let council_proposal_id = governor_client.propose(
+        &bombadil,
+        &title,
+        &description,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: samwise.clone(),
+            members: vec![&e],
+            threshold: 0,
+        }),
+    );
+    let result = governor_client.try_veto(&bombadil, &council_proposal_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+}
+
+#[test]
+fn test_multisig_council_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    // replace the council with a 2 of 3 multi-sig council
+    let council_config = CouncilConfig {
+        address: bombadil.clone(),
+        members: vec![&e, frodo.clone(), merry.clone(), pippin.clone()],
+        threshold: 2,
+    };
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::CouncilMultisig(council_config.clone()),
+    );
+    let config = governor_client.council_config();
+    assert_eq!(config.address, bombadil);
+    assert_eq!(config.members, council_config.members);
+    assert_eq!(config.threshold, 2);
+
+    let (title, description, action) = default_proposal_data(&e);
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+
+    // the council address can no longer cancel proposals directly
+    let result = governor_client.try_cancel(&bombadil, &proposal_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // non-members cannot submit council actions
+    let result = governor_client.try_council_submit(&samwise, &CouncilAction::Cancel(proposal_id));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    let action_id = governor_client.council_submit(&frodo, &CouncilAction::Cancel(proposal_id));
+    let action_data = governor_client.get_council_action(&action_id).unwrap();
+    assert_eq!(action_data.proposer, frodo);
+    assert_eq!(action_data.approvals, vec![&e, frodo.clone()]);
+    assert!(!action_data.executed);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Open);
+
+    let result = governor_client.try_council_approve(&frodo, &action_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(219))));
+
+    governor_client.council_approve(&pippin, &action_id);
+
+    // verify chain results
+    let action_data = governor_client.get_council_action(&action_id).unwrap();
+    assert_eq!(
+        action_data.approvals,
+        vec![&e, frodo.clone(), pippin.clone()]
+    );
+    assert!(action_data.executed);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Canceled);
+
+    // verify events
+    let events = e.events().all();
+    let tx_events = vec![
+        &e,
+        events.get_unchecked(events.len() - 2),
+        events.last().unwrap(),
+    ];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "proposal_canceled"), proposal_id).into_val(&e),
+                ().into_val(&e)
+            ),
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "council_action_executed"), action_id).into_val(&e),
+                ().into_val(&e)
+            )
+        ]
+    );
+
+    let result = governor_client.try_council_approve(&merry, &action_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(218))));
+}
+
+#[test]
+fn test_multisig_council_upgrade() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+    let frodo_votes: i128 = 10 * 10i128.pow(7);
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: bombadil.clone(),
+            members: vec![&e, frodo.clone(), merry.clone()],
+            threshold: 2,
+        }),
+    );
+
+    // upgrade proposals can only be created with council approvals
+    let (title, description, _) = default_proposal_data(&e);
+    let wasm_hash = BytesN::<32>::random(&e);
+    let result = governor_client.try_propose(
+        &bombadil,
+        &title,
+        &description,
+        &ProposalAction::Upgrade(wasm_hash.clone()),
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    let upgrade_title = String::from_str(&e, "Upgrade");
+    let action_id = governor_client.council_submit(
+        &frodo,
+        &CouncilAction::Upgrade(
+            upgrade_title.clone(),
+            description.clone(),
+            wasm_hash.clone(),
+        ),
+    );
+    governor_client.council_approve(&merry, &action_id);
+
+    // verify the upgrade proposal was created by the submitting member
+    let proposal_id = 1;
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.creator, frodo);
+    assert_eq!(proposal.data.status, ProposalStatus::Open);
+    assert_eq!(proposal.config.title, upgrade_title);
+    match proposal.config.action {
+        ProposalAction::Upgrade(hash) => assert_eq!(hash, wasm_hash),
+        _ => panic!("expected upgrade proposal"),
+    }
+}
+
+#[test]
+fn test_multisig_council_approvals_from_current_members() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+    let merry_votes: i128 = 100 * 10i128.pow(7);
+    token_client.mint(&merry, &merry_votes);
+    votes_client.deposit(&merry, &merry_votes);
+
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: bombadil.clone(),
+            members: vec![&e, frodo.clone(), merry.clone()],
+            threshold: 2,
+        }),
+    );
+
+    // submit a veto against a queued proposal
+    let (title, description, action) = default_proposal_data(&e);
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    let action_id = governor_client.council_submit(&frodo, &CouncilAction::Veto(proposal_id));
+
+    // rotate frodo out of the council
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &merry,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: bombadil.clone(),
+            members: vec![&e, merry.clone(), pippin.clone()],
+            threshold: 2,
+        }),
+    );
+
+    // frodo's approval no longer counts
+    governor_client.council_approve(&merry, &action_id);
+    assert!(
+        !governor_client
+            .get_council_action(&action_id)
+            .unwrap()
+            .executed
+    );
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Open);
+
+    governor_client.council_approve(&pippin, &action_id);
+    assert!(
+        governor_client
+            .get_council_action(&action_id)
+            .unwrap()
+            .executed
+    );
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.data.status, ProposalStatus::Vetoed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_propose_council_invalid_threshold() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes: i128 = 1000 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, _) = default_proposal_data(&e);
+    governor_client.propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: bombadil.clone(),
+            members: vec![&e, frodo.clone()],
+            threshold: 2,
+        }),
+    );
+}
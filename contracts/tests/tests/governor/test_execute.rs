@@ -1,6 +1,8 @@
 #[cfg(test)]
 use sep_41_token::testutils::MockTokenClient;
-use soroban_governor::types::{Calldata, GovernorSettings, ProposalAction, ProposalStatus};
+use soroban_governor::types::{
+    Calldata, GovernorSettings, ProposalAction, ProposalStatus, VoteSettings,
+};
 use soroban_governor::GovernorContractClient;
 use soroban_sdk::testutils::{Ledger, LedgerInfo};
 use soroban_sdk::{
@@ -335,6 +337,80 @@ fn test_execute_settings() {
     assert_eq!(gov_settings.vote_threshold, new_settings.vote_threshold);
 }
 
+#[test]
+fn test_execute_vote_settings() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    // vote settings are disabled by default
+    let gov_vote_settings = governor_client.vote_settings();
+    assert!(!gov_vote_settings.vote_lock);
+    assert!(!gov_vote_settings.exclude_abstain_quorum);
+    assert!(!gov_vote_settings.quadratic_voting);
+    assert_eq!(gov_vote_settings.quadratic_quorum_supply, 0);
+
+    // set intial votes
+    let frodo_votes: i128 = 10_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&frodo, &frodo_votes);
+    votes_client.mock_all_auths().deposit(&frodo, &frodo_votes);
+
+    // create a proposal
+    let new_vote_settings = VoteSettings {
+        vote_lock: true,
+        exclude_abstain_quorum: true,
+        quadratic_voting: true,
+        quadratic_quorum_supply: 1_000_000,
+    };
+    let (title, description, _) = default_proposal_data(&e);
+    let action = ProposalAction::VoteSettings(new_vote_settings.clone());
+
+    let proposal_id =
+        governor_client
+            .mock_all_auths()
+            .propose(&frodo, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(&frodo, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.mock_all_auths().close(&proposal_id);
+    e.jump(settings.timelock);
+
+    // remove any potential auth mocking
+    e.set_auths(&[]);
+    governor_client.set_auths(&[]);
+    governor_client.execute(&proposal_id);
+
+    let gov_vote_settings = governor_client.vote_settings();
+    assert_eq!(gov_vote_settings.vote_lock, new_vote_settings.vote_lock);
+    assert_eq!(
+        gov_vote_settings.exclude_abstain_quorum,
+        new_vote_settings.exclude_abstain_quorum
+    );
+    assert_eq!(
+        gov_vote_settings.quadratic_voting,
+        new_vote_settings.quadratic_voting
+    );
+    assert_eq!(
+        gov_vote_settings.quadratic_quorum_supply,
+        new_vote_settings.quadratic_quorum_supply
+    );
+
+    // the governor settings are unchanged
+    assert_eq!(governor_client.settings().quorum, settings.quorum);
+}
+
 #[test]
 fn test_execute_upgrade() {
     let e = Env::default();
@@ -0,0 +1,202 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::{
+    types::{ProposalAction, ProposalStatus},
+    GovernorContractClient,
+};
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, Env};
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data},
+    votes::BondingVotesClient,
+    ONE_DAY_LEDGERS,
+};
+
+#[test]
+fn test_outcome_executed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    let pippin_votes = 1_000 * 10i128.pow(7);
+    let frodo_votes = 1_000 * 10i128.pow(7);
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+    token_client.mint(&pippin, &pippin_votes);
+    votes_client.deposit(&pippin, &pippin_votes);
+
+    let (title, description, _) = default_proposal_data(&e);
+    let action = ProposalAction::Council(frodo.clone());
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    assert!(governor_client.get_proposal_outcome(&proposal_id).is_none());
+
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    governor_client.vote(&pippin, &proposal_id, &0);
+    e.jump(settings.vote_period);
+
+    // outcome recorded on close
+    governor_client.close(&proposal_id);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    let action_hash = e.crypto().sha256(&action.clone().to_xdr(&e));
+    assert_eq!(outcome.id, proposal_id);
+    assert_eq!(outcome.action_hash, action_hash);
+    assert_eq!(outcome.votes._for, samwise_votes);
+    assert_eq!(outcome.votes.against, pippin_votes);
+    assert_eq!(outcome.votes.abstain, 0);
+    assert_eq!(outcome.status, ProposalStatus::Successful);
+    assert_eq!(outcome.vote_start, proposal.data.vote_start);
+    assert_eq!(outcome.vote_end, proposal.data.vote_end);
+    assert_eq!(outcome.eta, proposal.data.eta);
+    assert_eq!(outcome.ledger, e.ledger().sequence());
+
+    // outcome updated on execute
+    e.jump(settings.timelock);
+    governor_client.execute(&proposal_id);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.id, proposal_id);
+    assert_eq!(outcome.action_hash, action_hash);
+    assert_eq!(outcome.votes._for, samwise_votes);
+    assert_eq!(outcome.votes.against, pippin_votes);
+    assert_eq!(outcome.status, ProposalStatus::Executed);
+    assert_eq!(outcome.eta, proposal.data.eta);
+    assert_eq!(outcome.ledger, e.ledger().sequence());
+
+    // outcome is still available past the lifetime of the proposal
+    e.jump(50 * ONE_DAY_LEDGERS);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.status, ProposalStatus::Executed);
+    assert_eq!(outcome.votes._for, samwise_votes);
+}
+
+#[test]
+fn test_outcome_defeated() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 100 * 10i128.pow(7);
+    let pippin_votes = 500 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+    token_client.mint(&pippin, &pippin_votes);
+    votes_client.deposit(&pippin, &pippin_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    governor_client.vote(&pippin, &proposal_id, &0);
+    e.jump(settings.vote_period);
+
+    governor_client.close(&proposal_id);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.id, proposal_id);
+    assert_eq!(outcome.action_hash, e.crypto().sha256(&action.to_xdr(&e)));
+    assert_eq!(outcome.votes._for, samwise_votes);
+    assert_eq!(outcome.votes.against, pippin_votes);
+    assert_eq!(outcome.status, ProposalStatus::Defeated);
+    assert_eq!(outcome.eta, 0);
+    assert_eq!(outcome.ledger, e.ledger().sequence());
+}
+
+#[test]
+fn test_outcome_expired() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 100 * 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client.vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period + settings.grace_period + 1);
+
+    governor_client.close(&proposal_id);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.id, proposal_id);
+    assert_eq!(outcome.votes._for, samwise_votes);
+    assert_eq!(outcome.status, ProposalStatus::Expired);
+    assert_eq!(outcome.eta, 0);
+    assert_eq!(outcome.ledger, e.ledger().sequence());
+}
+
+#[test]
+fn test_outcome_canceled() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 10i128.pow(7);
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay / 2);
+
+    governor_client.cancel(&samwise, &proposal_id);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.id, proposal_id);
+    assert_eq!(outcome.action_hash, e.crypto().sha256(&action.to_xdr(&e)));
+    assert_eq!(outcome.votes._for, 0);
+    assert_eq!(outcome.votes.against, 0);
+    assert_eq!(outcome.votes.abstain, 0);
+    assert_eq!(outcome.status, ProposalStatus::Canceled);
+    assert_eq!(outcome.vote_start, proposal.data.vote_start);
+    assert_eq!(outcome.vote_end, proposal.data.vote_end);
+    assert_eq!(outcome.eta, 0);
+    assert_eq!(outcome.ledger, e.ledger().sequence());
+}
@@ -1,14 +1,17 @@
 #[cfg(test)]
 use sep_41_token::testutils::MockTokenClient;
-use soroban_governor::types::{Calldata, ProposalAction, ProposalStatus};
+use soroban_governor::types::{Calldata, ProposalAction, ProposalContent, ProposalStatus};
 use soroban_governor::GovernorContractClient;
 use soroban_sdk::{
     testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, Events},
-    vec, Address, BytesN, Env, Error, IntoVal, Symbol, TryIntoVal, Val,
+    vec, Address, Bytes, BytesN, Env, Error, IntoVal, String, Symbol, TryIntoVal, Val,
 };
 use tests::{
     env::EnvTestUtils,
-    governor::{create_governor, default_governor_settings, default_proposal_data},
+    governor::{
+        create_governor, default_governor_settings, default_proposal_data, default_vote_settings,
+        set_vote_settings,
+    },
     votes::BondingVotesClient,
 };
 
@@ -112,6 +115,7 @@ fn test_propose_calldata() {
         action.try_into_val(&e).unwrap(),
         proposal.data.vote_start.into_val(&e),
         proposal.data.vote_end.into_val(&e),
+        None::<BytesN<32>>.into_val(&e),
     ];
     assert_eq!(
         tx_events,
@@ -373,6 +377,49 @@ fn test_propose_settings_validates() {
     governor_client.propose(&samwise, &title, &description, &action);
 }
 
+#[test]
+fn test_propose_settings_validates_quorum_with_vote_settings() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let mut vote_settings = default_vote_settings();
+    vote_settings.quadratic_voting = true;
+    vote_settings.quadratic_quorum_supply = 100; // 1 vote at a 1% quorum
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_mint_amount: i128 = 10_000_000;
+    token_client.mint(&samwise, &samwise_mint_amount);
+    votes_client.deposit(&samwise, &samwise_mint_amount);
+
+    let (title, description, _) = default_proposal_data(&e);
+
+    // a quorum of 0.09% of the quadratic quorum supply rounds down to 0 votes
+    let mut new_settings = settings.clone();
+    new_settings.quorum = 90;
+    let action = ProposalAction::Settings(new_settings.clone());
+    let result = governor_client.try_propose(&samwise, &title, &description, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(200))));
+
+    // a quadratic quorum supply too small for the current quorum is rejected
+    let mut new_vote_settings = vote_settings.clone();
+    new_vote_settings.quadratic_quorum_supply = 99;
+    let action = ProposalAction::VoteSettings(new_vote_settings);
+    let result = governor_client.try_propose(&samwise, &title, &description, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(200))));
+
+    new_settings.quorum = 100;
+    let action = ProposalAction::Settings(new_settings);
+    governor_client.propose(&samwise, &title, &description, &action);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #208)")]
 fn test_propose_below_proposal_threshold() {
@@ -397,3 +444,196 @@ fn test_propose_below_proposal_threshold() {
 
     governor_client.propose(&samwise, &title, &description, &action);
 }
+
+#[test]
+fn test_propose_hashed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_mint_amount: i128 = 10_000_000;
+    token_client.mint(&samwise, &samwise_mint_amount);
+    votes_client.deposit(&samwise, &samwise_mint_amount);
+
+    let (title, _, action) = default_proposal_data(&e);
+    let uri = String::from_str(
+        &e,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let content = Bytes::from_slice(&e, "# This is a very long and detailed proposal".as_bytes());
+    let content_hash = e.crypto().sha256(&content);
+
+    let proposal_id =
+        governor_client.propose_hashed(&samwise, &title, &uri, &content_hash, &action);
+
+    // verify auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    governor_address.clone(),
+                    Symbol::new(&e, "propose_hashed"),
+                    vec![
+                        &e,
+                        samwise.to_val(),
+                        title.to_val(),
+                        uri.to_val(),
+                        content_hash.to_val(),
+                        action.try_into_val(&e).unwrap()
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // verify chain results
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.id, 0);
+    assert_eq!(proposal.config.title, title);
+    assert_eq!(proposal.config.description, uri);
+    assert_eq!(proposal.data.creator, samwise);
+    assert_eq!(proposal.data.status, ProposalStatus::Open);
+
+    // verify events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    let event_data: soroban_sdk::Vec<Val> = vec![
+        &e,
+        title.into_val(&e),
+        uri.into_val(&e),
+        action.try_into_val(&e).unwrap(),
+        proposal.data.vote_start.into_val(&e),
+        proposal.data.vote_end.into_val(&e),
+        Some(content_hash.clone()).into_val(&e),
+    ];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (
+                    Symbol::new(&e, "proposal_created"),
+                    proposal_id,
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                event_data.into_val(&e)
+            )
+        ]
+    );
+
+    // verify description content
+    assert!(governor_client.verify_description(&proposal_id, &content));
+    let bad_content = Bytes::from_slice(&e, "# This is a different proposal".as_bytes());
+    assert!(!governor_client.verify_description(&proposal_id, &bad_content));
+
+    // content hash is recorded in the proposal outcome
+    governor_client.cancel(&samwise, &proposal_id);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.content, ProposalContent::OffChain(content_hash));
+    assert!(governor_client.verify_description(&proposal_id, &content));
+}
+
+#[test]
+fn test_verify_description_on_chain_description() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_mint_amount: i128 = 10_000_000;
+    token_client.mint(&samwise, &samwise_mint_amount);
+    votes_client.deposit(&samwise, &samwise_mint_amount);
+
+    let (title, description, action) = default_proposal_data(&e);
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+
+    let content = Bytes::from_slice(&e, "# This is a cool proposal".as_bytes());
+    assert!(!governor_client.verify_description(&proposal_id, &content));
+
+    governor_client.cancel(&samwise, &proposal_id);
+    let outcome = governor_client.get_proposal_outcome(&proposal_id).unwrap();
+    assert_eq!(outcome.content, ProposalContent::OnChain);
+    assert!(!governor_client.verify_description(&proposal_id, &content));
+
+    let result = governor_client.try_verify_description(&(proposal_id + 1), &content);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(201))));
+}
+
+#[test]
+fn test_propose_validates_content_lengths() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_mint_amount: i128 = 10_000_000;
+    token_client.mint(&samwise, &samwise_mint_amount);
+    votes_client.deposit(&samwise, &samwise_mint_amount);
+
+    let (title, _, action) = default_proposal_data(&e);
+    let content_hash = BytesN::<32>::random(&e);
+    let long_title = String::from_str(&e, &"a".repeat(257));
+    let uri = String::from_str(
+        &e,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let long_uri = String::from_str(&e, &"a".repeat(257));
+    let empty_uri = String::from_str(&e, "");
+
+    let result =
+        governor_client.try_propose_hashed(&samwise, &long_title, &uri, &content_hash, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(214))));
+
+    let result =
+        governor_client.try_propose_hashed(&samwise, &title, &long_uri, &content_hash, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(214))));
+
+    let result =
+        governor_client.try_propose_hashed(&samwise, &title, &empty_uri, &content_hash, &action);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(214))));
+
+    // validate max lengths are accepted
+    let max_title = String::from_str(&e, &"a".repeat(256));
+    let max_uri = String::from_str(&e, &"a".repeat(256));
+    let proposal_id =
+        governor_client.propose_hashed(&samwise, &max_title, &max_uri, &content_hash, &action);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.config.title, max_title);
+    assert_eq!(proposal.config.description, max_uri);
+    governor_client.cancel(&samwise, &proposal_id);
+
+    // on-chain content is not limited
+    let long_title = String::from_str(&e, &"a".repeat(512));
+    let long_description = String::from_str(&e, &"a".repeat(8 * 1024));
+    let proposal_id = governor_client.propose(&samwise, &long_title, &long_description, &action);
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.config.title, long_title);
+    assert_eq!(proposal.config.description, long_description);
+}
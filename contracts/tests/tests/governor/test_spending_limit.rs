@@ -0,0 +1,328 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::types::{CouncilAction, CouncilConfig, ProposalAction, SpendingLimit};
+use soroban_governor::GovernorContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use soroban_votes::TokenVotesClient;
+use tests::common::create_token;
+use tests::ONE_DAY_LEDGERS;
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data, pass_proposal},
+};
+
+#[test]
+fn test_council_spend() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let council = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    // keep proposals short so token entries remain live
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &council, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    let treasury_amount = 10_000 * 10i128.pow(7);
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &treasury_amount);
+
+    assert!(governor_client.get_spending_limit(&usdc_address).is_none());
+
+    // allow the council to spend 100 USDC per day
+    let limit_amount = 100 * 10i128.pow(7);
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::SpendingLimit(SpendingLimit {
+            token: usdc_address.clone(),
+            amount: limit_amount,
+            period: ONE_DAY_LEDGERS,
+        }),
+    );
+    let limit = governor_client.get_spending_limit(&usdc_address).unwrap();
+    assert_eq!(limit.amount, limit_amount);
+    assert_eq!(limit.period, ONE_DAY_LEDGERS);
+    assert_eq!(governor_client.get_council_spent(&usdc_address), 0);
+
+    let spend_amount = 60 * 10i128.pow(7);
+    governor_client
+        .mock_all_auths()
+        .council_spend(&usdc_address, &frodo, &spend_amount);
+    assert_eq!(
+        e.auths()[0],
+        (
+            council.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    governor_address.clone(),
+                    Symbol::new(&e, "council_spend"),
+                    vec![
+                        &e,
+                        usdc_address.to_val(),
+                        frodo.to_val(),
+                        spend_amount.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (
+                    Symbol::new(&e, "council_spend"),
+                    usdc_address.clone(),
+                    frodo.clone()
+                )
+                    .into_val(&e),
+                spend_amount.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(usdc_client.balance(&frodo), spend_amount);
+    assert_eq!(
+        usdc_client.balance(&governor_address),
+        treasury_amount - spend_amount
+    );
+    assert_eq!(
+        governor_client.get_council_spent(&usdc_address),
+        spend_amount
+    );
+
+    // spending more than the remaining allowance in the window fails
+    e.jump(ONE_DAY_LEDGERS / 2);
+    let result = governor_client.mock_all_auths().try_council_spend(
+        &usdc_address,
+        &frodo,
+        &(limit_amount - spend_amount + 1),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(216)))
+    );
+    governor_client.mock_all_auths().council_spend(
+        &usdc_address,
+        &frodo,
+        &(limit_amount - spend_amount),
+    );
+    assert_eq!(
+        governor_client.get_council_spent(&usdc_address),
+        limit_amount
+    );
+
+    // the first spend leaves the rolling window
+    e.jump(ONE_DAY_LEDGERS / 2);
+    assert_eq!(
+        governor_client.get_council_spent(&usdc_address),
+        limit_amount - spend_amount
+    );
+    governor_client
+        .mock_all_auths()
+        .council_spend(&usdc_address, &frodo, &spend_amount);
+    assert_eq!(usdc_client.balance(&frodo), limit_amount + spend_amount);
+
+    // revoke the allowance
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::SpendingLimit(SpendingLimit {
+            token: usdc_address.clone(),
+            amount: 0,
+            period: ONE_DAY_LEDGERS,
+        }),
+    );
+    assert!(governor_client.get_spending_limit(&usdc_address).is_none());
+    let result = governor_client
+        .mock_all_auths()
+        .try_council_spend(&usdc_address, &frodo, &1);
+    assert_eq!(
+        result.err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(216)))
+    );
+}
+
+#[test]
+fn test_multisig_council_spend() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let council = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+
+    // keep proposals short so token entries remain live
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &council, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    let treasury_amount = 10_000 * 10i128.pow(7);
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &treasury_amount);
+
+    // allow the council to spend 100 USDC per day, and replace it with a 2 of 2 multi-sig council
+    let limit_amount = 100 * 10i128.pow(7);
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::SpendingLimit(SpendingLimit {
+            token: usdc_address.clone(),
+            amount: limit_amount,
+            period: ONE_DAY_LEDGERS,
+        }),
+    );
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::CouncilMultisig(CouncilConfig {
+            address: council.clone(),
+            members: vec![&e, merry.clone(), pippin.clone()],
+            threshold: 2,
+        }),
+    );
+
+    // the council address can no longer spend directly
+    let spend_amount = 60 * 10i128.pow(7);
+    let result =
+        governor_client
+            .mock_all_auths()
+            .try_council_spend(&usdc_address, &frodo, &spend_amount);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // a spend requires member approvals
+    let action_id = governor_client.mock_all_auths().council_submit(
+        &merry,
+        &CouncilAction::Spend(usdc_address.clone(), frodo.clone(), spend_amount),
+    );
+    assert_eq!(usdc_client.balance(&frodo), 0);
+
+    governor_client
+        .mock_all_auths()
+        .council_approve(&pippin, &action_id);
+    assert_eq!(usdc_client.balance(&frodo), spend_amount);
+    assert_eq!(
+        usdc_client.balance(&governor_address),
+        treasury_amount - spend_amount
+    );
+    assert_eq!(
+        governor_client.get_council_spent(&usdc_address),
+        spend_amount
+    );
+
+    // the spending limit still applies to approved spends
+    let action_id = governor_client.mock_all_auths().council_submit(
+        &merry,
+        &CouncilAction::Spend(usdc_address.clone(), frodo.clone(), spend_amount),
+    );
+    let result = governor_client
+        .mock_all_auths()
+        .try_council_approve(&pippin, &action_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(216))));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #216)")]
+fn test_council_spend_no_limit() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let council = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, _) = create_governor(&e, &bombadil, &council, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    token_client
+        .mock_all_auths()
+        .mint(&governor_address, &(100 * 10i128.pow(7)));
+
+    governor_client
+        .mock_all_auths()
+        .council_spend(&token_address, &frodo, &10i128.pow(7));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_propose_spending_limit_invalid() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+
+    // period longer than the max spending period
+    let (title, description, _) = default_proposal_data(&e);
+    governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::SpendingLimit(SpendingLimit {
+            token: token_address.clone(),
+            amount: 100,
+            period: 32 * ONE_DAY_LEDGERS,
+        }),
+    );
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::types::{ProposalAction, StreamConfig};
+use soroban_governor::GovernorContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+use soroban_votes::TokenVotesClient;
+use tests::common::create_token;
+use tests::ONE_DAY_LEDGERS;
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data, pass_proposal},
+};
+
+#[test]
+fn test_stream() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    // keep proposals short so token entries remain live
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    let treasury_amount = 1_000 * 10i128.pow(7);
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &treasury_amount);
+
+    // stream starts after the proposal is executed
+    let start_ledger = e.ledger().sequence() + 4 * ONE_DAY_LEDGERS;
+    let end_ledger = start_ledger + 1000;
+    let config = StreamConfig {
+        token: usdc_address.clone(),
+        recipient: frodo.clone(),
+        amount_per_ledger: 10i128.pow(7),
+        start_ledger,
+        end_ledger,
+    };
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::Stream(config),
+    );
+
+    let stream = governor_client.get_stream(&0).unwrap();
+    assert_eq!(stream.id, 0);
+    assert_eq!(stream.config.recipient, frodo);
+    assert_eq!(stream.config.start_ledger, start_ledger);
+    assert_eq!(stream.config.end_ledger, end_ledger);
+    assert_eq!(stream.withdrawn, 0);
+    assert!(!stream.canceled);
+
+    // nothing is vested before the stream starts
+    assert_eq!(governor_client.mock_all_auths().withdraw_stream(&0), 0);
+
+    e.jump(start_ledger - e.ledger().sequence() + 300);
+    let withdrawn = governor_client.mock_all_auths().withdraw_stream(&0);
+    assert_eq!(withdrawn, 300 * 10i128.pow(7));
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    governor_address.clone(),
+                    Symbol::new(&e, "withdraw_stream"),
+                    vec![&e, 0_u32.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(usdc_client.balance(&frodo), withdrawn);
+    assert_eq!(
+        usdc_client.balance(&governor_address),
+        treasury_amount - withdrawn
+    );
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "stream_withdrawn"), 0_u32, frodo.clone()).into_val(&e),
+                withdrawn.into_val(&e)
+            )
+        ]
+    );
+
+    // vesting stops at the end of the stream
+    e.jump(2000);
+    let withdrawn = governor_client.mock_all_auths().withdraw_stream(&0);
+    assert_eq!(withdrawn, 700 * 10i128.pow(7));
+    assert_eq!(usdc_client.balance(&frodo), 1000 * 10i128.pow(7));
+    assert_eq!(governor_client.mock_all_auths().withdraw_stream(&0), 0);
+    assert_eq!(
+        governor_client.get_stream(&0).unwrap().withdrawn,
+        1000 * 10i128.pow(7)
+    );
+}
+
+#[test]
+fn test_cancel_stream() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    let treasury_amount = 10_000 * 10i128.pow(7);
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &treasury_amount);
+
+    // stream vests for 10 days, starting immediately
+    let start_ledger = e.ledger().sequence();
+    let config = StreamConfig {
+        token: usdc_address.clone(),
+        recipient: frodo.clone(),
+        amount_per_ledger: 1000,
+        start_ledger,
+        end_ledger: start_ledger + 10 * ONE_DAY_LEDGERS,
+    };
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::Stream(config),
+    );
+
+    // cancel the stream through a second proposal
+    pass_proposal(
+        &e,
+        &governor_client,
+        &settings,
+        &samwise,
+        &ProposalAction::CancelStream(0),
+    );
+    let cancel_ledger = e.ledger().sequence();
+    let events = e.events().all();
+    let tx_events = vec![&e, events.get_unchecked(events.len() - 2)];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "stream_canceled"), 0_u32).into_val(&e),
+                ().into_val(&e)
+            )
+        ]
+    );
+    let stream = governor_client.get_stream(&0).unwrap();
+    assert!(stream.canceled);
+    assert_eq!(stream.config.end_ledger, cancel_ledger);
+
+    // tokens vested before the cancellation can still be withdrawn
+    e.jump(ONE_DAY_LEDGERS);
+    let vested = 1000 * (cancel_ledger - start_ledger) as i128;
+    let withdrawn = governor_client.mock_all_auths().withdraw_stream(&0);
+    assert_eq!(withdrawn, vested);
+    assert_eq!(usdc_client.balance(&frodo), vested);
+    assert_eq!(
+        usdc_client.balance(&governor_address),
+        treasury_amount - vested
+    );
+    assert_eq!(governor_client.mock_all_auths().withdraw_stream(&0), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn test_withdraw_stream_nonexistent() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, _, _) = create_governor(&e, &bombadil, &bombadil, &settings);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    governor_client.mock_all_auths().withdraw_stream(&0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_propose_stream_invalid() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+
+    // stream ends before it starts
+    let (title, description, _) = default_proposal_data(&e);
+    let config = StreamConfig {
+        token: token_address.clone(),
+        recipient: frodo.clone(),
+        amount_per_ledger: 1000,
+        start_ledger: 1000,
+        end_ledger: 999,
+    };
+    governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::Stream(config),
+    );
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+use sep_41_token::testutils::MockTokenClient;
+use soroban_governor::types::{Calldata, ProposalAction};
+use soroban_governor::GovernorContractClient;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal, Symbol};
+use soroban_votes::TokenVotesClient;
+use tests::common::create_token;
+use tests::ONE_DAY_LEDGERS;
+use tests::{
+    env::EnvTestUtils,
+    governor::{create_governor, default_governor_settings, default_proposal_data},
+};
+
+#[test]
+fn test_treasury_balances() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    // keep proposals short so token entries remain live
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    settings.grace_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    token_client
+        .mock_all_auths()
+        .mint(&governor_address, &(100 * 10i128.pow(7)));
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &(500 * 10i128.pow(7)));
+
+    assert_eq!(governor_client.treasury_tokens().len(), 0);
+    assert_eq!(governor_client.treasury_balances().len(), 0);
+
+    // track the governance and usdc tokens
+    let (title, description, _) = default_proposal_data(&e);
+    let tokens = vec![&e, token_address.clone(), usdc_address.clone()];
+    let proposal_id = governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::TreasuryTokens(tokens.clone()),
+    );
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.close(&proposal_id);
+    e.jump(settings.timelock);
+    governor_client.execute(&proposal_id);
+
+    assert_eq!(governor_client.treasury_tokens(), tokens);
+    let balances = governor_client.treasury_balances();
+    assert_eq!(balances.len(), 2);
+    let balance = balances.get_unchecked(0);
+    assert_eq!(balance.token, token_address);
+    assert_eq!(balance.balance, 100 * 10i128.pow(7));
+    assert_eq!(balance.pending_outflow, 0);
+    let balance = balances.get_unchecked(1);
+    assert_eq!(balance.token, usdc_address);
+    assert_eq!(balance.balance, 500 * 10i128.pow(7));
+    assert_eq!(balance.pending_outflow, 0);
+
+    // queue a proposal that transfers usdc out of the treasury
+    let transfer_amount = 200 * 10i128.pow(7);
+    let action = ProposalAction::Calldata(Calldata {
+        contract_id: usdc_address.clone(),
+        function: Symbol::new(&e, "transfer"),
+        args: (governor_address.clone(), frodo.clone(), transfer_amount).into_val(&e),
+        auths: vec![&e],
+    });
+    let proposal_id =
+        governor_client
+            .mock_all_auths()
+            .propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+
+    // outflows are pending only once the proposal is queued
+    let balances = governor_client.treasury_balances();
+    assert_eq!(balances.get_unchecked(1).pending_outflow, 0);
+
+    governor_client.close(&proposal_id);
+    let balances = governor_client.treasury_balances();
+    assert_eq!(balances.get_unchecked(0).pending_outflow, 0);
+    let balance = balances.get_unchecked(1);
+    assert_eq!(balance.balance, 500 * 10i128.pow(7));
+    assert_eq!(balance.pending_outflow, transfer_amount);
+
+    e.jump(settings.timelock);
+    governor_client.execute(&proposal_id);
+    let balances = governor_client.treasury_balances();
+    let balance = balances.get_unchecked(1);
+    assert_eq!(balance.balance, 300 * 10i128.pow(7));
+    assert_eq!(balance.pending_outflow, 0);
+    assert_eq!(usdc_client.balance(&frodo), transfer_amount);
+}
+
+#[test]
+fn test_treasury_balances_authorized_transfer() {
+    let e = Env::default();
+    e.set_default_info();
+    e.budget().reset_unlimited();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    // keep proposals short so token entries remain live
+    let mut settings = default_governor_settings();
+    settings.vote_period = ONE_DAY_LEDGERS;
+    settings.grace_period = ONE_DAY_LEDGERS;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+    let (usdc_address, usdc_client) = create_token(&e, &bombadil, 7, "USDC");
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+    usdc_client
+        .mock_all_auths()
+        .mint(&governor_address, &(500 * 10i128.pow(7)));
+
+    let (title, description, _) = default_proposal_data(&e);
+    let proposal_id = governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::TreasuryTokens(vec![&e, usdc_address.clone()]),
+    );
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.close(&proposal_id);
+    e.jump(settings.timelock);
+    governor_client.execute(&proposal_id);
+
+    // queue a proposal that authorizes a transfer from the treasury to a deposit call
+    let transfer_amount = 123 * 10i128.pow(7);
+    let action = ProposalAction::Calldata(Calldata {
+        contract_id: votes_address.clone(),
+        function: Symbol::new(&e, "deposit"),
+        args: (governor_address.clone(), transfer_amount).into_val(&e),
+        auths: vec![
+            &e,
+            Calldata {
+                contract_id: usdc_address.clone(),
+                function: Symbol::new(&e, "transfer"),
+                args: (governor_address.clone(), frodo.clone(), transfer_amount).into_val(&e),
+                auths: vec![&e],
+            },
+        ],
+    });
+    let proposal_id =
+        governor_client
+            .mock_all_auths()
+            .propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+    governor_client
+        .mock_all_auths()
+        .vote(&samwise, &proposal_id, &1);
+    e.jump(settings.vote_period);
+    governor_client.close(&proposal_id);
+
+    let balance = governor_client.treasury_balances().get_unchecked(0);
+    assert_eq!(balance.pending_outflow, transfer_amount);
+
+    // outflows are no longer pending once the proposal expires
+    e.jump(settings.timelock + settings.grace_period + 1);
+    let balance = governor_client.treasury_balances().get_unchecked(0);
+    assert_eq!(balance.balance, 500 * 10i128.pow(7));
+    assert_eq!(balance.pending_outflow, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_propose_treasury_tokens_duplicate() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+
+    let (title, description, _) = default_proposal_data(&e);
+    governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::TreasuryTokens(vec![&e, token_address.clone(), token_address.clone()]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_propose_treasury_tokens_not_token() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let not_token = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = TokenVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mock_all_auths().mint(&samwise, &samwise_votes);
+    votes_client
+        .mock_all_auths()
+        .deposit(&samwise, &samwise_votes);
+
+    let (title, description, _) = default_proposal_data(&e);
+    governor_client.mock_all_auths().propose(
+        &samwise,
+        &title,
+        &description,
+        &ProposalAction::TreasuryTokens(vec![&e, token_address.clone(), not_token]),
+    );
+}
@@ -4,11 +4,14 @@ use soroban_governor::types::ProposalStatus;
 use soroban_governor::GovernorContractClient;
 use soroban_sdk::{
     testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
-    vec, Address, Env, IntoVal, Symbol, TryIntoVal, Val,
+    vec, Address, Env, Error, IntoVal, Symbol, TryIntoVal, Val,
 };
 use tests::{
     env::EnvTestUtils,
-    governor::{create_governor, default_governor_settings, default_proposal_data},
+    governor::{
+        create_governor, default_governor_settings, default_proposal_data, default_vote_settings,
+        set_vote_settings,
+    },
     votes::BondingVotesClient,
 };
 
@@ -142,6 +145,69 @@ fn test_vote_user_changes_support() {
     governor_client.vote(&samwise, &proposal_id, &voter_support);
 }
 
+#[test]
+fn test_vote_quadratic() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let settings = default_governor_settings();
+    let mut vote_settings = default_vote_settings();
+    vote_settings.quadratic_voting = true;
+    vote_settings.quadratic_quorum_supply = 1_000_000;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let frodo_votes = 2_000 * 10i128.pow(7);
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+
+    governor_client.vote(&samwise, &proposal_id, &0);
+
+    // each vote is weighted by the integer square root of the voter's votes
+    let samwise_weight: i128 = 282842;
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    let event_data: soroban_sdk::Vec<Val> =
+        vec![&e, 0u32.into_val(&e), samwise_weight.into_val(&e)];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                governor_address.clone(),
+                (Symbol::new(&e, "vote_cast"), proposal_id, samwise.clone()).into_val(&e),
+                event_data.into_val(&e)
+            )
+        ]
+    );
+
+    governor_client.vote(&frodo, &proposal_id, &1);
+
+    let frodo_weight: i128 = 141421;
+    let vote_count = governor_client.get_proposal_votes(&proposal_id).unwrap();
+    assert_eq!(vote_count.against, samwise_weight);
+    assert_eq!(vote_count._for, frodo_weight);
+    assert_eq!(vote_count.abstain, 0);
+}
+
 #[test]
 fn test_vote_multiple_users() {
     let e = Env::default();
@@ -346,3 +412,72 @@ fn test_vote_invalid_support_option() {
 
     governor_client.vote(&samwise, &proposal_id, &3);
 }
+
+#[test]
+fn test_vote_with_vote_lock() {
+    let e = Env::default();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let mut settings = default_governor_settings();
+    settings.vote_period = 17280;
+    settings.grace_period = 17280;
+    let mut vote_settings = default_vote_settings();
+    vote_settings.vote_lock = true;
+    let (governor_address, token_address, votes_address) =
+        create_governor(&e, &bombadil, &bombadil, &settings);
+    set_vote_settings(&e, &governor_address, &vote_settings);
+    let token_client = MockTokenClient::new(&e, &token_address);
+    let votes_client = BondingVotesClient::new(&e, &votes_address);
+    let governor_client = GovernorContractClient::new(&e, &governor_address);
+
+    let frodo_votes = 2_000 * 10i128.pow(7);
+    let samwise_votes = 8_000 * 10i128.pow(7);
+    token_client.mint(&frodo, &frodo_votes);
+    votes_client.deposit(&frodo, &frodo_votes);
+
+    token_client.mint(&samwise, &samwise_votes);
+    votes_client.deposit(&samwise, &samwise_votes);
+
+    let (title, description, action) = default_proposal_data(&e);
+
+    // setup a proposal that can be voted on
+    let proposal_id = governor_client.propose(&samwise, &title, &description, &action);
+    e.jump(settings.vote_delay + 1);
+
+    governor_client.vote(&samwise, &proposal_id, &1);
+
+    // validate the voter's balance is locked
+    let result = votes_client.try_withdraw(&samwise, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(112))));
+
+    // voters can still add to their balance, and withdraw anything above the locked amount
+    let deposit_amount = 100 * 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+    votes_client.withdraw(&samwise, &deposit_amount);
+    assert_eq!(votes_client.balance(&samwise), samwise_votes);
+
+    // accounts that have not voted are not locked
+    votes_client.withdraw(&frodo, &frodo_votes);
+    assert_eq!(votes_client.balance(&frodo), 0);
+
+    // lock lasts until the proposal can no longer be executed
+    let proposal = governor_client.get_proposal(&proposal_id).unwrap();
+    let lock_until = proposal.data.vote_end + settings.timelock + settings.grace_period;
+    e.jump(lock_until - e.ledger().sequence() - 1);
+    let result = votes_client.try_withdraw(&samwise, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(112))));
+
+    e.jump(1);
+    votes_client.withdraw(&samwise, &samwise_votes);
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(
+        token_client.balance(&samwise),
+        samwise_votes + deposit_amount
+    );
+}
@@ -1,8 +1,21 @@
+mod test_abstain;
 mod test_admin;
+mod test_bonding_assets;
+mod test_claim_to;
+mod test_delegate_by_sig;
+mod test_delegate_split;
 mod test_delegation;
+mod test_delegators;
 mod test_deposit;
+mod test_emission_payout;
+mod test_emission_programs;
+mod test_emission_views;
 mod test_emissions;
 mod test_get_past;
+mod test_nft_votes;
 mod test_set_vote_sequence;
+mod test_soulbound;
 mod test_token_actions;
+mod test_unbond;
+mod test_vote_escrow;
 mod test_withdraw;
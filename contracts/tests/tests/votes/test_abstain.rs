@@ -0,0 +1,127 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, Error, IntoVal, Symbol, Vec,
+};
+use tests::{
+    env::EnvTestUtils,
+    votes::{create_extended_token_votes_wasm, DelegationMode},
+};
+
+#[test]
+fn test_abstain() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let amount_samwise = 1_000 * 10i128.pow(7);
+    let amount_frodo = 500 * 10i128.pow(7);
+    votes_client.mint(&samwise, &amount_samwise);
+    votes_client.mint(&frodo, &amount_frodo);
+    votes_client.delegate(&samwise, &frodo);
+    assert_eq!(
+        votes_client.get_delegation_mode(&samwise),
+        DelegationMode::Delegated
+    );
+    assert_eq!(
+        votes_client.get_delegation_mode(&frodo),
+        DelegationMode::SelfDelegated
+    );
+    assert_eq!(votes_client.active_supply(), amount_samwise + amount_frodo);
+
+    e.jump(10);
+
+    votes_client.abstain(&samwise);
+
+    // validate events
+    let events = e.events().all();
+    let event = vec![&e, events.last_unchecked()];
+    let old_split: Vec<(Address, u32)> = vec![&e, (frodo.clone(), 10000u32)];
+    assert_eq!(
+        event,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "abstain"), samwise.clone()).into_val(&e),
+                old_split.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    assert_eq!(
+        votes_client.get_delegation_mode(&samwise),
+        DelegationMode::Abstain
+    );
+    assert_eq!(votes_client.get_delegate_split(&samwise).len(), 0);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), amount_frodo);
+    assert_eq!(votes_client.delegator_count(&frodo), 0);
+    assert_eq!(votes_client.total_supply(), amount_samwise + amount_frodo);
+    assert_eq!(votes_client.active_supply(), amount_frodo);
+
+    let result = votes_client.try_abstain(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(101))));
+
+    e.jump(10);
+
+    // balance changes of an abstaining account do not move votes
+    let transfer_amount = 200 * 10i128.pow(7);
+    votes_client.mint(&samwise, &transfer_amount);
+    votes_client.transfer(&samwise, &frodo, &transfer_amount);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(
+        votes_client.get_votes(&frodo),
+        amount_frodo + transfer_amount
+    );
+    assert_eq!(
+        votes_client.total_supply(),
+        amount_samwise + amount_frodo + transfer_amount
+    );
+    assert_eq!(votes_client.active_supply(), amount_frodo + transfer_amount);
+
+    e.jump(10);
+
+    // delegating again restores the account's votes
+    votes_client.delegate(&samwise, &samwise);
+    assert_eq!(
+        votes_client.get_delegation_mode(&samwise),
+        DelegationMode::SelfDelegated
+    );
+    assert_eq!(votes_client.get_votes(&samwise), amount_samwise);
+    assert_eq!(
+        votes_client.active_supply(),
+        amount_samwise + amount_frodo + transfer_amount
+    );
+
+    e.jump(10);
+
+    votes_client.abstain(&samwise);
+    assert_eq!(votes_client.active_supply(), amount_frodo + transfer_amount);
+    votes_client.delegate_split(
+        &samwise,
+        &vec![&e, (frodo.clone(), 5000u32), (bombadil.clone(), 5000u32)],
+    );
+    assert_eq!(
+        votes_client.get_delegation_mode(&samwise),
+        DelegationMode::Delegated
+    );
+    assert_eq!(
+        votes_client.get_votes(&frodo),
+        amount_frodo + transfer_amount + amount_samwise / 2
+    );
+    assert_eq!(votes_client.get_votes(&bombadil), amount_samwise / 2);
+    assert_eq!(
+        votes_client.active_supply(),
+        amount_samwise + amount_frodo + transfer_amount
+    );
+}
@@ -0,0 +1,330 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol, Vec,
+};
+use tests::{common::create_stellar_token, env::EnvTestUtils, votes::create_bonding_token_votes};
+
+#[test]
+fn test_bonding_assets() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (lp_id, lp_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let assets = vec![&e, (lp_id.clone(), 20000u32)];
+    votes_client.set_assets(&assets);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            governor.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "set_assets"),
+                    vec![&e, assets.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(votes_client.get_assets(), assets);
+
+    let vote_ledger = e.ledger().sequence() + 100;
+    votes_client.set_vote_sequence(&vote_ledger);
+
+    let token_amount = 1_000 * 10i128.pow(7);
+    let lp_amount = 300 * 10i128.pow(7) + 1;
+    token_client.mint(&samwise, &token_amount);
+    lp_client.mint(&samwise, &lp_amount);
+
+    votes_client.deposit(&samwise, &token_amount);
+    votes_client.deposit_asset(&samwise, &lp_id, &lp_amount);
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "deposit_asset"),
+                    samwise.clone(),
+                    lp_id.clone()
+                )
+                    .into_val(&e),
+                lp_amount.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    let lp_units = lp_amount * 2;
+    let asset_balance = votes_client.get_asset_balance(&samwise, &lp_id);
+    assert_eq!(asset_balance.amount, lp_amount);
+    assert_eq!(asset_balance.units, lp_units);
+    assert_eq!(votes_client.balance(&samwise), token_amount + lp_units);
+    assert_eq!(votes_client.get_votes(&samwise), token_amount + lp_units);
+    assert_eq!(votes_client.total_supply(), token_amount + lp_units);
+    assert_eq!(lp_client.balance(&votes_id), lp_amount);
+    assert_eq!(lp_client.balance(&samwise), 0);
+
+    // voting units from an asset cannot be withdrawn as the underlying token
+    let result = votes_client.try_withdraw(&samwise, &(token_amount + 1));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(10))));
+
+    e.jump(200);
+
+    // withdrawing an asset returns that asset
+    let withdraw_amount = 100 * 10i128.pow(7);
+    votes_client.withdraw_asset(&samwise, &lp_id, &withdraw_amount);
+
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "withdraw_asset"),
+                    samwise.clone(),
+                    lp_id.clone()
+                )
+                    .into_val(&e),
+                withdraw_amount.into_val(&e)
+            )
+        ]
+    );
+
+    let lp_units = (lp_amount - withdraw_amount) * 2;
+    assert_eq!(
+        votes_client.get_asset_balance(&samwise, &lp_id).units,
+        lp_units
+    );
+    assert_eq!(votes_client.balance(&samwise), token_amount + lp_units);
+    assert_eq!(lp_client.balance(&samwise), withdraw_amount);
+    assert_eq!(token_client.balance(&samwise), 0);
+    assert_eq!(
+        votes_client.get_past_votes(&samwise, &vote_ledger),
+        token_amount + lp_amount * 2
+    );
+
+    // cannot withdraw more than deposited
+    let result =
+        votes_client.try_withdraw_asset(&samwise, &lp_id, &(lp_amount - withdraw_amount + 1));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(10))));
+
+    // changing the weight applies on the next interaction with the asset
+    votes_client.set_assets(&vec![&e, (lp_id.clone(), 5000u32)]);
+    assert_eq!(votes_client.balance(&samwise), token_amount + lp_units);
+    votes_client.deposit_asset(&samwise, &lp_id, &0);
+    let lp_units = (lp_amount - withdraw_amount) / 2;
+    assert_eq!(votes_client.balance(&samwise), token_amount + lp_units);
+    assert_eq!(votes_client.get_votes(&samwise), token_amount + lp_units);
+
+    // removed assets can no longer be deposited, but can be withdrawn
+    votes_client.set_assets(&Vec::new(&e));
+    let result = votes_client.try_deposit_asset(&samwise, &lp_id, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+
+    votes_client.withdraw_asset(&samwise, &lp_id, &(lp_amount - withdraw_amount));
+    assert_eq!(votes_client.get_asset_balance(&samwise, &lp_id).amount, 0);
+    assert_eq!(votes_client.balance(&samwise), token_amount);
+    assert_eq!(lp_client.balance(&samwise), lp_amount);
+    assert_eq!(lp_client.balance(&votes_id), 0);
+}
+
+#[test]
+fn test_sync_asset() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, _) = create_stellar_token(&e, &bombadil);
+    let (lp_id, lp_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    votes_client.set_assets(&vec![&e, (lp_id.clone(), 20000u32)]);
+
+    let lp_amount = 300 * 10i128.pow(7);
+    lp_client.mint(&samwise, &lp_amount);
+    votes_client.deposit_asset(&samwise, &lp_id, &lp_amount);
+    votes_client.delegate(&samwise, &frodo);
+    assert_eq!(votes_client.balance(&samwise), lp_amount * 2);
+    assert_eq!(votes_client.get_votes(&frodo), lp_amount * 2);
+
+    let vote_ledger = e.ledger().sequence() + 100;
+    votes_client.set_vote_sequence(&vote_ledger);
+    e.jump(200);
+
+    // lowering the weight does not change the account's units until it is synced
+    votes_client.set_assets(&vec![&e, (lp_id.clone(), 5000u32)]);
+    assert_eq!(votes_client.balance(&samwise), lp_amount * 2);
+
+    // anyone can sync the account's units to the lowered weight
+    votes_client.sync_asset(&samwise, &lp_id);
+    assert_eq!(e.auths().len(), 0);
+
+    // validate events
+    let lp_units = lp_amount / 2;
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "sync_asset"),
+                    samwise.clone(),
+                    lp_id.clone()
+                )
+                    .into_val(&e),
+                lp_units.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    let asset_balance = votes_client.get_asset_balance(&samwise, &lp_id);
+    assert_eq!(asset_balance.amount, lp_amount);
+    assert_eq!(asset_balance.units, lp_units);
+    assert_eq!(votes_client.balance(&samwise), lp_units);
+    assert_eq!(votes_client.get_votes(&frodo), lp_units);
+    assert_eq!(votes_client.total_supply(), lp_units);
+    assert_eq!(
+        votes_client.get_past_votes(&frodo, &vote_ledger),
+        lp_amount * 2
+    );
+
+    // syncing a removed asset removes the account's units for it
+    votes_client.set_assets(&Vec::new(&e));
+    votes_client.sync_asset(&samwise, &lp_id);
+    assert_eq!(votes_client.get_asset_balance(&samwise, &lp_id).units, 0);
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), 0);
+
+    // the asset can still be withdrawn
+    votes_client.withdraw_asset(&samwise, &lp_id, &lp_amount);
+    assert_eq!(lp_client.balance(&samwise), lp_amount);
+}
+
+#[test]
+fn test_bonding_assets_unbond() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, _) = create_stellar_token(&e, &bombadil);
+    let (lp_id, lp_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    votes_client.set_assets(&vec![&e, (lp_id.clone(), 10000u32)]);
+    let unbond_delay = 17280;
+    votes_client.set_unbond_delay(&unbond_delay);
+
+    let lp_amount = 300 * 10i128.pow(7);
+    lp_client.mint(&samwise, &lp_amount);
+    votes_client.deposit_asset(&samwise, &lp_id, &lp_amount);
+
+    votes_client.withdraw_asset(&samwise, &lp_id, &lp_amount);
+    let unlock_ledger = e.ledger().sequence() + unbond_delay;
+
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "unbond_asset"),
+                    samwise.clone(),
+                    lp_id.clone()
+                )
+                    .into_val(&e),
+                (lp_amount, unlock_ledger).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(votes_client.get_unbond(&samwise).amount, 0);
+    let unbond = votes_client.get_asset_unbond(&samwise, &lp_id);
+    assert_eq!(unbond.amount, lp_amount);
+    assert_eq!(unbond.unlock_ledger, unlock_ledger);
+
+    e.jump(unbond_delay - 1);
+    let result = votes_client.try_complete_withdraw_asset(&samwise, &lp_id);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+    let result = votes_client.try_complete_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+
+    e.jump(1);
+    let withdrawn = votes_client.complete_withdraw_asset(&samwise, &lp_id);
+    assert_eq!(withdrawn, lp_amount);
+    assert_eq!(lp_client.balance(&samwise), lp_amount);
+    assert_eq!(votes_client.get_asset_unbond(&samwise, &lp_id).amount, 0);
+}
+
+#[test]
+fn test_set_assets_validates_assets() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, _) = create_stellar_token(&e, &bombadil);
+    let (lp_id, _) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    // zero weight
+    let result = votes_client.try_set_assets(&vec![&e, (lp_id.clone(), 0u32)]);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+
+    // underlying token
+    let result = votes_client.try_set_assets(&vec![&e, (token_id.clone(), 10000u32)]);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+
+    // duplicate asset
+    let result = votes_client.try_set_assets(&vec![
+        &e,
+        (lp_id.clone(), 10000u32),
+        (lp_id.clone(), 20000u32),
+    ]);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+
+    // too many assets
+    let mut assets = Vec::new(&e);
+    for _ in 0..11 {
+        assets.push_back((Address::generate(&e), 10000u32));
+    }
+    let result = votes_client.try_set_assets(&assets);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{
+    common::create_stellar_token,
+    env::EnvTestUtils,
+    votes::{create_bonding_token_votes, EmissionPayout},
+};
+
+#[test]
+fn test_claim_to() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let deposit_amount = 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    let t_emission_end = e.ledger().timestamp() + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    token_client.mint(&governor, &tokens_to_emit);
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+
+    // skip 25k seconds (1/4). All emissions will go to samwise
+    e.jump(25000 / 5);
+
+    let claimed = votes_client.claim_to(&samwise, &frodo);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "claim_to"),
+                    vec![&e, samwise.to_val(), frodo.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "claim"), samwise.clone(), frodo.clone()).into_val(&e),
+                claimed.into_val(&e)
+            ),
+        ]
+    );
+
+    // validate chain results - compounded emissions are credited to the recipient
+    assert_eq!(claimed, 25_000 * 10i128.pow(7));
+    assert_eq!(votes_client.balance(&samwise), deposit_amount);
+    assert_eq!(votes_client.balance(&frodo), claimed);
+    assert_eq!(votes_client.get_votes(&frodo), claimed);
+
+    // transferred emissions are sent to the recipient
+    votes_client.set_emis_payout(&EmissionPayout::Transfer(token_id.clone()));
+    e.jump(25000 / 5);
+    let claimed = votes_client.claim_to(&samwise, &frodo);
+    assert_eq!(token_client.balance(&frodo), claimed);
+    assert_eq!(token_client.balance(&samwise), 0);
+}
+
+#[test]
+fn test_claim_for() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let deposit_amount = 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+    token_client.mint(&frodo, &deposit_amount);
+    votes_client.deposit(&frodo, &deposit_amount);
+
+    let t_emission_end = e.ledger().timestamp() + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    token_client.mint(&governor, &tokens_to_emit);
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+
+    votes_client.approve_operator(&samwise, &keeper, &true);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "approve_operator"),
+                    vec![&e, samwise.to_val(), keeper.to_val(), true.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "approve_operator"),
+                    samwise.clone(),
+                    keeper.clone()
+                )
+                    .into_val(&e),
+                true.into_val(&e)
+            ),
+        ]
+    );
+    assert!(votes_client.is_operator(&samwise, &keeper));
+    assert!(!votes_client.is_operator(&frodo, &keeper));
+
+    // skip 50k seconds (1/2). Emissions will be split between samwise and frodo
+    e.jump(50000 / 5);
+
+    let claimed = votes_client.claim_for(&keeper, &samwise);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            keeper.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "claim_for"),
+                    vec![&e, keeper.to_val(), samwise.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "claim"), samwise.clone(), samwise.clone()).into_val(&e),
+                claimed.into_val(&e)
+            ),
+        ]
+    );
+
+    // validate chain results - emissions are claimed to the user
+    assert_eq!(claimed, 25_000 * 10i128.pow(7));
+    assert_eq!(votes_client.balance(&samwise), deposit_amount + claimed);
+    assert_eq!(votes_client.balance(&keeper), 0);
+
+    // keeper is not approved by frodo
+    let result = votes_client.try_claim_for(&keeper, &frodo);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // revoked operators can no longer claim
+    votes_client.approve_operator(&samwise, &keeper, &false);
+    assert!(!votes_client.is_operator(&samwise, &keeper));
+    let result = votes_client.try_claim_for(&keeper, &samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    xdr::{self, ToXdr},
+    Address, BytesN, Env, Error, IntoVal, Symbol, TryFromVal,
+};
+use tests::{env::EnvTestUtils, votes::create_extended_token_votes_wasm};
+
+/// Get the public key and address of the Stellar account for a signing key
+fn account(e: &Env, signer: &SigningKey) -> (BytesN<32>, Address) {
+    let public_key = signer.verifying_key().to_bytes();
+    let sc_address = xdr::ScAddress::Account(xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+        xdr::Uint256(public_key),
+    )));
+    (
+        BytesN::from_array(e, &public_key),
+        Address::try_from_val(e, &sc_address).unwrap(),
+    )
+}
+
+/// Sign a delegation for the votes contract
+fn sign_delegation(
+    e: &Env,
+    signer: &SigningKey,
+    votes_id: &Address,
+    delegatee: &Address,
+    nonce: u64,
+    expiry: u32,
+) -> BytesN<64> {
+    let message = (
+        Symbol::new(e, "delegate_by_sig"),
+        e.ledger().network_id(),
+        votes_id.clone(),
+        delegatee.clone(),
+        nonce,
+        expiry,
+    )
+        .to_xdr(e);
+    let mut message_bytes = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut message_bytes);
+    BytesN::from_array(e, &signer.sign(&message_bytes).to_bytes())
+}
+
+#[test]
+fn test_delegate_by_sig() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let governor = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let (samwise_pubkey, samwise) = account(&e, &signer);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let initial_amount = 100 * 10i128.pow(7);
+    e.mock_all_auths();
+    votes_client.mint(&samwise, &initial_amount);
+    e.set_auths(&[]);
+
+    assert_eq!(votes_client.get_nonce(&samwise), 0);
+
+    let expiry = e.ledger().sequence() + 100;
+    let signature = sign_delegation(&e, &signer, &votes_id, &frodo, 0, expiry);
+    votes_client.delegate_by_sig(&samwise_pubkey, &frodo, &0, &expiry, &signature);
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "delegate"), samwise.clone(), frodo.clone()).into_val(&e),
+                samwise.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    assert_eq!(votes_client.get_nonce(&samwise), 1);
+    assert_eq!(votes_client.get_delegate(&samwise), frodo);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), initial_amount);
+
+    // signature cannot be replayed
+    let result = votes_client.try_delegate_by_sig(&samwise_pubkey, &frodo, &0, &expiry, &signature);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(107))));
+
+    e.jump(10);
+
+    let signature = sign_delegation(&e, &signer, &votes_id, &merry, 1, expiry);
+    votes_client.delegate_by_sig(&samwise_pubkey, &merry, &1, &expiry, &signature);
+
+    assert_eq!(votes_client.get_nonce(&samwise), 2);
+    assert_eq!(votes_client.get_delegate(&samwise), merry);
+    assert_eq!(votes_client.get_votes(&frodo), 0);
+    assert_eq!(votes_client.get_votes(&merry), initial_amount);
+}
+
+#[test]
+fn test_delegate_by_sig_expired() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let (samwise_pubkey, samwise) = account(&e, &signer);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let expiry = e.ledger().sequence() + 100;
+    let signature = sign_delegation(&e, &signer, &votes_id, &frodo, 0, expiry);
+
+    e.jump(101);
+
+    let result = votes_client.try_delegate_by_sig(&samwise_pubkey, &frodo, &0, &expiry, &signature);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(106))));
+    assert_eq!(votes_client.get_nonce(&samwise), 0);
+    assert_eq!(votes_client.get_delegate(&samwise), samwise);
+}
+
+#[test]
+fn test_delegate_by_sig_invalid_nonce() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let (samwise_pubkey, _) = account(&e, &signer);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let expiry = e.ledger().sequence() + 100;
+    let signature = sign_delegation(&e, &signer, &votes_id, &frodo, 1, expiry);
+
+    let result = votes_client.try_delegate_by_sig(&samwise_pubkey, &frodo, &1, &expiry, &signature);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(107))));
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_delegate_by_sig_wrong_delegatee() {
+    let e = Env::default();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let governor = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let (samwise_pubkey, _) = account(&e, &signer);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let expiry = e.ledger().sequence() + 100;
+    let signature = sign_delegation(&e, &signer, &votes_id, &frodo, 0, expiry);
+
+    votes_client.delegate_by_sig(&samwise_pubkey, &merry, &0, &expiry, &signature);
+}
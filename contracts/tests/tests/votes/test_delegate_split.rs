@@ -0,0 +1,249 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol, Vec,
+};
+use tests::{
+    common::create_stellar_token,
+    env::EnvTestUtils,
+    votes::{create_bonding_token_votes, create_extended_token_votes_wasm},
+};
+
+#[test]
+fn test_delegate_split() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (votes_id, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let vote_ledger = e.ledger().sequence() + 15;
+    votes_client.set_vote_sequence(&vote_ledger);
+
+    let initial_amount = 1_000 * 10i128.pow(7) + 1;
+    votes_client.mint(&samwise, &initial_amount);
+
+    e.jump(10);
+
+    let split = vec![
+        &e,
+        (frodo.clone(), 3333u32),
+        (merry.clone(), 3333u32),
+        (pippin.clone(), 3334u32),
+    ];
+    votes_client.delegate_split(&samwise, &split);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "delegate_split"),
+                    vec![&e, samwise.to_val(), split.to_val(),]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "delegate_split"), samwise.clone()).into_val(&e),
+                split.to_val()
+            )
+        ]
+    );
+
+    // validate chain results
+    let frodo_share = initial_amount * 3333 / 10000;
+    let merry_share = initial_amount * 3333 / 10000;
+    let pippin_share = initial_amount - frodo_share - merry_share;
+    assert_eq!(votes_client.get_delegate_split(&samwise), split);
+    assert_eq!(votes_client.get_delegate(&samwise), pippin);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), frodo_share);
+    assert_eq!(votes_client.get_votes(&merry), merry_share);
+    assert_eq!(votes_client.get_votes(&pippin), pippin_share);
+    assert_eq!(votes_client.total_supply(), initial_amount);
+
+    e.jump(10);
+
+    // balance changes are spread between delegatees
+    let mint_amount = 500 * 10i128.pow(7);
+    votes_client.mint(&samwise, &mint_amount);
+    let transfer_amount = 123 * 10i128.pow(7) + 4567891;
+    votes_client.transfer(&samwise, &bombadil, &transfer_amount);
+    let burn_amount = 50 * 10i128.pow(7) + 7;
+    votes_client.burn(&samwise, &burn_amount);
+
+    let balance = initial_amount + mint_amount - transfer_amount - burn_amount;
+    let frodo_share = balance * 3333 / 10000;
+    let merry_share = balance * 3333 / 10000;
+    let pippin_share = balance - frodo_share - merry_share;
+    assert_eq!(votes_client.balance(&samwise), balance);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), frodo_share);
+    assert_eq!(votes_client.get_votes(&merry), merry_share);
+    assert_eq!(votes_client.get_votes(&pippin), pippin_share);
+    assert_eq!(votes_client.get_votes(&bombadil), transfer_amount);
+
+    e.jump(100);
+
+    // validate checkpoints were recorded for each delegatee
+    let frodo_share_past = initial_amount * 3333 / 10000;
+    let pippin_share_past = initial_amount - 2 * frodo_share_past;
+    assert_eq!(votes_client.get_past_votes(&samwise, &vote_ledger), 0);
+    assert_eq!(
+        votes_client.get_past_votes(&frodo, &vote_ledger),
+        frodo_share_past
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&merry, &vote_ledger),
+        frodo_share_past
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&pippin, &vote_ledger),
+        pippin_share_past
+    );
+
+    // delegating to a single address removes the split
+    votes_client.delegate(&samwise, &merry);
+
+    assert_eq!(
+        votes_client.get_delegate_split(&samwise),
+        vec![&e, (merry.clone(), 10000u32)]
+    );
+    assert_eq!(votes_client.get_delegate(&samwise), merry);
+    assert_eq!(votes_client.get_votes(&frodo), 0);
+    assert_eq!(votes_client.get_votes(&merry), balance);
+    assert_eq!(votes_client.get_votes(&pippin), 0);
+}
+
+#[test]
+fn test_delegate_split_change_split() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let initial_balance = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+    votes_client.deposit(&samwise, &initial_balance);
+
+    votes_client.delegate(&samwise, &frodo);
+    assert_eq!(votes_client.get_votes(&frodo), initial_balance);
+
+    votes_client.delegate_split(
+        &samwise,
+        &vec![&e, (frodo.clone(), 2500u32), (merry.clone(), 7500u32)],
+    );
+    assert_eq!(votes_client.get_delegate(&samwise), merry);
+    assert_eq!(votes_client.get_votes(&frodo), initial_balance / 4);
+    assert_eq!(votes_client.get_votes(&merry), initial_balance * 3 / 4);
+
+    votes_client.delegate_split(
+        &samwise,
+        &vec![&e, (pippin.clone(), 5000u32), (merry.clone(), 5000u32)],
+    );
+    assert_eq!(votes_client.get_delegate(&samwise), pippin);
+    assert_eq!(votes_client.get_votes(&frodo), 0);
+    assert_eq!(votes_client.get_votes(&merry), initial_balance / 2);
+    assert_eq!(votes_client.get_votes(&pippin), initial_balance / 2);
+
+    // withdrawing removes votes from each delegatee
+    votes_client.withdraw(&samwise, &(initial_balance / 2));
+    assert_eq!(votes_client.get_votes(&merry), initial_balance / 4);
+    assert_eq!(votes_client.get_votes(&pippin), initial_balance / 4);
+    assert_eq!(votes_client.total_supply(), initial_balance / 2);
+
+    // a split with a single delegatee is a normal delegation
+    votes_client.delegate_split(&samwise, &vec![&e, (frodo.clone(), 10000u32)]);
+    assert_eq!(votes_client.get_delegate(&samwise), frodo);
+    assert_eq!(
+        votes_client.get_delegate_split(&samwise),
+        vec![&e, (frodo.clone(), 10000u32)]
+    );
+    assert_eq!(votes_client.get_votes(&frodo), initial_balance / 2);
+    assert_eq!(votes_client.get_votes(&merry), 0);
+    assert_eq!(votes_client.get_votes(&pippin), 0);
+
+    let result = votes_client.try_delegate(&samwise, &frodo);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(101))));
+}
+
+#[test]
+fn test_delegate_split_validates_split() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, _) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    // no delegatees
+    let result = votes_client.try_delegate_split(&samwise, &Vec::new(&e));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // shares do not sum to 10000
+    let result = votes_client.try_delegate_split(
+        &samwise,
+        &vec![&e, (frodo.clone(), 5000u32), (merry.clone(), 4999u32)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // zero share
+    let result = votes_client.try_delegate_split(
+        &samwise,
+        &vec![&e, (frodo.clone(), 10000u32), (merry.clone(), 0u32)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // duplicate delegatee
+    let result = votes_client.try_delegate_split(
+        &samwise,
+        &vec![&e, (frodo.clone(), 5000u32), (frodo.clone(), 5000u32)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // too many delegatees
+    let mut split = Vec::new(&e);
+    for _ in 0..11 {
+        split.push_back((Address::generate(&e), 900u32));
+    }
+    split.set(10, (Address::generate(&e), 1000u32));
+    let result = votes_client.try_delegate_split(&samwise, &split);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use tests::{env::EnvTestUtils, votes::create_extended_token_votes_wasm};
+
+#[test]
+fn test_delegators() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (_, votes_client) = create_extended_token_votes_wasm(&e, &bombadil, &governor);
+
+    let amount = 1_000 * 10i128.pow(7);
+    votes_client.mint(&samwise, &amount);
+    votes_client.mint(&merry, &amount);
+
+    // accounts without a balance are not indexed
+    votes_client.delegate(&pippin, &frodo);
+    votes_client.delegate(&samwise, &frodo);
+    votes_client.delegate(&merry, &frodo);
+    assert_eq!(votes_client.delegator_count(&frodo), 2);
+    assert_eq!(
+        votes_client.get_delegators(&frodo, &0, &10),
+        vec![&e, samwise.clone(), merry.clone()]
+    );
+    assert_eq!(
+        votes_client.get_delegators(&frodo, &1, &10),
+        vec![&e, merry.clone()]
+    );
+    assert_eq!(
+        votes_client.get_delegators(&frodo, &0, &1),
+        vec![&e, samwise.clone()]
+    );
+    assert_eq!(votes_client.get_delegators(&frodo, &5, &1).len(), 0);
+
+    e.jump(10);
+
+    // balance changes add and remove delegators
+    votes_client.transfer(&samwise, &pippin, &amount);
+    assert_eq!(votes_client.delegator_count(&frodo), 2);
+    assert_eq!(
+        votes_client.get_delegators(&frodo, &0, &10),
+        vec![&e, merry.clone(), pippin.clone()]
+    );
+
+    e.jump(10);
+
+    // splits index every delegatee
+    votes_client.delegate_split(
+        &merry,
+        &vec![&e, (frodo.clone(), 5000u32), (bombadil.clone(), 5000u32)],
+    );
+    assert_eq!(votes_client.delegator_count(&frodo), 2);
+    assert_eq!(votes_client.delegator_count(&bombadil), 1);
+    assert_eq!(
+        votes_client.get_delegators(&bombadil, &0, &10),
+        vec![&e, merry.clone()]
+    );
+
+    e.jump(10);
+
+    // delegating to self removes the account from the index
+    votes_client.delegate(&merry, &merry);
+    assert_eq!(votes_client.delegator_count(&frodo), 1);
+    assert_eq!(votes_client.delegator_count(&bombadil), 0);
+    assert_eq!(votes_client.delegator_count(&merry), 0);
+    assert_eq!(
+        votes_client.get_delegators(&frodo, &0, &10),
+        vec![&e, pippin.clone()]
+    );
+
+    e.jump(10);
+
+    votes_client.burn(&pippin, &amount);
+    assert_eq!(votes_client.delegator_count(&frodo), 0);
+    assert_eq!(votes_client.get_delegators(&frodo, &0, &10).len(), 0);
+}
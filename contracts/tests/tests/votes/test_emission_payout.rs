@@ -0,0 +1,157 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{
+    common::create_stellar_token,
+    env::EnvTestUtils,
+    votes::{create_bonding_token_votes, EmissionPayout},
+};
+
+#[test]
+fn test_emission_payout_transfer_underlying() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let payout = EmissionPayout::Transfer(token_id.clone());
+    votes_client.set_emis_payout(&payout);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            governor.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "set_emis_payout"),
+                    vec![&e, payout.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "set_emission_payout"),).into_val(&e),
+                payout.into_val(&e)
+            ),
+        ]
+    );
+    assert_eq!(votes_client.get_emis_payout(), payout);
+
+    let deposit_amount = 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    let t_emission_end = e.ledger().timestamp() + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    token_client.mint(&governor, &tokens_to_emit);
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+    assert_eq!(votes_client.get_emis_reserve(), Some(tokens_to_emit));
+
+    // skip 50k seconds (1/2). All emissions will go to samwise
+    e.jump(50000 / 5);
+
+    let claimed = votes_client.claim(&samwise);
+
+    // validate chain results - claimed tokens are transferred and voting power does not compound
+    assert_eq!(claimed, 50_000 * 10i128.pow(7));
+    assert_eq!(token_client.balance(&samwise), claimed);
+    assert_eq!(votes_client.balance(&samwise), deposit_amount);
+    assert_eq!(votes_client.get_votes(&samwise), deposit_amount);
+    assert_eq!(votes_client.total_supply(), deposit_amount);
+
+    // tokens held for emissions are reconciled separately from deposits
+    assert_eq!(
+        votes_client.get_emis_reserve(),
+        Some(tokens_to_emit - claimed)
+    );
+    assert_eq!(
+        token_client.balance(&votes_id),
+        votes_client.total_supply() + votes_client.get_emis_reserve().unwrap()
+    );
+
+    // compounding can be re-enabled, as the emissions are still paid in the underlying token
+    votes_client.set_emis_payout(&EmissionPayout::Compound);
+    e.jump(50000 / 5);
+    let claimed_2 = votes_client.claim(&samwise);
+    assert_eq!(claimed_2, 50_000 * 10i128.pow(7));
+    assert_eq!(token_client.balance(&samwise), claimed);
+    assert_eq!(votes_client.balance(&samwise), deposit_amount + claimed_2);
+    assert_eq!(votes_client.get_emis_reserve(), Some(0));
+    assert_eq!(
+        token_client.balance(&votes_id),
+        votes_client.total_supply() + votes_client.get_emis_reserve().unwrap()
+    );
+}
+
+#[test]
+fn test_emission_payout_reward_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (reward_id, reward_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    votes_client.set_emis_payout(&EmissionPayout::Transfer(reward_id.clone()));
+
+    let deposit_amount = 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    // emissions are funded with the reward token
+    let t_emission_end = e.ledger().timestamp() + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    reward_client.mint(&governor, &tokens_to_emit);
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+    assert_eq!(reward_client.balance(&votes_id), tokens_to_emit);
+    assert_eq!(token_client.balance(&votes_id), deposit_amount);
+
+    e.jump(50000 / 5);
+
+    let claimed = votes_client.claim(&samwise);
+    assert_eq!(claimed, 50_000 * 10i128.pow(7));
+    assert_eq!(reward_client.balance(&samwise), claimed);
+    assert_eq!(votes_client.balance(&samwise), deposit_amount);
+    assert_eq!(
+        votes_client.get_emis_reserve(),
+        Some(tokens_to_emit - claimed)
+    );
+    assert_eq!(
+        reward_client.balance(&votes_id),
+        votes_client.get_emis_reserve().unwrap()
+    );
+
+    // the payout token cannot be changed once emissions are set
+    let result = votes_client.try_set_emis_payout(&EmissionPayout::Compound);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+
+    // the payout token cannot be used for an emission program
+    reward_client.mint(&governor, &tokens_to_emit);
+    let result = votes_client.try_set_emis_program(&reward_id, &tokens_to_emit, &t_emission_end);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+}
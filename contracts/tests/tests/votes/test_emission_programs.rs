@@ -0,0 +1,258 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{
+    common::create_stellar_token,
+    env::EnvTestUtils,
+    votes::{create_bonding_token_votes, create_bonding_token_votes_wasm},
+};
+
+#[test]
+fn test_emission_programs() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (reward_id, reward_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let initial_balance = 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+    token_client.mint(&frodo, &initial_balance);
+    votes_client.deposit(&samwise, &initial_balance);
+
+    let t_emission_end = e.ledger().timestamp() + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    let rewards_to_emit = 200_000 * 10i128.pow(7);
+    token_client.mint(&governor, &tokens_to_emit);
+    reward_client.mint(&governor, &rewards_to_emit);
+
+    // emit 100k vote tokens and 200k reward tokens over 100k seconds
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+    votes_client.set_emis_program(&reward_id, &rewards_to_emit, &t_emission_end);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            governor.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "set_emis_program"),
+                    vec![
+                        &e,
+                        reward_id.to_val(),
+                        rewards_to_emit.into_val(&e),
+                        t_emission_end.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        reward_id.clone(),
+                        Symbol::new(&e, "transfer"),
+                        vec![
+                            &e,
+                            governor.to_val(),
+                            votes_id.to_val(),
+                            rewards_to_emit.into_val(&e)
+                        ]
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "set_program_emissions"),
+                    reward_id.clone(),
+                    2_0000000u64,
+                    t_emission_end
+                )
+                    .into_val(&e),
+                ().into_val(&e)
+            ),
+        ]
+    );
+    assert_eq!(
+        votes_client.get_emis_programs(),
+        vec![&e, reward_id.clone()]
+    );
+    assert_eq!(reward_client.balance(&votes_id), rewards_to_emit);
+
+    // skip 50k seconds (1/2). All emissions will go to samwise
+    e.jump(50000 / 5);
+
+    // frodo deposits an equal amount as samwise
+    votes_client.deposit(&frodo, &initial_balance);
+
+    // skip 50k seconds (2/2). Emissions will be split between samwise and frodo
+    e.jump(50000 / 5);
+
+    // samwise claims his reward tokens
+    let claimed = votes_client.claim_program(&samwise, &reward_id);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "claim_program"),
+                    vec![&e, samwise.to_val(), reward_id.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = vec![&e, events.last().unwrap()];
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (
+                    Symbol::new(&e, "claim_program"),
+                    samwise.clone(),
+                    reward_id.clone()
+                )
+                    .into_val(&e),
+                claimed.into_val(&e)
+            ),
+        ]
+    );
+
+    // validate chain results - reward tokens do not change voting power
+    assert_eq!(claimed, 150_000 * 10i128.pow(7));
+    assert_eq!(reward_client.balance(&samwise), claimed);
+    assert_eq!(votes_client.balance(&samwise), initial_balance);
+
+    // the vote token emissions are claimed independently
+    let claimed_votes = votes_client.claim(&samwise);
+    assert_eq!(claimed_votes, 75_000 * 10i128.pow(7));
+    assert_eq!(
+        votes_client.balance(&samwise),
+        initial_balance + claimed_votes
+    );
+
+    // frodo claims his reward tokens
+    let claimed = votes_client.claim_program(&frodo, &reward_id);
+    assert_eq!(claimed, 50_000 * 10i128.pow(7));
+    assert_eq!(reward_client.balance(&frodo), claimed);
+    assert_eq!(reward_client.balance(&votes_id), 0);
+
+    // nothing left to claim
+    assert_eq!(votes_client.claim_program(&samwise, &reward_id), 0);
+}
+
+#[test]
+fn test_set_emis_program_validates_program() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let tokens = 1_000 * 10i128.pow(7);
+    let expiration = e.ledger().timestamp() + 100_000;
+
+    // underlying token
+    token_client.mint(&governor, &tokens);
+    let result = votes_client.try_set_emis_program(&token_id, &tokens, &expiration);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+
+    // too many programs
+    for _ in 0..5 {
+        let (reward_id, reward_client) = create_stellar_token(&e, &bombadil);
+        reward_client.mint(&governor, &tokens);
+        votes_client.set_emis_program(&reward_id, &tokens, &expiration);
+    }
+    assert_eq!(votes_client.get_emis_programs().len(), 5);
+    let (reward_id, reward_client) = create_stellar_token(&e, &bombadil);
+    reward_client.mint(&governor, &tokens);
+    let result = votes_client.try_set_emis_program(&reward_id, &tokens, &expiration);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+}
+
+#[test]
+fn test_emission_programs_fit_transaction_budget() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes_wasm(&e, &token_id, &governor);
+
+    let initial_balance = 10i128.pow(7);
+    token_client.mint(&samwise, &(2 * initial_balance));
+    token_client.mint(&frodo, &initial_balance);
+    votes_client.deposit(&samwise, &initial_balance);
+    votes_client.deposit(&frodo, &initial_balance);
+
+    // run the vote token emissions and the max number of emission programs
+    let tokens = 1_000 * 10i128.pow(7);
+    let expiration = e.ledger().timestamp() + 100_000;
+    token_client.mint(&governor, &tokens);
+    votes_client.set_emis(&tokens, &expiration);
+    let mut reward_ids = std::vec![];
+    for _ in 0..5 {
+        let (reward_id, reward_client) = create_stellar_token(&e, &bombadil);
+        reward_client.mint(&governor, &tokens);
+        votes_client.set_emis_program(&reward_id, &tokens, &expiration);
+        reward_ids.push(reward_id);
+    }
+
+    e.jump(10_000 / 5);
+
+    // a balance change updates every program for the user within a single transaction budget
+    e.budget().reset_default();
+    votes_client.deposit(&samwise, &initial_balance);
+    assert!(e.budget().cpu_instruction_cost() < 100_000_000);
+    assert!(e.budget().memory_bytes_cost() < 40 * 1024 * 1024);
+
+    e.jump(10_000 / 5);
+
+    e.budget().reset_default();
+    votes_client.claim(&frodo);
+    assert!(e.budget().cpu_instruction_cost() < 100_000_000);
+    assert!(e.budget().memory_bytes_cost() < 40 * 1024 * 1024);
+
+    e.budget().reset_default();
+    votes_client.claim_program(&frodo, &reward_ids[4]);
+    assert!(e.budget().cpu_instruction_cost() < 100_000_000);
+    assert!(e.budget().memory_bytes_cost() < 40 * 1024 * 1024);
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use tests::{common::create_stellar_token, env::EnvTestUtils, votes::create_bonding_token_votes};
+
+#[test]
+fn test_emission_views() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    assert!(votes_client.emission_config().is_none());
+    assert!(votes_client.emission_data().is_none());
+    assert_eq!(votes_client.pending_emissions(&samwise), 0);
+    assert_eq!(votes_client.emissions_per_vote_unit_per_sec(), 0);
+
+    let deposit_amount = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &deposit_amount);
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    let t_start = e.ledger().timestamp();
+    let t_emission_end = t_start + 100_000;
+    let tokens_to_emit = 100_000 * 10i128.pow(7);
+    token_client.mint(&governor, &tokens_to_emit);
+    votes_client.set_emis(&tokens_to_emit, &t_emission_end);
+
+    let emis_config = votes_client.emission_config().unwrap();
+    assert_eq!(emis_config.eps, 1_0000000);
+    assert_eq!(emis_config.expiration, t_emission_end);
+    // 1 token per second split over 1000 vote tokens, or 0.001 with 7 decimals
+    assert_eq!(votes_client.emissions_per_vote_unit_per_sec(), 10000);
+
+    // skip 25k seconds (1/4). All emissions will go to samwise
+    e.jump(25000 / 5);
+
+    let emis_data = votes_client.emission_data().unwrap();
+    assert_eq!(emis_data.index, 25000 * 10000);
+    assert_eq!(emis_data.last_time, e.ledger().timestamp());
+    assert_eq!(
+        votes_client.pending_emissions(&samwise),
+        25_000 * 10i128.pow(7)
+    );
+    assert_eq!(votes_client.pending_emissions(&frodo), 0);
+
+    // frodo deposits an equal amount as samwise, halving the emissions per vote token
+    token_client.mint(&frodo, &deposit_amount);
+    votes_client.deposit(&frodo, &deposit_amount);
+    assert_eq!(votes_client.emissions_per_vote_unit_per_sec(), 5000);
+
+    e.jump(25000 / 5);
+    assert_eq!(
+        votes_client.pending_emissions(&samwise),
+        37_500 * 10i128.pow(7)
+    );
+    assert_eq!(
+        votes_client.pending_emissions(&frodo),
+        12_500 * 10i128.pow(7)
+    );
+
+    // views match the claimed amount
+    let pending = votes_client.pending_emissions(&samwise);
+    assert_eq!(votes_client.claim(&samwise), pending);
+    assert_eq!(votes_client.pending_emissions(&samwise), 0);
+
+    // no emissions after expiration
+    e.jump(60000 / 5);
+    assert_eq!(votes_client.emissions_per_vote_unit_per_sec(), 0);
+}
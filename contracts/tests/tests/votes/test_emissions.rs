@@ -10,6 +10,7 @@ use tests::{
 #[test]
 fn test_emissions() {
     let e = Env::default();
+    e.budget().reset_unlimited();
     e.mock_all_auths();
     e.set_default_info();
 
@@ -142,7 +143,7 @@ fn test_emissions() {
             &e,
             (
                 votes_id.clone(),
-                (Symbol::new(&e, "claim"), samwise.clone()).into_val(&e),
+                (Symbol::new(&e, "claim"), samwise.clone(), samwise.clone()).into_val(&e),
                 claimed.into_val(&e)
             ),
         ]
@@ -452,3 +452,73 @@ fn test_get_past_transfer_same_user() {
         initial_balance
     );
 }
+
+#[test]
+fn test_get_past_delegate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let cur_ledger = e.ledger().sequence();
+    votes_client.set_vote_sequence(&(cur_ledger + 99));
+    votes_client.set_vote_sequence(&(cur_ledger + 199));
+
+    let initial_balance = 100 * 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+    votes_client.deposit(&samwise, &initial_balance);
+
+    e.jump(100);
+
+    // delegate after the first vote ledger
+    votes_client.delegate(&samwise, &frodo);
+
+    e.jump(50);
+
+    // delegate twice before the second vote ledger, only the last is needed
+    votes_client.delegate(&samwise, &pippin);
+    e.jump(10);
+    votes_client.delegate(&samwise, &frodo);
+
+    e.jump(100);
+
+    votes_client.delegate(&samwise, &pippin);
+    let last_delegate_ledger = e.ledger().sequence();
+
+    e.jump(1);
+
+    assert_eq!(
+        votes_client.get_past_delegate(&samwise, &(cur_ledger + 99)),
+        samwise
+    );
+    assert_eq!(
+        votes_client.get_past_delegate(&samwise, &(cur_ledger + 199)),
+        frodo
+    );
+    assert_eq!(
+        votes_client.get_past_delegate(&samwise, &last_delegate_ledger),
+        pippin
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&frodo, &(cur_ledger + 199)),
+        initial_balance
+    );
+
+    // accounts that never delegated are self delegated
+    assert_eq!(
+        votes_client.get_past_delegate(&frodo, &(cur_ledger + 199)),
+        frodo
+    );
+
+    // sequence must be closed
+    let result = votes_client.try_get_past_delegate(&samwise, &e.ledger().sequence());
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+}
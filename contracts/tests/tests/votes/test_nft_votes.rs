@@ -0,0 +1,122 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{env::EnvTestUtils, mocks::create_mock_nft_wasm, votes::create_nft_token_votes_wasm};
+
+#[test]
+fn test_nft_deposit_and_withdraw() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (nft_id, nft_client) = create_mock_nft_wasm(&e);
+    let (votes_id, votes_client) = create_nft_token_votes_wasm(&e, &nft_id, &governor);
+    assert_eq!(votes_client.collection(), nft_id);
+    assert_eq!(votes_client.decimals(), 0);
+
+    nft_client.mint(&samwise, &1);
+    nft_client.mint(&samwise, &2);
+    nft_client.mint(&frodo, &3);
+
+    votes_client.deposit(&samwise, &1);
+    let events = e.events().all();
+    let event = vec![&e, events.last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "deposit"), samwise.clone()).into_val(&e),
+                1u32.into_val(&e)
+            )
+        ]
+    );
+    votes_client.deposit(&samwise, &2);
+    votes_client.deposit(&frodo, &3);
+
+    assert_eq!(nft_client.owner_of(&1), Some(votes_id.clone()));
+    assert_eq!(nft_client.owner_of(&3), Some(votes_id.clone()));
+    assert_eq!(votes_client.get_owner(&1), Some(samwise.clone()));
+    assert_eq!(votes_client.get_owner(&3), Some(frodo.clone()));
+    assert_eq!(votes_client.get_owner(&4), None);
+    assert_eq!(votes_client.balance(&samwise), 2);
+    assert_eq!(votes_client.balance(&frodo), 1);
+    assert_eq!(votes_client.get_votes(&samwise), 2);
+    assert_eq!(votes_client.get_votes(&frodo), 1);
+    assert_eq!(votes_client.total_supply(), 3);
+
+    // only the depositor can withdraw an NFT
+    let result = votes_client.try_withdraw(&frodo, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    votes_client.withdraw(&samwise, &1);
+    let events = e.events().all();
+    let event = vec![&e, events.last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "withdraw"), samwise.clone()).into_val(&e),
+                1u32.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(nft_client.owner_of(&1), Some(samwise.clone()));
+    assert_eq!(votes_client.get_owner(&1), None);
+    assert_eq!(votes_client.balance(&samwise), 1);
+    assert_eq!(votes_client.get_votes(&samwise), 1);
+    assert_eq!(votes_client.total_supply(), 2);
+
+    // an NFT can't be withdrawn twice
+    let result = votes_client.try_withdraw(&samwise, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+}
+
+#[test]
+fn test_nft_votes_delegation_and_past_votes() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (nft_id, nft_client) = create_mock_nft_wasm(&e);
+    let (_, votes_client) = create_nft_token_votes_wasm(&e, &nft_id, &governor);
+
+    let cur_ledger = e.ledger().sequence();
+    votes_client.set_vote_sequence(&(cur_ledger + 99));
+
+    nft_client.mint(&samwise, &1);
+    nft_client.mint(&samwise, &2);
+    votes_client.deposit(&samwise, &1);
+    votes_client.deposit(&samwise, &2);
+
+    votes_client.delegate(&samwise, &frodo);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), 2);
+
+    e.jump(100);
+
+    votes_client.withdraw(&samwise, &2);
+    assert_eq!(votes_client.get_votes(&frodo), 1);
+    assert_eq!(votes_client.total_supply(), 1);
+
+    e.jump(1);
+
+    assert_eq!(votes_client.get_past_votes(&frodo, &(cur_ledger + 99)), 2);
+    assert_eq!(votes_client.get_past_total_supply(&(cur_ledger + 99)), 2);
+    assert_eq!(votes_client.get_past_votes(&samwise, &(cur_ledger + 99)), 0);
+}
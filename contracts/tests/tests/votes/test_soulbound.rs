@@ -0,0 +1,148 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{env::EnvTestUtils, votes::create_soulbound_token_votes_wasm};
+
+#[test]
+fn test_soulbound_not_transferable() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (_, votes_client) = create_soulbound_token_votes_wasm(&e, &bombadil, &governor);
+
+    let amount = 100 * 10i128.pow(7);
+    votes_client.mint(&samwise, &amount);
+    assert_eq!(votes_client.balance(&samwise), amount);
+    assert_eq!(votes_client.get_votes(&samwise), amount);
+
+    let result = votes_client.try_transfer(&samwise, &frodo, &amount);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(114))));
+
+    let result =
+        votes_client.try_approve(&samwise, &frodo, &amount, &(e.ledger().sequence() + 100));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(114))));
+
+    let result = votes_client.try_transfer_from(&frodo, &samwise, &frodo, &amount);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(114))));
+
+    // voting power can still be delegated
+    votes_client.delegate(&samwise, &frodo);
+    assert_eq!(votes_client.get_votes(&frodo), amount);
+
+    // admin can revoke reputation, even if it is locked by a vote
+    votes_client.lock(&samwise, &(e.ledger().sequence() + 1000));
+    votes_client.clawback(&samwise, &amount);
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&frodo), 0);
+    assert_eq!(votes_client.total_supply(), 0);
+}
+
+#[test]
+fn test_soulbound_expire() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (votes_id, votes_client) = create_soulbound_token_votes_wasm(&e, &bombadil, &governor);
+
+    // reputation does not expire by default
+    let amount = 100 * 10i128.pow(7);
+    votes_client.mint(&frodo, &amount);
+    assert_eq!(votes_client.get_expiry_period(), 0);
+    assert_eq!(votes_client.get_expiration(&frodo), 0);
+    let result = votes_client.try_expire(&frodo);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(115))));
+
+    let period = 1000;
+    votes_client.set_expiry_period(&period);
+    assert_eq!(votes_client.get_expiry_period(), period);
+
+    votes_client.mint(&samwise, &amount);
+    let expiration = e.ledger().sequence() + period;
+    assert_eq!(votes_client.get_expiration(&samwise), expiration);
+
+    // minting again resets the expiration
+    e.jump(500);
+    votes_client.mint(&samwise, &amount);
+    let expiration = e.ledger().sequence() + period;
+    assert_eq!(votes_client.get_expiration(&samwise), expiration);
+
+    e.jump(period - 1);
+    let result = votes_client.try_expire(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(115))));
+
+    e.jump(1);
+    let expired = votes_client.expire(&samwise);
+    assert_eq!(expired, 2 * amount);
+
+    // validate events
+    let events = e.events().all();
+    let event = vec![&e, events.last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "expire"), samwise.clone()).into_val(&e),
+                expired.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.get_expiration(&samwise), 0);
+    assert_eq!(votes_client.total_supply(), amount);
+}
+
+#[test]
+fn test_soulbound_expire_while_locked() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (_, votes_client) = create_soulbound_token_votes_wasm(&e, &bombadil, &governor);
+
+    let period = 1000;
+    votes_client.set_expiry_period(&period);
+    let amount = 100 * 10i128.pow(7);
+    votes_client.mint(&samwise, &amount);
+
+    // a vote lock that outlasts the reputation prevents it from expiring until the lock ends
+    let lock_until = e.ledger().sequence() + 2 * period;
+    votes_client.lock(&samwise, &lock_until);
+    e.jump(period);
+    let result = votes_client.try_expire(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(112))));
+    assert_eq!(votes_client.balance(&samwise), amount);
+    assert_eq!(votes_client.get_votes(&samwise), amount);
+
+    e.jump(lock_until - e.ledger().sequence());
+    let expired = votes_client.expire(&samwise);
+    assert_eq!(expired, amount);
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.total_supply(), 0);
+}
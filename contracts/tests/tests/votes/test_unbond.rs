@@ -0,0 +1,193 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{common::create_stellar_token, env::EnvTestUtils, votes::create_bonding_token_votes};
+
+#[test]
+fn test_withdraw_with_unbond_delay() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    let unbond_delay = 7 * 17280;
+    votes_client.set_unbond_delay(&unbond_delay);
+    assert_eq!(votes_client.get_unbond_delay(), unbond_delay);
+
+    let initial_balance = 100_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+
+    let deposit_amount = 123_7654321;
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    e.jump(1000);
+
+    let withdraw_amount = 100 * 10i128.pow(7);
+    votes_client.withdraw(&samwise, &withdraw_amount);
+    let unlock_ledger = e.ledger().sequence() + unbond_delay;
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "unbond"), samwise.clone()).into_val(&e),
+                (withdraw_amount, unlock_ledger).into_val(&e)
+            )
+        ]
+    );
+
+    // validate voting power is removed but tokens are not returned
+    let unbond = votes_client.get_unbond(&samwise);
+    assert_eq!(unbond.amount, withdraw_amount);
+    assert_eq!(unbond.unlock_ledger, unlock_ledger);
+    assert_eq!(
+        votes_client.balance(&samwise),
+        deposit_amount - withdraw_amount
+    );
+    assert_eq!(
+        votes_client.get_votes(&samwise),
+        deposit_amount - withdraw_amount
+    );
+    assert_eq!(
+        votes_client.total_supply(),
+        deposit_amount - withdraw_amount
+    );
+    assert_eq!(
+        token_client.balance(&samwise),
+        initial_balance - deposit_amount
+    );
+    assert_eq!(token_client.balance(&votes_id), deposit_amount);
+
+    // withdrawing again adds to the unbond and restarts the delay
+    e.jump(1000);
+    let withdraw_amount_2 = 20 * 10i128.pow(7);
+    votes_client.withdraw(&samwise, &withdraw_amount_2);
+    let unlock_ledger = e.ledger().sequence() + unbond_delay;
+
+    let unbond = votes_client.get_unbond(&samwise);
+    assert_eq!(unbond.amount, withdraw_amount + withdraw_amount_2);
+    assert_eq!(unbond.unlock_ledger, unlock_ledger);
+
+    // unbond cannot be completed early
+    e.jump(unbond_delay - 1);
+    let result = votes_client.try_complete_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+
+    e.jump(1);
+    let withdrawn = votes_client.complete_withdraw(&samwise);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "complete_withdraw"),
+                    vec![&e, samwise.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "withdraw"), samwise.clone()).into_val(&e),
+                withdrawn.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    assert_eq!(withdrawn, withdraw_amount + withdraw_amount_2);
+    assert_eq!(votes_client.get_unbond(&samwise).amount, 0);
+    assert_eq!(
+        token_client.balance(&samwise),
+        initial_balance - deposit_amount + withdrawn
+    );
+    assert_eq!(token_client.balance(&votes_id), deposit_amount - withdrawn);
+
+    // nothing left to withdraw
+    let result = votes_client.try_complete_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+}
+
+#[test]
+fn test_set_unbond_delay() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, _) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_bonding_token_votes(&e, &token_id, &governor);
+
+    assert_eq!(votes_client.get_unbond_delay(), 0);
+
+    let unbond_delay = 17280;
+    votes_client.set_unbond_delay(&unbond_delay);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            governor.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "set_unbond_delay"),
+                    vec![&e, unbond_delay.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "set_unbond_delay"),).into_val(&e),
+                unbond_delay.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(votes_client.get_unbond_delay(), unbond_delay);
+
+    // delay cannot exceed 30 days
+    let result = votes_client.try_set_unbond_delay(&(30 * 17280 + 1));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(111))));
+
+    // a delay of 0 disables unbonding
+    votes_client.set_unbond_delay(&0);
+    assert_eq!(votes_client.get_unbond_delay(), 0);
+}
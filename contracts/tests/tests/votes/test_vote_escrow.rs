@@ -0,0 +1,299 @@
+#[cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+use tests::{
+    common::create_stellar_token, env::EnvTestUtils, votes::create_escrow_token_votes_wasm,
+};
+
+const LOCK_PERIOD_LEDGERS: u32 = 7 * 17280;
+const MAX_LOCK_LEDGERS: u32 = 52 * LOCK_PERIOD_LEDGERS;
+
+/// Get the expected voting power of a lock at a ledger
+fn lock_power(amount: i128, end: u32, ledger: u32) -> i128 {
+    if end <= ledger {
+        return 0;
+    }
+    amount * (end - ledger) as i128 / MAX_LOCK_LEDGERS as i128
+}
+
+#[test]
+fn test_vote_escrow_deposit() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_escrow_token_votes_wasm(&e, &token_id, &governor);
+
+    let initial_balance = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+
+    let amount = 600 * 10i128.pow(7);
+    let unlock_ledger = e.ledger().sequence() + 10 * LOCK_PERIOD_LEDGERS;
+    let end = unlock_ledger / LOCK_PERIOD_LEDGERS * LOCK_PERIOD_LEDGERS;
+    votes_client.deposit(&samwise, &amount, &unlock_ledger);
+
+    // validate auth
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    votes_id.clone(),
+                    Symbol::new(&e, "deposit"),
+                    vec![
+                        &e,
+                        samwise.to_val(),
+                        amount.into_val(&e),
+                        unlock_ledger.into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        token_id.clone(),
+                        Symbol::new(&e, "transfer"),
+                        vec![&e, samwise.to_val(), votes_id.to_val(), amount.into_val(&e)]
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )
+    );
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 2)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "deposit"), samwise.clone()).into_val(&e),
+                amount.into_val(&e)
+            ),
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "lock"), samwise.clone()).into_val(&e),
+                (amount, end).into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    let deposit_ledger = e.ledger().sequence();
+    let lock = votes_client.get_lock(&samwise);
+    assert_eq!(lock.amount, amount);
+    assert_eq!(lock.end, end);
+    assert_eq!(votes_client.balance(&samwise), amount);
+    assert_eq!(token_client.balance(&samwise), initial_balance - amount);
+    assert_eq!(token_client.balance(&votes_id), amount);
+    let expected_power = lock_power(amount, end, deposit_ledger);
+    assert_eq!(votes_client.get_votes(&samwise), expected_power);
+    assert_eq!(votes_client.total_supply(), expected_power);
+
+    // voting power decays linearly
+    e.jump(LOCK_PERIOD_LEDGERS + 123);
+    let cur_ledger = e.ledger().sequence();
+    assert_eq!(
+        votes_client.get_votes(&samwise),
+        lock_power(amount, end, cur_ledger)
+    );
+    assert_eq!(
+        votes_client.total_supply(),
+        lock_power(amount, end, cur_ledger)
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&samwise, &deposit_ledger),
+        expected_power
+    );
+    assert_eq!(votes_client.get_past_total_supply(&(deposit_ledger - 1)), 0);
+
+    // increasing the amount and extending the lock increases the voting power
+    let amount_2 = 400 * 10i128.pow(7);
+    let end_2 = end + 2 * LOCK_PERIOD_LEDGERS;
+    votes_client.deposit(&samwise, &amount_2, &end_2);
+    let update_ledger = e.ledger().sequence();
+    let lock = votes_client.get_lock(&samwise);
+    assert_eq!(lock.amount, initial_balance);
+    assert_eq!(lock.end, end_2);
+    assert_eq!(token_client.balance(&samwise), 0);
+    assert_eq!(
+        votes_client.get_votes(&samwise),
+        lock_power(initial_balance, end_2, update_ledger)
+    );
+
+    e.jump(100);
+    assert_eq!(
+        votes_client.get_past_votes(&samwise, &(update_ledger - 1)),
+        lock_power(amount, end, update_ledger - 1)
+    );
+    assert_eq!(
+        votes_client.get_past_total_supply(&update_ledger),
+        lock_power(initial_balance, end_2, update_ledger)
+    );
+}
+
+#[test]
+fn test_vote_escrow_delegate() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    // start shortly before the end of a lock period
+    e.jump(5 * LOCK_PERIOD_LEDGERS - 3000 - e.ledger().sequence());
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_escrow_token_votes_wasm(&e, &token_id, &governor);
+
+    let amount = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &amount);
+    token_client.mint(&frodo, &amount);
+
+    let end_samwise = 20 * LOCK_PERIOD_LEDGERS;
+    let end_frodo = 5 * LOCK_PERIOD_LEDGERS;
+    votes_client.deposit(&samwise, &amount, &end_samwise);
+    votes_client.deposit(&frodo, &amount, &end_frodo);
+
+    e.jump(1000);
+
+    votes_client.delegate(&samwise, &frodo);
+    let delegate_ledger = e.ledger().sequence();
+    assert_eq!(votes_client.get_delegate(&samwise), frodo);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(
+        votes_client.get_votes(&frodo),
+        lock_power(amount, end_samwise, delegate_ledger)
+            + lock_power(amount, end_frodo, delegate_ledger)
+    );
+
+    // frodo's lock expires, but samwise's delegated votes remain
+    e.jump(3000);
+    let cur_ledger = e.ledger().sequence();
+    assert_eq!(
+        votes_client.get_votes(&frodo),
+        lock_power(amount, end_samwise, cur_ledger)
+    );
+    assert_eq!(
+        votes_client.total_supply(),
+        lock_power(amount, end_samwise, cur_ledger)
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&samwise, &(delegate_ledger - 1)),
+        lock_power(amount, end_samwise, delegate_ledger - 1)
+    );
+}
+
+#[test]
+fn test_vote_escrow_withdraw() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    // start shortly before the end of a lock period
+    e.jump(2 * LOCK_PERIOD_LEDGERS - 3000 - e.ledger().sequence());
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (votes_id, votes_client) = create_escrow_token_votes_wasm(&e, &token_id, &governor);
+
+    let amount = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &amount);
+
+    let end = 2 * LOCK_PERIOD_LEDGERS;
+    votes_client.deposit(&samwise, &amount, &end);
+
+    // lock has not expired
+    e.jump(end - e.ledger().sequence() - 1);
+    let result = votes_client.try_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(109))));
+
+    e.jump(1);
+    assert_eq!(votes_client.get_votes(&samwise), 0);
+    assert_eq!(votes_client.total_supply(), 0);
+
+    let withdrawn = votes_client.withdraw(&samwise);
+
+    // validate events
+    let events = e.events().all();
+    let tx_events = events.slice((events.len() - 1)..(events.len()));
+    assert_eq!(
+        tx_events,
+        vec![
+            &e,
+            (
+                votes_id.clone(),
+                (Symbol::new(&e, "withdraw"), samwise.clone()).into_val(&e),
+                amount.into_val(&e)
+            )
+        ]
+    );
+
+    // validate chain results
+    assert_eq!(withdrawn, amount);
+    assert_eq!(votes_client.get_lock(&samwise).amount, 0);
+    assert_eq!(votes_client.balance(&samwise), 0);
+    assert_eq!(token_client.balance(&samwise), amount);
+    assert_eq!(token_client.balance(&votes_id), 0);
+
+    // a new lock can be created after withdrawing
+    let end_2 = e.ledger().sequence() + 3 * LOCK_PERIOD_LEDGERS;
+    votes_client.deposit(&samwise, &amount, &end_2);
+    assert!(votes_client.get_votes(&samwise) > 0);
+}
+
+#[test]
+fn test_vote_escrow_invalid_lock() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let (token_id, token_client) = create_stellar_token(&e, &bombadil);
+    let (_, votes_client) = create_escrow_token_votes_wasm(&e, &token_id, &governor);
+
+    let amount = 1_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &amount);
+
+    // lock ends within the current lock period
+    let result = votes_client.try_deposit(&samwise, &amount, &(e.ledger().sequence() + 10));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(108))));
+
+    // lock exceeds the max lock duration
+    let result = votes_client.try_deposit(
+        &samwise,
+        &amount,
+        &(e.ledger().sequence() + MAX_LOCK_LEDGERS + LOCK_PERIOD_LEDGERS),
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(108))));
+
+    // lock cannot be shortened
+    votes_client.deposit(&samwise, &(amount / 2), &(4 * LOCK_PERIOD_LEDGERS));
+    let result = votes_client.try_deposit(&samwise, &(amount / 2), &(3 * LOCK_PERIOD_LEDGERS));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(108))));
+
+    // negative amount
+    let result = votes_client.try_deposit(&samwise, &-1, &(4 * LOCK_PERIOD_LEDGERS));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(8))));
+}
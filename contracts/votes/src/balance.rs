@@ -2,10 +2,15 @@ use crate::{
     checkpoints::{add_supply_checkpoint, Checkpoint},
     error::TokenVotesError,
     storage,
-    voting_units::move_voting_units,
+    vote_lock::require_unlocked_balance,
+    voting_units::move_balance_units,
 };
 use soroban_sdk::{panic_with_error, Address, Env};
 
+#[cfg(feature = "balance-checkpoints")]
+use crate::checkpoints::add_balance_checkpoint;
+#[cfg(feature = "extended-delegation")]
+use crate::delegation::update_delegator_status;
 #[cfg(feature = "bonding")]
 use crate::emissions;
 
@@ -36,13 +41,12 @@ pub fn mint_balance(e: &Env, to: &Address, amount: i128) {
 
         let vote_ledgers = storage::get_vote_ledgers(e);
         add_supply_checkpoint(e, &vote_ledgers, total_supply_checkpoint);
-        move_voting_units(
-            e,
-            &vote_ledgers,
-            None,
-            Some(&storage::get_delegate(e, to)),
-            amount,
-        );
+        move_balance_units(e, &vote_ledgers, None, Some((to, balance)), amount);
+        #[cfg(feature = "balance-checkpoints")]
+        add_balance_checkpoint(e, &vote_ledgers, to, balance);
+
+        #[cfg(feature = "extended-delegation")]
+        update_delegator_status(e, to, balance > 0, true);
 
         storage::set_balance(e, to, &(balance + amount));
     }
@@ -80,18 +84,38 @@ pub fn burn_balance(e: &Env, from: &Address, amount: i128) {
 
         let vote_ledgers = storage::get_vote_ledgers(e);
         add_supply_checkpoint(e, &vote_ledgers, total_supply_checkpoint);
-        move_voting_units(
-            e,
-            &vote_ledgers,
-            Some(&storage::get_delegate(e, from)),
-            None,
-            amount,
-        );
+        move_balance_units(e, &vote_ledgers, Some((from, balance)), None, amount);
+        #[cfg(feature = "balance-checkpoints")]
+        add_balance_checkpoint(e, &vote_ledgers, from, balance);
+
+        #[cfg(feature = "extended-delegation")]
+        update_delegator_status(e, from, true, balance > amount);
 
         storage::set_balance(e, from, &(balance - amount));
     }
 }
 
+#[cfg(any(
+    feature = "sep-0041",
+    feature = "bonding",
+    feature = "nft",
+    feature = "soulbound"
+))]
+/// Remove tokens from an address's balance on behalf of the address. The balance cannot be reduced below
+/// any amount locked by a vote.
+///
+/// ### Arguments
+/// * `from` - The address to remove the balance from
+/// * `amount` - The amount to remove. This function does nothing if the amount is not greater than 0.
+///
+/// ### Panics
+/// This function panics if the balance is less than the amount to remove, or if the remaining balance
+/// would be less than the locked balance.
+pub fn burn_unlocked_balance(e: &Env, from: &Address, amount: i128) {
+    burn_balance(e, from, amount);
+    require_unlocked_balance(e, from, storage::get_balance(e, from));
+}
+
 #[cfg(feature = "sep-0041")]
 pub fn transfer_balance(e: &Env, from: &Address, to: &Address, amount: i128) {
     if amount > 0 {
@@ -99,6 +123,7 @@ pub fn transfer_balance(e: &Env, from: &Address, to: &Address, amount: i128) {
         if from_balance < amount {
             panic_with_error!(e, TokenVotesError::BalanceError);
         }
+        require_unlocked_balance(e, from, from_balance - amount);
         storage::set_balance(e, from, &(from_balance - amount));
 
         let to_balance = storage::get_balance(e, to);
@@ -115,12 +140,24 @@ pub fn transfer_balance(e: &Env, from: &Address, to: &Address, amount: i128) {
         }
 
         let vote_ledgers = storage::get_vote_ledgers(e);
-        move_voting_units(
+        move_balance_units(
             e,
             &vote_ledgers,
-            Some(&storage::get_delegate(e, from)),
-            Some(&storage::get_delegate(e, to)),
+            Some((from, from_balance)),
+            Some((to, to_balance)),
             amount,
         );
+        #[cfg(feature = "balance-checkpoints")]
+        {
+            add_balance_checkpoint(e, &vote_ledgers, from, from_balance);
+            if from != to {
+                add_balance_checkpoint(e, &vote_ledgers, to, to_balance);
+            }
+        }
+        #[cfg(feature = "extended-delegation")]
+        if from != to {
+            update_delegator_status(e, from, true, from_balance > amount);
+            update_delegator_status(e, to, to_balance > 0, true);
+        }
     }
 }
@@ -0,0 +1,124 @@
+use soroban_sdk::{panic_with_error, token::TokenClient, Address, Env, Vec};
+
+use crate::{
+    balance,
+    constants::{BPS_SCALAR, MAX_BONDING_ASSETS},
+    error::TokenVotesError,
+    storage::{self, Unbond},
+};
+
+/// Require that a list of additional bonding assets is valid. The list can contain at most
+/// `MAX_BONDING_ASSETS` assets, each with a non-zero weight in basis points, and cannot contain
+/// duplicates or the underlying token.
+///
+/// ### Arguments
+/// * `assets` - The assets and their weights in basis points
+pub fn require_valid_assets(e: &Env, assets: &Vec<(Address, u32)>) {
+    if assets.len() > MAX_BONDING_ASSETS {
+        panic_with_error!(e, TokenVotesError::InvalidAssetError);
+    }
+    let token = storage::get_token(e);
+    for (index, (asset, weight)) in assets.iter().enumerate() {
+        if weight == 0 || asset == token {
+            panic_with_error!(e, TokenVotesError::InvalidAssetError);
+        }
+        if assets
+            .iter()
+            .skip(index + 1)
+            .any(|(other, _)| other == asset)
+        {
+            panic_with_error!(e, TokenVotesError::InvalidAssetError);
+        }
+    }
+}
+
+/// Get the weight of an additional bonding asset in basis points, or 0 if the asset is not
+/// currently accepted
+pub fn get_asset_weight(e: &Env, token: &Address) -> u32 {
+    storage::get_assets(e)
+        .iter()
+        .find(|(asset, _)| asset == token)
+        .map(|(_, weight)| weight)
+        .unwrap_or(0)
+}
+
+/// Update the amount of an additional asset bonded by an account, and mint or burn the voting
+/// units of the account to match the asset's current weight
+///
+/// Returns the voting units the account is credited for the asset
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `token` - The address of the asset
+/// * `amount` - The change in the bonded amount of the asset
+///
+/// ### Panics
+/// If the bonded amount of the asset would become negative
+pub fn update_asset_balance(e: &Env, account: &Address, token: &Address, amount: i128) -> i128 {
+    let mut asset_balance = storage::get_asset_balance(e, account, token);
+    asset_balance.amount += amount;
+    if asset_balance.amount < 0 {
+        panic_with_error!(e, TokenVotesError::BalanceError);
+    }
+    let units = asset_balance.amount * get_asset_weight(e, token) as i128 / BPS_SCALAR as i128;
+    let units_change = units - asset_balance.units;
+    if units_change > 0 {
+        balance::mint_balance(e, account, units_change);
+    } else {
+        balance::burn_balance(e, account, -units_change);
+    }
+    asset_balance.units = units;
+    storage::set_asset_balance(e, account, token, &asset_balance);
+    let asset_units = storage::get_asset_units(e, account);
+    storage::set_asset_units(e, account, asset_units + units_change);
+    units
+}
+
+/// Return withdrawn tokens to an account, or add them to the account's unbond for the token
+/// if an unbonding delay is set.
+///
+/// Returns the account's unbond if the tokens are unbonding
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `token` - The address of the token being withdrawn
+/// * `amount` - The amount of tokens being withdrawn
+pub fn unbond_or_transfer(
+    e: &Env,
+    account: &Address,
+    token: &Address,
+    amount: i128,
+) -> Option<Unbond> {
+    let unbond_delay = storage::get_unbond_delay(e);
+    if unbond_delay == 0 {
+        TokenClient::new(e, token).transfer(&e.current_contract_address(), account, &amount);
+        None
+    } else {
+        let mut unbond = storage::get_unbond(e, account, token);
+        unbond.amount += amount;
+        unbond.unlock_ledger = e.ledger().sequence() + unbond_delay;
+        storage::set_unbond(e, account, token, &unbond);
+        Some(unbond)
+    }
+}
+
+/// Return the tokens an account has finished unbonding
+///
+/// Returns the amount of tokens returned
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `token` - The address of the token being unbonded
+///
+/// ### Panics
+/// If the account has no unbond for the token or the unbonding delay has not passed
+pub fn complete_unbond(e: &Env, account: &Address, token: &Address) -> i128 {
+    let unbond = storage::get_unbond(e, account, token);
+    if unbond.amount == 0 || unbond.unlock_ledger > e.ledger().sequence() {
+        panic_with_error!(e, TokenVotesError::UnbondNotCompleteError);
+    }
+    storage::del_unbond(e, account, token);
+
+    TokenClient::new(e, token).transfer(&e.current_contract_address(), account, &unbond.amount);
+    unbond.amount
+}
@@ -1,7 +1,15 @@
-use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env, Vec};
+use soroban_sdk::{unwrap::UnwrapOptimized, Env};
 
-use crate::{constants::MAX_CHECKPOINT_AGE_LEDGERS, error::TokenVotesError, storage};
+use crate::{constants::MAX_CHECKPOINT_AGE_LEDGERS, storage};
 
+#[cfg(not(feature = "vote-escrow"))]
+use crate::error::TokenVotesError;
+#[cfg(not(feature = "vote-escrow"))]
+use soroban_sdk::panic_with_error;
+#[cfg(any(feature = "extended-delegation", not(feature = "vote-escrow")))]
+use soroban_sdk::{Address, Vec};
+
+#[cfg(not(feature = "vote-escrow"))]
 pub trait Checkpoint {
     /// Convert a sequence and amount to a Checkpoint
     ///
@@ -19,6 +27,7 @@ pub trait Checkpoint {
     fn to_checkpoint_data(self) -> (u32, i128);
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 /// Stores the Checkpoint as a u128.
 ///
 /// The Checkpoint encodeds the sequence (u32) and the amount (u96) into
@@ -47,6 +56,7 @@ impl Checkpoint for u128 {
     }
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 /// Get the amount of the checkpoint that has a sequence number greater than or equal
 /// to the given sequence.
 ///
@@ -78,6 +88,7 @@ pub fn upper_lookup(e: &Env, checkpoints: &Vec<u128>, sequence: u32) -> i128 {
     }
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 /// Add "to_add" to the checkpoints vector for the user.
 ///
 /// This function assumes that the caller is setting a new value for the persistent
@@ -95,6 +106,49 @@ pub fn add_user_checkpoint(e: &Env, vote_ledgers: &Vec<u32>, user: &Address, to_
     }
 }
 
+#[cfg(feature = "balance-checkpoints")]
+/// Add the previous balance of the user to the balance checkpoints vector for the user.
+///
+/// This function assumes that the caller is setting a new balance for the user on this ledger.
+///
+/// ### Arguments
+/// * vote_ledgers - The vote ledgers
+/// * user - The address of the user
+/// * prev_balance - The balance of the user before it is changed
+pub fn add_balance_checkpoint(
+    e: &Env,
+    vote_ledgers: &Vec<u32>,
+    user: &Address,
+    prev_balance: i128,
+) {
+    let prev_sequence = storage::get_balance_ledger(e, user);
+    let to_add = u128::from_checkpoint_data(e, prev_sequence, prev_balance);
+    let mut balance_checkpoints = storage::get_balance_checkpoints(e, user);
+    let needs_write = add_checkpoint(e, vote_ledgers, &mut balance_checkpoints, &to_add);
+    if needs_write {
+        storage::set_balance_checkpoints(e, user, &balance_checkpoints);
+    }
+    storage::set_balance_ledger(e, user, e.ledger().sequence());
+}
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+/// Add "to_add" to the checkpoints vector for the abstain supply.
+///
+/// This function assumes that the caller is setting a new value for the persistent
+/// entry on this ledger.
+///
+/// ### Arguments
+/// * vote_ledgers - The vote ledgers
+/// * to_add - The abstain supply checkpoint to add
+pub fn add_abstain_supply_checkpoint(e: &Env, vote_ledgers: &Vec<u32>, to_add: u128) {
+    let mut abstain_checkpoints = storage::get_abstain_supply_checkpoints(e);
+    let needs_write = add_checkpoint(e, vote_ledgers, &mut abstain_checkpoints, &to_add);
+    if needs_write {
+        storage::set_abstain_supply_checkpoints(e, &abstain_checkpoints);
+    }
+}
+
+#[cfg(not(feature = "vote-escrow"))]
 /// Add "to_add" to the checkpoints vector for the total supple.
 ///
 /// This function assumes that the caller is setting a new value for the persistent
@@ -111,6 +165,89 @@ pub fn add_supply_checkpoint(e: &Env, vote_ledgers: &Vec<u32>, to_add: u128) {
     }
 }
 
+/// Record a change of an account's delegate in the account's delegate checkpoints. This function
+/// also prunes any checkpoints that are no longer needed to look up the delegate at a vote ledger.
+///
+/// Each checkpoint is the sequence the delegate was set at and the delegate.
+///
+/// ### Arguments
+/// * vote_ledgers - The vote ledgers
+/// * account - The address of the account
+/// * prev_delegate - The delegate of the account before the change
+/// * delegate - The new delegate of the account
+#[cfg(feature = "extended-delegation")]
+pub fn add_delegate_checkpoint(
+    e: &Env,
+    vote_ledgers: &Vec<u32>,
+    account: &Address,
+    prev_delegate: &Address,
+    delegate: &Address,
+) {
+    if prev_delegate == delegate {
+        return;
+    }
+    let sequence = e.ledger().sequence();
+    let mut checkpoints = storage::get_delegate_checkpoints(e, account);
+    let prev_seq = match checkpoints.last() {
+        Some((last_seq, _)) => {
+            checkpoints.pop_back();
+            last_seq
+        }
+        None => 0,
+    };
+    // the previous delegate is needed if there is a vote ledger between the sequence
+    // it was set at (inclusive) and the current ledger sequence (exclusive)
+    let next_vote_ledger = match vote_ledgers.binary_search(prev_seq) {
+        Ok(index) => vote_ledgers.get(index),
+        Err(index) => vote_ledgers.get(index),
+    };
+    if let Some(vote_ledger) = next_vote_ledger {
+        if vote_ledger < sequence {
+            checkpoints.push_back((prev_seq, prev_delegate.clone()));
+        }
+    }
+    checkpoints.push_back((sequence, delegate.clone()));
+
+    // prune checkpoints older than the oldest vote_ledger entry
+    // except the most recent checkpoint older than or equal to the oldest vote_ledger entry
+    let oldest_vote_ledger = vote_ledgers.first().unwrap_or(sequence);
+    let mut lower_bound_inclusive = 0;
+    for (index, (checkpoint_seq, _)) in checkpoints.iter().enumerate() {
+        if checkpoint_seq > oldest_vote_ledger {
+            break;
+        }
+        lower_bound_inclusive = index as u32;
+    }
+    if lower_bound_inclusive != 0 {
+        checkpoints = checkpoints.slice(lower_bound_inclusive..checkpoints.len());
+    }
+    storage::set_delegate_checkpoints(e, account, &checkpoints);
+}
+
+/// Get the delegate of an account at a sequence from the account's delegate checkpoints.
+///
+/// Returns the current delegate if the account has no checkpoints, and the account itself if
+/// no checkpoint exists at or before the sequence.
+///
+/// ### Arguments
+/// * account - The address of the account
+/// * sequence - The sequence to search for
+#[cfg(feature = "extended-delegation")]
+pub fn lookup_delegate(e: &Env, account: &Address, sequence: u32) -> Address {
+    let checkpoints = storage::get_delegate_checkpoints(e, account);
+    if checkpoints.is_empty() {
+        return storage::get_delegate(e, account);
+    }
+    let mut delegate = account.clone();
+    for (checkpoint_seq, checkpoint_delegate) in checkpoints.iter() {
+        if checkpoint_seq > sequence {
+            break;
+        }
+        delegate = checkpoint_delegate;
+    }
+    delegate
+}
+
 /// Add a vote ledger to the list of vote ledgers. This function will also prune any
 /// vote ledgers that are at least `MAX_CHECKPOINT_AGE_LEDGERS` old.
 ///
@@ -148,6 +285,7 @@ pub fn add_vote_ledger(e: &Env, sequence: u32) {
     storage::set_vote_ledgers(&e, &vote_ledgers);
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 /// Appends "to_add" to the checkpoints vector in place. This function also
 /// manages any pruning of old checkpoints that may be necessary.
 ///
@@ -246,9 +384,11 @@ mod tests {
     use crate::{constants::ONE_DAY_LEDGERS, contract::TokenVotes};
 
     use super::*;
+    #[cfg(any(feature = "extended-delegation", not(feature = "vote-escrow")))]
+    use soroban_sdk::{testutils::Address as _, Address};
     use soroban_sdk::{
-        testutils::{Address as _, Ledger, LedgerInfo},
-        vec, Address, Env, Vec,
+        testutils::{Ledger, LedgerInfo},
+        vec, Env, Vec,
     };
 
     const DEFAULT_LEDGER_INFO: LedgerInfo = LedgerInfo {
@@ -262,6 +402,7 @@ mod tests {
         max_entry_ttl: 100000000,
     };
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_checkpoint_data_conversion() {
         let e = Env::default();
@@ -278,6 +419,7 @@ mod tests {
         assert_eq!(amt, amount);
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     #[should_panic(expected = "Error(Contract, #102)")]
     fn test_checkpoint_data_amount_too_large() {
@@ -288,6 +430,7 @@ mod tests {
         u128::from_checkpoint_data(&e, sequence, amount);
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     #[should_panic(expected = "Error(Contract, #102)")]
     fn test_checkpoint_data_amount_negative() {
@@ -298,6 +441,7 @@ mod tests {
         u128::from_checkpoint_data(&e, sequence, amount);
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_upper_lookup() {
         let e = Env::default();
@@ -313,6 +457,7 @@ mod tests {
         assert_eq!(upper_lookup(&e, &checkpoints, 199), 9876543);
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_upper_lookup_empty() {
         let e = Env::default();
@@ -321,6 +466,7 @@ mod tests {
         assert_eq!(upper_lookup(&e, &checkpoints, 0), 0);
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_user_checkpoint_needs_write_empty() {
         let e = Env::default();
@@ -341,6 +487,7 @@ mod tests {
         });
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_user_checkpoint_no_write_empty() {
         let e = Env::default();
@@ -361,6 +508,7 @@ mod tests {
         });
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_supply_checkpoint_needs_write() {
         let e = Env::default();
@@ -390,6 +538,7 @@ mod tests {
         });
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_supply_checkpoint_no_write_empty() {
         let e = Env::default();
@@ -413,6 +562,7 @@ mod tests {
         });
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_checkpoint_keeps_entry_for_oldest_voting_ledger() {
         let e = Env::default();
@@ -447,6 +597,7 @@ mod tests {
         assert_eq!(vote_first.to_checkpoint_data(), first.to_checkpoint_data());
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_checkpoint_prunes_old_entries() {
         let e = Env::default();
@@ -482,6 +633,7 @@ mod tests {
         );
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_checkpoint_replaces_entries() {
         let e = Env::default();
@@ -521,6 +673,7 @@ mod tests {
         );
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_checkpoint_replaces_same_sequence() {
         let e = Env::default();
@@ -560,6 +713,7 @@ mod tests {
         );
     }
 
+    #[cfg(not(feature = "vote-escrow"))]
     #[test]
     fn test_add_checkpoint_vote_ledger_same_as_cur_ledger_skips() {
         let e = Env::default();
@@ -581,6 +735,163 @@ mod tests {
         assert_eq!(vote_first.to_checkpoint_data(), (ledger - 123, 456));
     }
 
+    #[cfg(feature = "balance-checkpoints")]
+    #[test]
+    fn test_add_balance_checkpoint() {
+        let e = Env::default();
+        e.ledger().set(DEFAULT_LEDGER_INFO);
+
+        let ledger = DEFAULT_LEDGER_INFO.sequence_number;
+        let voting_ledgers = vec![&e, ledger - 100];
+
+        let votes = e.register_contract(None, TokenVotes {});
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&votes, || {
+            storage::set_balance_ledger(&e, &samwise, ledger - 200);
+
+            add_balance_checkpoint(&e, &voting_ledgers, &samwise, 100);
+
+            let balance_checkpoints = storage::get_balance_checkpoints(&e, &samwise);
+            assert_eq!(balance_checkpoints.len(), 1);
+            assert_eq!(
+                balance_checkpoints.get_unchecked(0).to_checkpoint_data(),
+                (ledger - 200, 100)
+            );
+            assert_eq!(storage::get_balance_ledger(&e, &samwise), ledger);
+
+            // balance set at the current ledger is not needed
+            add_balance_checkpoint(&e, &voting_ledgers, &samwise, 200);
+            let balance_checkpoints = storage::get_balance_checkpoints(&e, &samwise);
+            assert_eq!(balance_checkpoints.len(), 1);
+        });
+    }
+
+    #[cfg(feature = "extended-delegation")]
+    #[test]
+    fn test_add_delegate_checkpoint_keeps_delegate_for_vote_ledger() {
+        let e = Env::default();
+        e.ledger().set(DEFAULT_LEDGER_INFO);
+
+        let ledger = DEFAULT_LEDGER_INFO.sequence_number;
+        let voting_ledgers = vec![&e, ledger - 100];
+
+        let votes = e.register_contract(None, TokenVotes {});
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&votes, || {
+            add_delegate_checkpoint(&e, &voting_ledgers, &samwise, &samwise, &frodo);
+
+            let checkpoints = storage::get_delegate_checkpoints(&e, &samwise);
+            assert_eq!(
+                checkpoints,
+                vec![&e, (0, samwise.clone()), (ledger, frodo.clone())]
+            );
+            assert_eq!(lookup_delegate(&e, &samwise, ledger - 100), samwise);
+            assert_eq!(lookup_delegate(&e, &samwise, ledger), frodo);
+        });
+    }
+
+    #[cfg(feature = "extended-delegation")]
+    #[test]
+    fn test_add_delegate_checkpoint_no_vote_ledger_skips() {
+        let e = Env::default();
+        e.ledger().set(DEFAULT_LEDGER_INFO);
+
+        let ledger = DEFAULT_LEDGER_INFO.sequence_number;
+        let voting_ledgers = vec![&e, ledger + 100];
+
+        let votes = e.register_contract(None, TokenVotes {});
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+
+        e.as_contract(&votes, || {
+            add_delegate_checkpoint(&e, &voting_ledgers, &samwise, &samwise, &frodo);
+            // same delegate does not add a checkpoint
+            add_delegate_checkpoint(&e, &voting_ledgers, &samwise, &frodo, &frodo);
+
+            let checkpoints = storage::get_delegate_checkpoints(&e, &samwise);
+            assert_eq!(checkpoints, vec![&e, (ledger, frodo.clone())]);
+
+            // delegate replaced in the same ledger
+            add_delegate_checkpoint(&e, &voting_ledgers, &samwise, &frodo, &merry);
+            let checkpoints = storage::get_delegate_checkpoints(&e, &samwise);
+            assert_eq!(checkpoints, vec![&e, (ledger, merry.clone())]);
+        });
+    }
+
+    #[cfg(feature = "extended-delegation")]
+    #[test]
+    fn test_add_delegate_checkpoint_prunes_old_entries() {
+        let e = Env::default();
+        e.ledger().set(DEFAULT_LEDGER_INFO);
+
+        let ledger = DEFAULT_LEDGER_INFO.sequence_number;
+        let voting_ledgers = vec![
+            &e,
+            ledger + 3 * ONE_DAY_LEDGERS,
+            ledger + 5 * ONE_DAY_LEDGERS,
+        ];
+
+        let votes = e.register_contract(None, TokenVotes {});
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let pippin = Address::generate(&e);
+
+        e.as_contract(&votes, || {
+            storage::set_delegate_checkpoints(
+                &e,
+                &samwise,
+                &vec![
+                    &e,
+                    (ledger, frodo.clone()),
+                    (ledger + 3 * ONE_DAY_LEDGERS - 1, merry.clone()),
+                ],
+            );
+
+            let mut new_ledger_info = DEFAULT_LEDGER_INFO.clone();
+            new_ledger_info.sequence_number += 6 * ONE_DAY_LEDGERS;
+            e.ledger().set(new_ledger_info);
+
+            add_delegate_checkpoint(&e, &voting_ledgers, &samwise, &merry, &pippin);
+
+            let checkpoints = storage::get_delegate_checkpoints(&e, &samwise);
+            assert_eq!(
+                checkpoints,
+                vec![
+                    &e,
+                    (ledger + 3 * ONE_DAY_LEDGERS - 1, merry.clone()),
+                    (ledger + 6 * ONE_DAY_LEDGERS, pippin.clone())
+                ]
+            );
+            assert_eq!(
+                lookup_delegate(&e, &samwise, ledger + 5 * ONE_DAY_LEDGERS),
+                merry
+            );
+        });
+    }
+
+    #[cfg(feature = "extended-delegation")]
+    #[test]
+    fn test_lookup_delegate_empty() {
+        let e = Env::default();
+        e.ledger().set(DEFAULT_LEDGER_INFO);
+
+        let votes = e.register_contract(None, TokenVotes {});
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&votes, || {
+            assert_eq!(lookup_delegate(&e, &samwise, 123), samwise);
+
+            storage::set_delegate(&e, &samwise, &frodo);
+            assert_eq!(lookup_delegate(&e, &samwise, 123), frodo);
+        });
+    }
+
     #[test]
     fn test_add_vote_ledger() {
         let e = Env::default();
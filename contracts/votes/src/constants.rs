@@ -10,5 +10,38 @@ pub(crate) const MAX_CHECKPOINT_AGE_LEDGERS: u32 = 15 * ONE_DAY_LEDGERS;
 /// The maximum number of ledgers a proposal can exist for.
 pub(crate) const MAX_PROPOSAL_AGE_LEDGERS: u32 = 31 * ONE_DAY_LEDGERS;
 
+/// 1 in basis points
+#[cfg(any(
+    feature = "extended-delegation",
+    feature = "vote-escrow",
+    feature = "bonding"
+))]
+pub(crate) const BPS_SCALAR: u32 = 10_000;
+
+/// The maximum number of delegatees an account can split its voting units between.
+#[cfg(feature = "extended-delegation")]
+pub(crate) const MAX_DELEGATE_SPLITS: u32 = 10;
+
+/// The interval lock end ledgers are rounded down to. Changes in the rate voting power decays only occur on these intervals.
+#[cfg(feature = "vote-escrow")]
+pub(crate) const LOCK_PERIOD_LEDGERS: u32 = 7 * ONE_DAY_LEDGERS;
+
+/// The maximum number of ledgers tokens can be locked for. Tokens locked for the maximum duration have
+/// one vote per token.
+#[cfg(feature = "vote-escrow")]
+pub(crate) const MAX_LOCK_LEDGERS: u32 = 52 * LOCK_PERIOD_LEDGERS;
+
+/// The maximum number of ledgers withdrawn tokens can be required to unbond for.
+#[cfg(feature = "bonding")]
+pub(crate) const MAX_UNBOND_DELAY_LEDGERS: u32 = 30 * ONE_DAY_LEDGERS;
+
+/// The maximum number of additional assets that can be bonded for voting units.
+#[cfg(feature = "bonding")]
+pub(crate) const MAX_BONDING_ASSETS: u32 = 10;
+
+/// The maximum number of emission programs that can stream reward tokens at once.
+#[cfg(feature = "bonding")]
+pub(crate) const MAX_EMISSION_PROGRAMS: u32 = 5;
+
 #[cfg(feature = "bonding")]
 pub(crate) const SCALAR_7: i128 = 1_0000000;
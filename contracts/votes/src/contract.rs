@@ -1,44 +1,98 @@
 use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, String};
 
 use crate::{
-    balance,
-    checkpoints::{add_vote_ledger, upper_lookup, Checkpoint},
+    checkpoints::add_vote_ledger,
+    delegation,
     error::TokenVotesError,
     events::TokenVotesEvents,
-    storage::{self, set_delegate, TokenMetadata},
-    validation::require_nonnegative_amount,
+    storage::{self, TokenMetadata},
+    vote_lock,
     votes::Votes,
-    voting_units::move_voting_units,
 };
 
+#[cfg(not(feature = "nft"))]
+use crate::validation::require_nonnegative_amount;
+
+#[cfg(not(feature = "vote-escrow"))]
+use crate::checkpoints::{upper_lookup, Checkpoint};
+
+#[cfg(not(any(feature = "vote-escrow", feature = "nft")))]
+use crate::balance;
+
+// Balance Checkpoints Feature imports
+
+#[cfg(feature = "balance-checkpoints")]
+use crate::votes::BalanceCheckpoints;
+
+// Extended Delegation Feature imports
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+use crate::storage::DelegationMode;
+#[cfg(feature = "extended-delegation")]
+use crate::{checkpoints::lookup_delegate, votes::ExtendedDelegation};
+#[cfg(feature = "extended-delegation")]
+use soroban_sdk::BytesN;
+#[cfg(any(feature = "bonding", feature = "extended-delegation"))]
+use soroban_sdk::Vec;
+
 // SEP-0041 Feature imports
 
-#[cfg(any(feature = "sep-0041", not(feature = "bonding")))]
+#[cfg(any(
+    feature = "sep-0041",
+    not(any(feature = "bonding", feature = "vote-escrow", feature = "nft"))
+))]
 use sep_41_token::TokenEvents;
 
 #[cfg(feature = "sep-0041")]
 use sep_41_token::Token;
 
 #[cfg(feature = "sep-0041")]
-use crate::allowance::{create_allowance, spend_allowance};
+use crate::{
+    allowance::{create_allowance, spend_allowance},
+    validation::require_transferable,
+};
 
 // Bonding Feature imports
 
 #[cfg(feature = "bonding")]
 use crate::{
-    emissions::{claim_emissions, set_emissions},
+    bonding,
+    constants::MAX_UNBOND_DELAY_LEDGERS,
+    emissions::{
+        claim_emissions, claim_program_emissions, get_current_emission_data, get_emission_token,
+        get_emissions_per_vote_unit, get_pending_emissions, set_emission_payout, set_emissions,
+        set_program_emissions,
+    },
+    storage::{AssetBalance, EmissionConfig, EmissionData, EmissionPayout, Unbond},
     votes::Bonding,
 };
 #[cfg(feature = "bonding")]
 use soroban_sdk::token::TokenClient;
 
-// Admin (Bonding not enabled) Feature imports
+// Vote Escrow Feature imports
+
+#[cfg(feature = "vote-escrow")]
+use crate::{escrow, storage::Lock, votes::VoteEscrow};
+#[cfg(feature = "vote-escrow")]
+use soroban_sdk::token::TokenClient;
+
+// Admin (Bonding and Vote Escrow not enabled) Feature imports
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 use crate::votes::Admin;
-#[cfg(not(feature = "bonding"))]
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 use soroban_sdk::Symbol;
 
+// NFT Feature imports
+
+#[cfg(feature = "nft")]
+use crate::{nft, votes::Nft};
+
+// Soulbound Feature imports
+
+#[cfg(feature = "soulbound")]
+use crate::{soulbound, votes::Soulbound};
+
 // Token Data Feature imports (SEP-0041 not enabled)
 
 #[cfg(not(feature = "sep-0041"))]
@@ -61,6 +115,7 @@ impl Token for TokenVotes {
     }
 
     fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        require_transferable(&e);
         from.require_auth();
         require_nonnegative_amount(&e, amount);
         storage::extend_instance(&e);
@@ -76,6 +131,7 @@ impl Token for TokenVotes {
     }
 
     fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        require_transferable(&e);
         from.require_auth();
         require_nonnegative_amount(&e, amount);
         storage::extend_instance(&e);
@@ -86,6 +142,7 @@ impl Token for TokenVotes {
     }
 
     fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        require_transferable(&e);
         spender.require_auth();
         require_nonnegative_amount(&e, amount);
         storage::extend_instance(&e);
@@ -101,7 +158,7 @@ impl Token for TokenVotes {
         require_nonnegative_amount(&e, amount);
         storage::extend_instance(&e);
 
-        balance::burn_balance(&e, &from, amount);
+        balance::burn_unlocked_balance(&e, &from, amount);
 
         // burn underlying from the tokens held by this contract
         #[cfg(feature = "bonding")]
@@ -116,7 +173,7 @@ impl Token for TokenVotes {
         storage::extend_instance(&e);
 
         spend_allowance(&e, &from, &spender, amount);
-        balance::burn_balance(&e, &from, amount);
+        balance::burn_unlocked_balance(&e, &from, amount);
 
         // burn underlying from the tokens held by this contract
         #[cfg(feature = "bonding")]
@@ -138,6 +195,7 @@ impl Token for TokenVotes {
     }
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 #[contractimpl]
 /// Implementation of the Votes trait to allow for tracking votes
 impl Votes for TokenVotes {
@@ -146,6 +204,12 @@ impl Votes for TokenVotes {
         storage::get_total_supply(&e).to_checkpoint_data().1
     }
 
+    fn active_supply(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        total_supply - delegation::get_abstain_supply(&e, e.ledger().sequence())
+    }
+
     fn set_vote_sequence(e: Env, sequence: u32) {
         storage::get_governor(&e).require_auth();
         storage::extend_instance(&e);
@@ -153,6 +217,13 @@ impl Votes for TokenVotes {
         add_vote_ledger(&e, sequence);
     }
 
+    fn lock(e: Env, account: Address, until_sequence: u32) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        vote_lock::lock_balance(&e, &account, until_sequence);
+    }
+
     fn get_past_total_supply(e: Env, sequence: u32) -> i128 {
         storage::extend_instance(&e);
         if sequence >= e.ledger().sequence() {
@@ -167,6 +238,20 @@ impl Votes for TokenVotes {
         upper_lookup(&e, &supply_checkpoints, sequence)
     }
 
+    fn get_past_active_supply(e: Env, sequence: u32) -> i128 {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        let (supply_seq, cur_supply) = storage::get_total_supply(&e).to_checkpoint_data();
+        let total_supply = if supply_seq <= sequence {
+            cur_supply
+        } else {
+            upper_lookup(&e, &storage::get_total_supply_checkpoints(&e), sequence)
+        };
+        total_supply - delegation::get_abstain_supply(&e, sequence)
+    }
+
     fn get_votes(e: Env, account: Address) -> i128 {
         storage::extend_instance(&e);
         storage::get_voting_units(&e, &account)
@@ -196,24 +281,96 @@ impl Votes for TokenVotes {
     fn delegate(e: Env, account: Address, delegatee: Address) {
         account.require_auth();
         storage::extend_instance(&e);
-        let cur_delegate = storage::get_delegate(&e, &account);
-        if cur_delegate == delegatee {
-            panic_with_error!(e, TokenVotesError::InvalidDelegateeError);
+
+        delegation::delegate(&e, account, delegatee);
+    }
+}
+
+#[cfg(feature = "balance-checkpoints")]
+#[contractimpl]
+impl BalanceCheckpoints for TokenVotes {
+    fn get_past_balance(e: Env, account: Address, sequence: u32) -> i128 {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
         }
-        let balance = storage::get_balance(&e, &account);
-        let vote_ledgers = storage::get_vote_ledgers(&e);
-        if balance > 0 {
-            move_voting_units(
-                &e,
-                &vote_ledgers,
-                Some(&cur_delegate),
-                Some(&delegatee),
-                balance,
-            );
+        if storage::get_balance_ledger(&e, &account) <= sequence {
+            return storage::get_balance(&e, &account);
         }
-        set_delegate(&e, &account, &delegatee);
+        let checkpoints = storage::get_balance_checkpoints(&e, &account);
+        upper_lookup(&e, &checkpoints, sequence)
+    }
+}
 
-        TokenVotesEvents::delegate(&e, account, delegatee, cur_delegate)
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+#[contractimpl]
+impl ExtendedDelegation for TokenVotes {
+    fn get_past_delegate(e: Env, account: Address, sequence: u32) -> Address {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        lookup_delegate(&e, &account, sequence)
+    }
+
+    fn get_delegate_split(e: Env, account: Address) -> Vec<(Address, u32)> {
+        storage::extend_instance(&e);
+        delegation::get_delegate_split(&e, &account)
+    }
+
+    fn get_delegators(e: Env, delegatee: Address, start: u32, limit: u32) -> Vec<Address> {
+        storage::extend_instance(&e);
+        delegation::get_delegators(&e, &delegatee, start, limit)
+    }
+
+    fn delegator_count(e: Env, delegatee: Address) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_delegator_count(&e, &delegatee)
+    }
+
+    fn delegate_split(e: Env, account: Address, split: Vec<(Address, u32)>) {
+        account.require_auth();
+        storage::extend_instance(&e);
+
+        delegation::delegate_split(&e, account, split);
+    }
+
+    fn abstain(e: Env, account: Address) {
+        account.require_auth();
+        storage::extend_instance(&e);
+
+        delegation::abstain(&e, account);
+    }
+
+    fn get_delegation_mode(e: Env, account: Address) -> DelegationMode {
+        storage::extend_instance(&e);
+        delegation::get_delegation_mode(&e, &account)
+    }
+
+    fn delegate_by_sig(
+        e: Env,
+        public_key: BytesN<32>,
+        delegatee: Address,
+        nonce: u64,
+        expiry: u32,
+        signature: BytesN<64>,
+    ) {
+        storage::extend_instance(&e);
+
+        let account = delegation::require_delegation_sig(
+            &e,
+            &public_key,
+            &delegatee,
+            nonce,
+            expiry,
+            &signature,
+        );
+        delegation::delegate(&e, account, delegatee);
+    }
+
+    fn get_nonce(e: Env, account: Address) -> u64 {
+        storage::extend_instance(&e);
+        storage::get_nonce(&e, &account)
     }
 }
 
@@ -257,34 +414,436 @@ impl Bonding for TokenVotes {
         from.require_auth();
         storage::extend_instance(&e);
 
-        balance::burn_balance(&e, &from, amount);
+        // voting tokens credited for additional assets can only be withdrawn as those assets
+        let withdrawable = storage::get_balance(&e, &from) - storage::get_asset_units(&e, &from);
+        if amount > withdrawable {
+            panic_with_error!(e, TokenVotesError::BalanceError);
+        }
+        balance::burn_unlocked_balance(&e, &from, amount);
 
-        let token = TokenClient::new(&e, &storage::get_token(&e));
-        token.transfer(&e.current_contract_address(), &from, &amount);
+        match bonding::unbond_or_transfer(&e, &from, &storage::get_token(&e), amount) {
+            Some(unbond) => TokenVotesEvents::unbond(&e, from, unbond.amount, unbond.unlock_ledger),
+            None => TokenVotesEvents::withdraw(&e, from, amount),
+        }
+    }
+
+    fn complete_withdraw(e: Env, from: Address) -> i128 {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        let amount = bonding::complete_unbond(&e, &from, &storage::get_token(&e));
 
         TokenVotesEvents::withdraw(&e, from, amount);
+        amount
+    }
+
+    fn get_unbond(e: Env, account: Address) -> Unbond {
+        storage::extend_instance(&e);
+        storage::get_unbond(&e, &account, &storage::get_token(&e))
+    }
+
+    fn deposit_asset(e: Env, from: Address, token: Address, amount: i128) {
+        require_nonnegative_amount(&e, amount);
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        if bonding::get_asset_weight(&e, &token) == 0 {
+            panic_with_error!(e, TokenVotesError::InvalidAssetError);
+        }
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+
+        bonding::update_asset_balance(&e, &from, &token, amount);
+
+        TokenVotesEvents::deposit_asset(&e, from, token, amount);
+    }
+
+    fn withdraw_asset(e: Env, from: Address, token: Address, amount: i128) {
+        require_nonnegative_amount(&e, amount);
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        bonding::update_asset_balance(&e, &from, &token, -amount);
+        vote_lock::require_unlocked_balance(&e, &from, storage::get_balance(&e, &from));
+
+        match bonding::unbond_or_transfer(&e, &from, &token, amount) {
+            Some(unbond) => {
+                TokenVotesEvents::unbond_asset(&e, from, token, unbond.amount, unbond.unlock_ledger)
+            }
+            None => TokenVotesEvents::withdraw_asset(&e, from, token, amount),
+        }
+    }
+
+    fn complete_withdraw_asset(e: Env, from: Address, token: Address) -> i128 {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        let amount = bonding::complete_unbond(&e, &from, &token);
+
+        TokenVotesEvents::withdraw_asset(&e, from, token, amount);
+        amount
+    }
+
+    fn sync_asset(e: Env, account: Address, token: Address) {
+        storage::extend_instance(&e);
+
+        let units = bonding::update_asset_balance(&e, &account, &token, 0);
+
+        TokenVotesEvents::sync_asset(&e, account, token, units);
+    }
+
+    fn get_asset_balance(e: Env, account: Address, token: Address) -> AssetBalance {
+        storage::extend_instance(&e);
+        storage::get_asset_balance(&e, &account, &token)
+    }
+
+    fn get_asset_unbond(e: Env, account: Address, token: Address) -> Unbond {
+        storage::extend_instance(&e);
+        storage::get_unbond(&e, &account, &token)
+    }
+
+    fn get_assets(e: Env) -> Vec<(Address, u32)> {
+        storage::extend_instance(&e);
+        storage::get_assets(&e)
+    }
+
+    fn set_assets(e: Env, assets: Vec<(Address, u32)>) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        bonding::require_valid_assets(&e, &assets);
+        storage::set_assets(&e, &assets);
+
+        TokenVotesEvents::set_assets(&e, assets);
+    }
+
+    fn get_unbond_delay(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_unbond_delay(&e)
+    }
+
+    fn set_unbond_delay(e: Env, delay: u32) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        if delay > MAX_UNBOND_DELAY_LEDGERS {
+            panic_with_error!(e, TokenVotesError::InvalidUnbondDelayError);
+        }
+        storage::set_unbond_delay(&e, delay);
+
+        TokenVotesEvents::set_unbond_delay(&e, delay);
     }
 
     fn claim(e: Env, address: Address) -> i128 {
         address.require_auth();
         let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
         let balance = storage::get_balance(&e, &address);
-        claim_emissions(&e, total_supply, &address, balance)
+        claim_emissions(&e, total_supply, &address, balance, &address)
+    }
+
+    fn claim_to(e: Env, address: Address, recipient: Address) -> i128 {
+        address.require_auth();
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        let balance = storage::get_balance(&e, &address);
+        claim_emissions(&e, total_supply, &address, balance, &recipient)
+    }
+
+    fn claim_for(e: Env, operator: Address, address: Address) -> i128 {
+        operator.require_auth();
+        if !storage::get_emission_operator(&e, &address, &operator) {
+            panic_with_error!(e, TokenVotesError::UnauthorizedError);
+        }
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        let balance = storage::get_balance(&e, &address);
+        claim_emissions(&e, total_supply, &address, balance, &address)
+    }
+
+    fn approve_operator(e: Env, address: Address, operator: Address, approved: bool) {
+        address.require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_emission_operator(&e, &address, &operator, approved);
+
+        TokenVotesEvents::approve_operator(&e, address, operator, approved);
+    }
+
+    fn is_operator(e: Env, address: Address, operator: Address) -> bool {
+        storage::extend_instance(&e);
+        storage::get_emission_operator(&e, &address, &operator)
     }
 
     fn set_emis(e: Env, tokens: i128, expiration: u64) {
         let governor = storage::get_governor(&e);
         governor.require_auth();
 
-        let token = TokenClient::new(&e, &storage::get_token(&e));
+        let token = TokenClient::new(&e, &get_emission_token(&e));
         token.transfer(&governor, &e.current_contract_address(), &tokens);
 
         let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
         set_emissions(&e, total_supply, tokens, expiration);
     }
+
+    fn emission_config(e: Env) -> Option<EmissionConfig> {
+        storage::extend_instance(&e);
+        storage::get_emission_config(&e)
+    }
+
+    fn emission_data(e: Env) -> Option<EmissionData> {
+        storage::extend_instance(&e);
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        get_current_emission_data(&e, total_supply)
+    }
+
+    fn pending_emissions(e: Env, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        let balance = storage::get_balance(&e, &user);
+        get_pending_emissions(&e, total_supply, &user, balance)
+    }
+
+    fn emissions_per_vote_unit_per_sec(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        get_emissions_per_vote_unit(&e, total_supply)
+    }
+
+    fn get_emis_payout(e: Env) -> EmissionPayout {
+        storage::extend_instance(&e);
+        storage::get_emission_payout(&e)
+    }
+
+    fn get_emis_reserve(e: Env) -> Option<i128> {
+        storage::extend_instance(&e);
+        storage::get_emission_reserve(&e)
+    }
+
+    fn set_emis_payout(e: Env, payout: EmissionPayout) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        set_emission_payout(&e, &payout);
+    }
+
+    fn claim_program(e: Env, address: Address, token: Address) -> i128 {
+        address.require_auth();
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        let balance = storage::get_balance(&e, &address);
+        claim_program_emissions(&e, total_supply, &token, &address, balance)
+    }
+
+    fn get_emis_programs(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_emission_programs(&e)
+    }
+
+    fn set_emis_program(e: Env, token: Address, tokens: i128, expiration: u64) {
+        let governor = storage::get_governor(&e);
+        governor.require_auth();
+
+        TokenClient::new(&e, &token).transfer(&governor, &e.current_contract_address(), &tokens);
+
+        let total_supply = storage::get_total_supply(&e).to_checkpoint_data().1;
+        set_program_emissions(&e, total_supply, &token, tokens, expiration);
+    }
+}
+
+#[cfg(feature = "vote-escrow")]
+#[contractimpl]
+/// Implementation of the Votes trait where voting power decays as locks approach their unlock ledger
+impl Votes for TokenVotes {
+    fn total_supply(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        escrow::get_voting_power(&e, None, e.ledger().sequence())
+    }
+
+    fn set_vote_sequence(e: Env, sequence: u32) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        add_vote_ledger(&e, sequence);
+    }
+
+    fn lock(e: Env, account: Address, until_sequence: u32) {
+        storage::get_governor(&e).require_auth();
+        storage::extend_instance(&e);
+
+        vote_lock::lock_balance(&e, &account, until_sequence);
+    }
+
+    fn get_past_total_supply(e: Env, sequence: u32) -> i128 {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        escrow::get_voting_power(&e, None, sequence)
+    }
+
+    fn get_past_active_supply(e: Env, sequence: u32) -> i128 {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        escrow::get_voting_power(&e, None, sequence)
+    }
+
+    fn get_votes(e: Env, account: Address) -> i128 {
+        storage::extend_instance(&e);
+        escrow::get_voting_power(&e, Some(&account), e.ledger().sequence())
+    }
+
+    fn get_past_votes(e: Env, user: Address, sequence: u32) -> i128 {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        escrow::get_voting_power(&e, Some(&user), sequence)
+    }
+
+    fn get_delegate(e: Env, account: Address) -> Address {
+        storage::extend_instance(&e);
+        storage::get_delegate(&e, &account)
+    }
+
+    fn delegate(e: Env, account: Address, delegatee: Address) {
+        account.require_auth();
+        storage::extend_instance(&e);
+
+        delegation::delegate(&e, account, delegatee);
+    }
+}
+
+#[cfg(all(feature = "extended-delegation", feature = "vote-escrow"))]
+#[contractimpl]
+impl ExtendedDelegation for TokenVotes {
+    fn get_past_delegate(e: Env, account: Address, sequence: u32) -> Address {
+        storage::extend_instance(&e);
+        if sequence >= e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::SequenceNotClosedError);
+        }
+        lookup_delegate(&e, &account, sequence)
+    }
+
+    fn get_delegate_split(e: Env, account: Address) -> Vec<(Address, u32)> {
+        storage::extend_instance(&e);
+        delegation::get_delegate_split(&e, &account)
+    }
+
+    fn get_delegators(e: Env, delegatee: Address, start: u32, limit: u32) -> Vec<Address> {
+        storage::extend_instance(&e);
+        delegation::get_delegators(&e, &delegatee, start, limit)
+    }
+
+    fn delegator_count(e: Env, delegatee: Address) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_delegator_count(&e, &delegatee)
+    }
+
+    fn delegate_split(e: Env, account: Address, split: Vec<(Address, u32)>) {
+        account.require_auth();
+        storage::extend_instance(&e);
+
+        delegation::delegate_split(&e, account, split);
+    }
+
+    fn delegate_by_sig(
+        e: Env,
+        public_key: BytesN<32>,
+        delegatee: Address,
+        nonce: u64,
+        expiry: u32,
+        signature: BytesN<64>,
+    ) {
+        storage::extend_instance(&e);
+
+        let account = delegation::require_delegation_sig(
+            &e,
+            &public_key,
+            &delegatee,
+            nonce,
+            expiry,
+            &signature,
+        );
+        delegation::delegate(&e, account, delegatee);
+    }
+
+    fn get_nonce(e: Env, account: Address) -> u64 {
+        storage::extend_instance(&e);
+        storage::get_nonce(&e, &account)
+    }
 }
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(feature = "vote-escrow")]
+#[contractimpl]
+impl VoteEscrow for TokenVotes {
+    fn initialize(e: Env, token: Address, governor: Address, name: String, symbol: String) {
+        if storage::get_is_init(&e) {
+            panic_with_error!(e, TokenVotesError::AlreadyInitializedError);
+        }
+        storage::extend_instance(&e);
+
+        let underlying_token = TokenClient::new(&e, &token);
+        let decimal = underlying_token.decimals();
+        let token_metadata = TokenMetadata {
+            decimal,
+            name,
+            symbol,
+        };
+        storage::set_metadata(&e, &token_metadata);
+        storage::set_token(&e, &token);
+        storage::set_governor(&e, &governor);
+        storage::set_is_init(&e);
+    }
+
+    fn deposit(e: Env, from: Address, amount: i128, unlock_ledger: u32) {
+        require_nonnegative_amount(&e, amount);
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        let old_lock = storage::get_lock(&e, &from);
+        let new_lock = Lock {
+            amount: old_lock.amount + amount,
+            end: escrow::round_to_period(unlock_ledger),
+        };
+        escrow::require_valid_lock(&e, &old_lock, &new_lock);
+
+        let token = TokenClient::new(&e, &storage::get_token(&e));
+        token.transfer(&from, &e.current_contract_address(), &amount);
+
+        escrow::update_lock_power(&e, &from, &old_lock, &new_lock);
+        storage::set_lock(&e, &from, &new_lock);
+        storage::set_balance(&e, &from, &new_lock.amount);
+
+        TokenVotesEvents::deposit(&e, from.clone(), amount);
+        TokenVotesEvents::lock(&e, from, new_lock.amount, new_lock.end);
+    }
+
+    fn withdraw(e: Env, from: Address) -> i128 {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        let lock = storage::get_lock(&e, &from);
+        if lock.end > e.ledger().sequence() {
+            panic_with_error!(e, TokenVotesError::LockNotExpiredError);
+        }
+        vote_lock::require_unlocked_balance(&e, &from, 0);
+        #[cfg(feature = "extended-delegation")]
+        delegation::update_delegator_status(&e, &from, lock.amount > 0, false);
+        storage::set_lock(&e, &from, &Lock { amount: 0, end: 0 });
+        storage::set_balance(&e, &from, &0);
+
+        let token = TokenClient::new(&e, &storage::get_token(&e));
+        token.transfer(&e.current_contract_address(), &from, &lock.amount);
+
+        TokenVotesEvents::withdraw(&e, from, lock.amount);
+        lock.amount
+    }
+
+    fn get_lock(e: Env, account: Address) -> Lock {
+        storage::extend_instance(&e);
+        storage::get_lock(&e, &account)
+    }
+}
+
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 #[contractimpl]
 impl Admin for TokenVotes {
     fn initialize(
@@ -318,6 +877,8 @@ impl Admin for TokenVotes {
         storage::extend_instance(&e);
 
         balance::mint_balance(&e, &to, amount);
+        #[cfg(feature = "soulbound")]
+        soulbound::extend_expiration(&e, &to);
 
         TokenEvents::mint(&e, admin, to, amount);
     }
@@ -350,6 +911,85 @@ impl Admin for TokenVotes {
     }
 }
 
+#[cfg(feature = "nft")]
+#[contractimpl]
+impl Nft for TokenVotes {
+    fn initialize(e: Env, collection: Address, governor: Address, name: String, symbol: String) {
+        if storage::get_is_init(&e) {
+            panic_with_error!(e, TokenVotesError::AlreadyInitializedError);
+        }
+        storage::extend_instance(&e);
+
+        let token_metadata = TokenMetadata {
+            decimal: 0,
+            name,
+            symbol,
+        };
+        storage::set_metadata(&e, &token_metadata);
+        storage::set_token(&e, &collection);
+        storage::set_governor(&e, &governor);
+        storage::set_is_init(&e);
+    }
+
+    fn deposit(e: Env, from: Address, token_id: u32) {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        nft::deposit(&e, &from, token_id);
+
+        TokenVotesEvents::deposit_nft(&e, from, token_id);
+    }
+
+    fn withdraw(e: Env, from: Address, token_id: u32) {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        nft::withdraw(&e, &from, token_id);
+
+        TokenVotesEvents::withdraw_nft(&e, from, token_id);
+    }
+
+    fn get_owner(e: Env, token_id: u32) -> Option<Address> {
+        storage::extend_instance(&e);
+        storage::get_nft_owner(&e, token_id)
+    }
+
+    fn collection(e: Env) -> Address {
+        storage::get_token(&e)
+    }
+}
+
+#[cfg(feature = "soulbound")]
+#[contractimpl]
+impl Soulbound for TokenVotes {
+    fn set_expiry_period(e: Env, period: u32) {
+        storage::get_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_expiry_period(&e, period);
+
+        TokenVotesEvents::set_expiry_period(&e, period);
+    }
+
+    fn get_expiry_period(e: Env) -> u32 {
+        storage::get_expiry_period(&e)
+    }
+
+    fn get_expiration(e: Env, account: Address) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_expiration(&e, &account)
+    }
+
+    fn expire(e: Env, account: Address) -> i128 {
+        storage::extend_instance(&e);
+
+        let amount = soulbound::expire(&e, &account);
+
+        TokenVotesEvents::expire(&e, account, amount);
+        amount
+    }
+}
+
 #[cfg(not(feature = "sep-0041"))]
 #[contractimpl]
 impl TokenData for TokenVotes {
@@ -0,0 +1,444 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::{error::TokenVotesError, events::TokenVotesEvents, storage};
+
+#[cfg(any(feature = "extended-delegation", feature = "vote-escrow"))]
+use crate::constants::BPS_SCALAR;
+#[cfg(any(feature = "extended-delegation", feature = "vote-escrow"))]
+use soroban_sdk::Vec;
+
+#[cfg(feature = "extended-delegation")]
+use crate::{checkpoints::add_delegate_checkpoint, validation::require_valid_delegate_split};
+#[cfg(feature = "extended-delegation")]
+use soroban_sdk::{
+    unwrap::UnwrapOptimized,
+    xdr::{FromXdr, ToXdr},
+    Bytes, BytesN, Symbol,
+};
+
+#[cfg(not(feature = "vote-escrow"))]
+use crate::voting_units::move_voting_units;
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+use crate::{
+    checkpoints::{upper_lookup, Checkpoint},
+    storage::DelegationMode,
+    voting_units::{move_abstain_supply, move_split_units},
+};
+
+#[cfg(feature = "vote-escrow")]
+use crate::escrow;
+
+/// Get the delegatees of an account and their share of the account's votes in basis points
+///
+/// ### Arguments
+/// * `account` - The address of the account
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegate_split(e: &Env, account: &Address) -> Vec<(Address, u32)> {
+    storage::get_delegate_split(e, account)
+        .unwrap_or_else(|| Vec::from_array(e, [(storage::get_delegate(e, account), BPS_SCALAR)]))
+}
+
+/// Get the delegatee of an account with the full share of the account's votes in basis points
+///
+/// ### Arguments
+/// * `account` - The address of the account
+#[cfg(all(not(feature = "extended-delegation"), feature = "vote-escrow"))]
+pub fn get_delegate_split(e: &Env, account: &Address) -> Vec<(Address, u32)> {
+    Vec::from_array(e, [(storage::get_delegate(e, account), BPS_SCALAR)])
+}
+
+/// Delegate all voting units of an account to a single delegatee
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `delegatee` - The address of the delegatee
+///
+/// ### Panics
+/// If the account already delegates all of its voting units to the delegatee
+#[cfg(feature = "extended-delegation")]
+pub fn delegate(e: &Env, account: Address, delegatee: Address) {
+    let cur_delegate = storage::get_delegate(e, &account);
+    let cur_split = storage::get_delegate_split(e, &account);
+    if cur_split.is_none() && cur_delegate == delegatee {
+        panic_with_error!(e, TokenVotesError::InvalidDelegateeError);
+    }
+
+    #[cfg(not(feature = "vote-escrow"))]
+    {
+        let balance = storage::get_balance(e, &account);
+        let vote_ledgers = storage::get_vote_ledgers(e);
+        match &cur_split {
+            Some(split) => {
+                if split.is_empty() {
+                    move_abstain_supply(e, &vote_ledgers, -balance);
+                }
+                move_split_units(e, &vote_ledgers, split, balance, 0);
+                move_voting_units(e, &vote_ledgers, None, Some(&delegatee), balance);
+            }
+            None => move_voting_units(
+                e,
+                &vote_ledgers,
+                Some(&cur_delegate),
+                Some(&delegatee),
+                balance,
+            ),
+        }
+    }
+    #[cfg(feature = "vote-escrow")]
+    escrow::move_lock_power(
+        e,
+        &account,
+        &get_delegate_split(e, &account),
+        &Vec::from_array(e, [(delegatee.clone(), BPS_SCALAR)]),
+    );
+
+    let active = has_voting_units(e, &account);
+    update_delegators(
+        e,
+        &account,
+        &get_delegate_split(e, &account),
+        &Vec::from_array(e, [(delegatee.clone(), BPS_SCALAR)]),
+        active,
+        active,
+    );
+    if cur_split.is_some() {
+        storage::del_delegate_split(e, &account);
+    }
+    add_delegate_checkpoint(
+        e,
+        &storage::get_vote_ledgers(e),
+        &account,
+        &cur_delegate,
+        &delegatee,
+    );
+    storage::set_delegate(e, &account, &delegatee);
+
+    TokenVotesEvents::delegate(e, account, delegatee, cur_delegate)
+}
+
+/// Delegate all voting units of an account to a delegatee
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `delegatee` - The address of the delegatee
+///
+/// ### Panics
+/// If the account already delegates to the delegatee
+#[cfg(not(feature = "extended-delegation"))]
+pub fn delegate(e: &Env, account: Address, delegatee: Address) {
+    let cur_delegate = storage::get_delegate(e, &account);
+    if cur_delegate == delegatee {
+        panic_with_error!(e, TokenVotesError::InvalidDelegateeError);
+    }
+
+    #[cfg(not(feature = "vote-escrow"))]
+    move_voting_units(
+        e,
+        &storage::get_vote_ledgers(e),
+        Some(&cur_delegate),
+        Some(&delegatee),
+        storage::get_balance(e, &account),
+    );
+    #[cfg(feature = "vote-escrow")]
+    escrow::move_lock_power(
+        e,
+        &account,
+        &get_delegate_split(e, &account),
+        &Vec::from_array(e, [(delegatee.clone(), BPS_SCALAR)]),
+    );
+    storage::set_delegate(e, &account, &delegatee);
+
+    TokenVotesEvents::delegate(e, account, delegatee, cur_delegate)
+}
+
+/// Split the voting units of an account between multiple delegatees
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `split` - The addresses of the delegatees and their shares in basis points
+///
+/// ### Panics
+/// If the split is invalid
+#[cfg(feature = "extended-delegation")]
+pub fn delegate_split(e: &Env, account: Address, split: Vec<(Address, u32)>) {
+    require_valid_delegate_split(e, &split);
+
+    let cur_split = get_delegate_split(e, &account);
+    #[cfg(not(feature = "vote-escrow"))]
+    {
+        let balance = storage::get_balance(e, &account);
+        let vote_ledgers = storage::get_vote_ledgers(e);
+        if cur_split.is_empty() {
+            move_abstain_supply(e, &vote_ledgers, -balance);
+        }
+        move_split_units(e, &vote_ledgers, &cur_split, balance, 0);
+        move_split_units(e, &vote_ledgers, &split, 0, balance);
+    }
+    #[cfg(feature = "vote-escrow")]
+    escrow::move_lock_power(e, &account, &cur_split, &split);
+
+    let active = has_voting_units(e, &account);
+    update_delegators(e, &account, &cur_split, &split, active, active);
+
+    // track the delegatee with the largest share as the account's primary delegate
+    let mut primary = split.get_unchecked(0);
+    for entry in split.iter() {
+        if entry.1 > primary.1 {
+            primary = entry;
+        }
+    }
+    add_delegate_checkpoint(
+        e,
+        &storage::get_vote_ledgers(e),
+        &account,
+        &storage::get_delegate(e, &account),
+        &primary.0,
+    );
+    storage::set_delegate(e, &account, &primary.0);
+    if split.len() == 1 {
+        storage::del_delegate_split(e, &account);
+    } else {
+        storage::set_delegate_split(e, &account, &split);
+    }
+
+    TokenVotesEvents::delegate_split(e, account, split);
+}
+
+/// Stop delegating the voting units of an account. The account's balance is removed from its
+/// delegatees and is not counted towards the active supply until the account delegates again.
+///
+/// ### Arguments
+/// * `account` - The address of the account
+///
+/// ### Panics
+/// If the account is already abstaining
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn abstain(e: &Env, account: Address) {
+    let cur_split = get_delegate_split(e, &account);
+    if cur_split.is_empty() {
+        panic_with_error!(e, TokenVotesError::InvalidDelegateeError);
+    }
+
+    let balance = storage::get_balance(e, &account);
+    let vote_ledgers = storage::get_vote_ledgers(e);
+    move_split_units(e, &vote_ledgers, &cur_split, balance, 0);
+    move_abstain_supply(e, &vote_ledgers, balance);
+
+    let active = balance > 0;
+    let empty_split = Vec::new(e);
+    update_delegators(e, &account, &cur_split, &empty_split, active, active);
+    add_delegate_checkpoint(
+        e,
+        &vote_ledgers,
+        &account,
+        &storage::get_delegate(e, &account),
+        &account,
+    );
+    storage::set_delegate(e, &account, &account);
+    storage::set_delegate_split(e, &account, &empty_split);
+
+    TokenVotesEvents::abstain(e, account, cur_split);
+}
+
+/// Get how the voting units of an account are delegated
+///
+/// ### Arguments
+/// * `account` - The address of the account
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn get_delegation_mode(e: &Env, account: &Address) -> DelegationMode {
+    match storage::get_delegate_split(e, account) {
+        Some(split) if split.is_empty() => DelegationMode::Abstain,
+        Some(_) => DelegationMode::Delegated,
+        None if storage::get_delegate(e, account) != *account => DelegationMode::Delegated,
+        None => DelegationMode::SelfDelegated,
+    }
+}
+
+/// Get the supply held by abstaining accounts at a ledger sequence
+///
+/// ### Arguments
+/// * `sequence` - The sequence number to get the abstain supply at
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn get_abstain_supply(e: &Env, sequence: u32) -> i128 {
+    let (abstain_seq, cur_abstain) = storage::get_abstain_supply(e).to_checkpoint_data();
+    if abstain_seq <= sequence {
+        return cur_abstain;
+    }
+    upper_lookup(e, &storage::get_abstain_supply_checkpoints(e), sequence)
+}
+
+/// Get the supply held by abstaining accounts at a ledger sequence. Accounts can only abstain
+/// with the `extended-delegation` feature, so this is always 0.
+#[cfg(not(any(feature = "extended-delegation", feature = "vote-escrow")))]
+pub fn get_abstain_supply(_e: &Env, _sequence: u32) -> i128 {
+    0
+}
+
+/// Get a page of the accounts that delegate voting units to a delegatee
+///
+/// ### Arguments
+/// * `delegatee` - The address of the delegatee
+/// * `start` - The index of the first delegator to return
+/// * `limit` - The maximum number of delegators to return
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegators(e: &Env, delegatee: &Address, start: u32, limit: u32) -> Vec<Address> {
+    let count = storage::get_delegator_count(e, delegatee);
+    let end = start.saturating_add(limit).min(count);
+    let mut delegators = Vec::new(e);
+    for index in start..end {
+        delegators.push_back(storage::get_delegator(e, delegatee, index).unwrap_optimized());
+    }
+    delegators
+}
+
+/// Update the delegator index when an account gains or loses all of its voting units
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `was_active` - If the account had voting units before the change
+/// * `is_active` - If the account has voting units after the change
+#[cfg(feature = "extended-delegation")]
+pub fn update_delegator_status(e: &Env, account: &Address, was_active: bool, is_active: bool) {
+    if was_active != is_active {
+        let split = get_delegate_split(e, account);
+        update_delegators(e, account, &split, &split, was_active, is_active);
+    }
+}
+
+/// Update the delegator index of each delegatee an account delegates to. An account is only
+/// indexed as a delegator if it has voting units and is not delegating to itself.
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `cur_split` - The delegatees of the account before the change
+/// * `new_split` - The delegatees of the account after the change
+/// * `was_active` - If the account had voting units before the change
+/// * `is_active` - If the account has voting units after the change
+#[cfg(feature = "extended-delegation")]
+fn update_delegators(
+    e: &Env,
+    account: &Address,
+    cur_split: &Vec<(Address, u32)>,
+    new_split: &Vec<(Address, u32)>,
+    was_active: bool,
+    is_active: bool,
+) {
+    if was_active {
+        for (delegatee, _) in cur_split.iter() {
+            if delegatee != *account && !(is_active && split_contains(new_split, &delegatee)) {
+                remove_delegator(e, &delegatee, account);
+            }
+        }
+    }
+    if is_active {
+        for (delegatee, _) in new_split.iter() {
+            if delegatee != *account && !(was_active && split_contains(cur_split, &delegatee)) {
+                add_delegator(e, &delegatee, account);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "extended-delegation")]
+fn split_contains(split: &Vec<(Address, u32)>, address: &Address) -> bool {
+    split.iter().any(|(delegatee, _)| delegatee == *address)
+}
+
+/// Add an account to the end of a delegatee's delegator index
+#[cfg(feature = "extended-delegation")]
+fn add_delegator(e: &Env, delegatee: &Address, account: &Address) {
+    if storage::get_delegator_index(e, delegatee, account).is_none() {
+        let count = storage::get_delegator_count(e, delegatee);
+        storage::set_delegator(e, delegatee, count, account);
+        storage::set_delegator_index(e, delegatee, account, count);
+        storage::set_delegator_count(e, delegatee, count + 1);
+    }
+}
+
+/// Remove an account from a delegatee's delegator index by replacing it with the last delegator
+#[cfg(feature = "extended-delegation")]
+fn remove_delegator(e: &Env, delegatee: &Address, account: &Address) {
+    if let Some(index) = storage::get_delegator_index(e, delegatee, account) {
+        let last_index = storage::get_delegator_count(e, delegatee) - 1;
+        if index != last_index {
+            let last = storage::get_delegator(e, delegatee, last_index).unwrap_optimized();
+            storage::set_delegator(e, delegatee, index, &last);
+            storage::set_delegator_index(e, delegatee, &last, index);
+        }
+        storage::del_delegator(e, delegatee, last_index);
+        storage::del_delegator_index(e, delegatee, account);
+        storage::set_delegator_count(e, delegatee, last_index);
+    }
+}
+
+/// Check if an account has voting units to delegate
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+fn has_voting_units(e: &Env, account: &Address) -> bool {
+    storage::get_balance(e, account) > 0
+}
+
+/// Check if an account has voting units to delegate
+#[cfg(all(feature = "extended-delegation", feature = "vote-escrow"))]
+fn has_voting_units(e: &Env, account: &Address) -> bool {
+    storage::get_lock(e, account).amount > 0
+}
+
+/// Verify a delegation signed by a Stellar account and consume the account's nonce
+///
+/// The signed message is the XDR encoding of the tuple
+/// `("delegate_by_sig", network_id, votes_contract, delegatee, nonce, expiry)`
+///
+/// ### Arguments
+/// * `public_key` - The ed25519 public key of the account
+/// * `delegatee` - The address of the delegatee
+/// * `nonce` - The current nonce of the account
+/// * `expiry` - The last ledger sequence the signature is valid for
+/// * `signature` - The ed25519 signature of the message
+///
+/// ### Returns
+/// The address of the account
+///
+/// ### Panics
+/// If the signature is expired, the nonce is not the account's current nonce, or the signature is invalid
+#[cfg(feature = "extended-delegation")]
+pub fn require_delegation_sig(
+    e: &Env,
+    public_key: &BytesN<32>,
+    delegatee: &Address,
+    nonce: u64,
+    expiry: u32,
+    signature: &BytesN<64>,
+) -> Address {
+    if e.ledger().sequence() > expiry {
+        panic_with_error!(e, TokenVotesError::SignatureExpiredError);
+    }
+    let account = account_address(e, public_key);
+    let cur_nonce = storage::get_nonce(e, &account);
+    if nonce != cur_nonce {
+        panic_with_error!(e, TokenVotesError::InvalidNonceError);
+    }
+
+    let message = (
+        Symbol::new(e, "delegate_by_sig"),
+        e.ledger().network_id(),
+        e.current_contract_address(),
+        delegatee.clone(),
+        nonce,
+        expiry,
+    )
+        .to_xdr(e);
+    e.crypto().ed25519_verify(public_key, &message, signature);
+
+    storage::set_nonce(e, &account, cur_nonce + 1);
+    account
+}
+
+/// Get the address of the Stellar account with the given ed25519 public key
+#[cfg(feature = "extended-delegation")]
+fn account_address(e: &Env, public_key: &BytesN<32>) -> Address {
+    // XDR encoding of `ScVal::Address(ScAddress::Account(PublicKey::Ed25519(public_key)))`
+    let mut address_xdr = Bytes::from_array(e, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+    address_xdr.append(&Bytes::from(public_key.clone()));
+    Address::from_xdr(e, &address_xdr).unwrap_optimized()
+}
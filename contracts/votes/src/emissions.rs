@@ -1,95 +1,387 @@
 #![cfg(feature = "bonding")]
 
 use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env};
+use soroban_sdk::{panic_with_error, token::TokenClient, unwrap::UnwrapOptimized, Address, Env};
 
 use crate::{
     balance,
-    constants::SCALAR_7,
+    constants::{MAX_EMISSION_PROGRAMS, SCALAR_7},
     error::TokenVotesError,
     events::TokenVotesEvents,
-    storage::{self, EmissionConfig, EmissionData, UserEmissionData},
+    storage::{self, EmissionConfig, EmissionData, EmissionPayout, UserEmissionData},
 };
 
-/// Claim emission for a user into their vote token balance
+/// Claim emission for a user. The emissions are added to the recipient's vote token balance, or
+/// transferred to the recipient, based on the emission payout.
 ///
 /// ### Arguments
 /// * `total_supply` - The total supply of the vote token
 /// * `user` - The address of the user
 /// * `balance` - The balance of the user
+/// * `recipient` - The address to receive the claimed emissions
 ///
 /// ### Returns
 /// The number of tokens claimed
-pub fn claim_emissions(e: &Env, total_supply: i128, user: &Address, balance: i128) -> i128 {
-    if let Some(emis_config) = storage::get_emission_config(e) {
-        let prev_emis_data = storage::get_emission_data(e).unwrap_optimized(); // exists if config exists
+///
+/// ### Panics
+/// If the emission reserve is tracked and does not hold enough tokens to pay out the claim
+pub fn claim_emissions(
+    e: &Env,
+    total_supply: i128,
+    user: &Address,
+    balance: i128,
+    recipient: &Address,
+) -> i128 {
+    let to_claim = accrue_emissions(e, None, total_supply, user, balance);
+    if to_claim > 0 {
+        // emissions set before the reserve was tracked are not checked against it
+        if let Some(reserve) = storage::get_emission_reserve(e) {
+            if to_claim > reserve {
+                panic_with_error!(e, TokenVotesError::BalanceError);
+            }
+            storage::set_emission_reserve(e, reserve - to_claim);
+        }
+
+        match storage::get_emission_payout(e) {
+            EmissionPayout::Compound => balance::mint_balance(e, recipient, to_claim),
+            EmissionPayout::Transfer(token) => TokenClient::new(e, &token).transfer(
+                &e.current_contract_address(),
+                recipient,
+                &to_claim,
+            ),
+        }
+
+        TokenVotesEvents::claim(e, user.clone(), recipient.clone(), to_claim);
+    }
+    to_claim
+}
+
+/// Claim emission for a user from an emission program, transferring the program's reward token
+/// to the user
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+/// * `token` - The reward token of the emission program
+/// * `user` - The address of the user
+/// * `balance` - The balance of the user
+///
+/// ### Returns
+/// The number of reward tokens claimed
+pub fn claim_program_emissions(
+    e: &Env,
+    total_supply: i128,
+    token: &Address,
+    user: &Address,
+    balance: i128,
+) -> i128 {
+    let to_claim = accrue_emissions(e, Some(token), total_supply, user, balance);
+    if to_claim > 0 {
+        TokenClient::new(e, token).transfer(&e.current_contract_address(), user, &to_claim);
+
+        TokenVotesEvents::claim_program(e, user.clone(), token.clone(), to_claim);
+    }
+    to_claim
+}
+
+/// Update the emissions for a balance change
+///
+/// Updates the vote token emissions and every emission program, which is bounded by
+/// `MAX_EMISSION_PROGRAMS` so the update fits within a single transaction.
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+/// * `user` - The address of the user
+/// * `balance` - The balance of the user
+pub fn update_emissions(e: &Env, total_supply: i128, user: &Address, balance: i128) {
+    update_program_emissions(e, None, total_supply, user, balance);
+    for token in storage::get_emission_programs(e).iter() {
+        update_program_emissions(e, Some(&token), total_supply, user, balance);
+    }
+}
+
+/// Set the emissions for the vote token
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+/// * `new_tokens` - The number of tokens being emitted
+/// * `new_expiration` - The time the emission will expire
+pub fn set_emissions(e: &Env, total_supply: i128, new_tokens: i128, new_expiration: u64) {
+    // only track the reserve if it covers every emission set, as the tokens held for emissions
+    // set before it was tracked are unknown
+    match storage::get_emission_reserve(e) {
+        Some(reserve) => storage::set_emission_reserve(e, reserve + new_tokens),
+        None if storage::get_emission_config(e).is_none() => {
+            storage::set_emission_reserve(e, new_tokens)
+        }
+        None => (),
+    }
+    let eps = set_emission_config(e, None, total_supply, new_tokens, new_expiration);
+
+    TokenVotesEvents::set_emissions(e, eps, new_expiration);
+}
+
+/// Get the emission data, updated to the current timestamp. Does not write the updated data.
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+pub fn get_current_emission_data(e: &Env, total_supply: i128) -> Option<EmissionData> {
+    let emis_config = storage::get_emission_config(e)?;
+    let emis_data = storage::get_emission_data(e).unwrap_optimized(); // exists if config exists
+    match update_emission_data(e, &emis_data, &emis_config, total_supply) {
+        Some(data) => Some(data),
+        None => Some(emis_data),
+    }
+}
+
+/// Get the emissions a user could claim at the current timestamp. Does not write any data.
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+/// * `user` - The address of the user
+/// * `balance` - The balance of the user
+pub fn get_pending_emissions(e: &Env, total_supply: i128, user: &Address, balance: i128) -> i128 {
+    match get_current_emission_data(e, total_supply) {
+        Some(emis_data) => {
+            let user_data = storage::get_user_emission_data(e, user);
+            match update_user_emissions(&user_data, &emis_data, balance) {
+                Some(data) => data.accrued,
+                None => user_data.unwrap_optimized().accrued,
+            }
+        }
+        None => 0,
+    }
+}
+
+/// Get the number of tokens currently emitted per second for each vote token, scaled by
+/// `SCALAR_7`. Returns 0 if emissions have expired or there is no supply.
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+pub fn get_emissions_per_vote_unit(e: &Env, total_supply: i128) -> i128 {
+    match storage::get_emission_config(e) {
+        Some(emis_config)
+            if emis_config.expiration > e.ledger().timestamp() && total_supply > 0 =>
+        {
+            (emis_config.eps as i128)
+                .fixed_div_floor(total_supply, SCALAR_7)
+                .unwrap_optimized()
+        }
+        _ => 0,
+    }
+}
+
+/// Get the token emissions are paid out in
+pub fn get_emission_token(e: &Env) -> Address {
+    match storage::get_emission_payout(e) {
+        EmissionPayout::Compound => storage::get_token(e),
+        EmissionPayout::Transfer(token) => token,
+    }
+}
+
+/// Set how claimed emissions are paid out
+///
+/// ### Arguments
+/// * `payout` - The new emission payout
+///
+/// ### Panics
+/// If the payout changes the token emissions are paid out in after emissions have been set, or
+/// if the token is the reward token of an emission program
+pub fn set_emission_payout(e: &Env, payout: &EmissionPayout) {
+    let new_token = match payout {
+        EmissionPayout::Compound => storage::get_token(e),
+        EmissionPayout::Transfer(token) => token.clone(),
+    };
+    if storage::get_emission_programs(e).contains(&new_token)
+        || (new_token != get_emission_token(e) && storage::get_emission_config(e).is_some())
+    {
+        panic_with_error!(e, TokenVotesError::InvalidEmissionConfigError);
+    }
+    storage::set_emission_payout(e, payout);
+
+    TokenVotesEvents::set_emission_payout(e, payout.clone());
+}
+
+/// Set the emissions for an emission program. Creates the program if it does not exist.
+///
+/// Expired programs are removed once every holder has accrued their emissions, making room for
+/// new programs.
+///
+/// ### Arguments
+/// * `total_supply` - The total supply of the vote token
+/// * `token` - The reward token of the emission program
+/// * `new_tokens` - The number of reward tokens being emitted
+/// * `new_expiration` - The time the emission will expire
+///
+/// ### Panics
+/// If the token is the underlying token or the token emissions are paid out in, or if the program
+/// would exceed the maximum number of emission programs
+pub fn set_program_emissions(
+    e: &Env,
+    total_supply: i128,
+    token: &Address,
+    new_tokens: i128,
+    new_expiration: u64,
+) {
+    if *token == storage::get_token(e) || *token == get_emission_token(e) {
+        panic_with_error!(e, TokenVotesError::InvalidEmissionConfigError);
+    }
+    let mut programs = storage::get_emission_programs(e);
+    if !programs.contains(token) {
+        if programs.len() >= MAX_EMISSION_PROGRAMS {
+            panic_with_error!(e, TokenVotesError::InvalidEmissionConfigError);
+        }
+        programs.push_back(token.clone());
+        storage::set_emission_programs(e, &programs);
+    }
+    storage::del_program_unsettled_supply(e, token);
+    let eps = set_emission_config(e, Some(token), total_supply, new_tokens, new_expiration);
+
+    TokenVotesEvents::set_program_emissions(e, token.clone(), eps, new_expiration);
+}
+
+/// Update a user's emissions for an emission program, and take the tokens they have accrued
+///
+/// ### Arguments
+/// * `program` - The reward token of the emission program, or None for the vote token emissions
+/// * `total_supply` - The total supply of the vote token
+/// * `user` - The address of the user
+/// * `balance` - The balance of the user
+///
+/// ### Returns
+/// The number of tokens accrued by the user
+fn accrue_emissions(
+    e: &Env,
+    program: Option<&Address>,
+    total_supply: i128,
+    user: &Address,
+    balance: i128,
+) -> i128 {
+    if let Some(emis_config) = get_config(e, program) {
+        let prev_emis_data = get_data(e, program).unwrap_optimized(); // exists if config exists
         let emis_data = match update_emission_data(e, &prev_emis_data, &emis_config, total_supply) {
             Some(data) => {
-                storage::set_emission_data(e, &data);
+                set_data(e, program, &data);
                 data
             }
             None => prev_emis_data,
         };
-        let prev_data = storage::get_user_emission_data(e, user);
+        let prev_data = get_user_data(e, program, user);
         let mut user_data = match update_user_emissions(&prev_data, &emis_data, balance) {
-            Some(data) => data,
+            Some(data) => {
+                if let Some(token) = program {
+                    settle_program(e, token, &emis_config, &emis_data, total_supply, balance);
+                }
+                data
+            }
             None => prev_data.unwrap_optimized(),
         };
 
-        let to_claim = user_data.accrued.clone();
-        if to_claim > 0 {
-            user_data.accrued = 0;
-            storage::set_user_emission_data(e, user, &user_data);
-
-            balance::mint_balance(e, &user, to_claim);
-
-            TokenVotesEvents::claim(&e, user.clone(), to_claim);
-        } else {
-            storage::set_user_emission_data(e, user, &user_data);
-        }
+        let to_claim = user_data.accrued;
+        user_data.accrued = 0;
+        set_user_data(e, program, user, &user_data);
         to_claim
     } else {
         0
     }
 }
 
-/// Update the emissions for a balance change
+/// Update a user's emissions for an emission program for a balance change
 ///
 /// ### Arguments
+/// * `program` - The reward token of the emission program, or None for the vote token emissions
 /// * `total_supply` - The total supply of the vote token
 /// * `user` - The address of the user
 /// * `balance` - The balance of the user
-pub fn update_emissions(e: &Env, total_supply: i128, user: &Address, balance: i128) {
-    if let Some(emis_config) = storage::get_emission_config(e) {
-        let prev_emis_data = storage::get_emission_data(e).unwrap_optimized(); // exists if config exists
+fn update_program_emissions(
+    e: &Env,
+    program: Option<&Address>,
+    total_supply: i128,
+    user: &Address,
+    balance: i128,
+) {
+    if let Some(emis_config) = get_config(e, program) {
+        let prev_emis_data = get_data(e, program).unwrap_optimized(); // exists if config exists
         let emis_data = match update_emission_data(e, &prev_emis_data, &emis_config, total_supply) {
             Some(data) => {
-                storage::set_emission_data(e, &data);
+                set_data(e, program, &data);
                 data
             }
             None => prev_emis_data,
         };
-        let user_data = storage::get_user_emission_data(e, user);
+        let user_data = get_user_data(e, program, user);
         if let Some(new_user_data) = update_user_emissions(&user_data, &emis_data, balance) {
-            storage::set_user_emission_data(e, user, &new_user_data);
+            set_user_data(e, program, user, &new_user_data);
+            if let Some(token) = program {
+                settle_program(e, token, &emis_config, &emis_data, total_supply, balance);
+            }
         }
     }
 }
 
-/// Set the emissions for the vote token
+/// Track the supply that has not accrued an expired emission program's final emissions. Once
+/// every holder has accrued them, the program is removed from the emission programs so balance
+/// changes stop updating it. Accrued emissions can still be claimed.
+///
+/// ### Arguments
+/// * `token` - The reward token of the emission program
+/// * `emis_config` - The emission config of the program
+/// * `emis_data` - The updated emission data of the program
+/// * `total_supply` - The total supply of the vote token
+/// * `balance` - The balance of the user that just accrued emissions
+fn settle_program(
+    e: &Env,
+    token: &Address,
+    emis_config: &EmissionConfig,
+    emis_data: &EmissionData,
+    total_supply: i128,
+    balance: i128,
+) {
+    if emis_data.last_time < emis_config.expiration {
+        return;
+    }
+    // the first update after expiration stores the supply, which has not changed since expiration
+    let unsettled = match storage::get_program_unsettled_supply(e, token) {
+        Some(0) => return,
+        Some(supply) => supply,
+        None => total_supply,
+    } - balance;
+    if unsettled > 0 {
+        storage::set_program_unsettled_supply(e, token, unsettled);
+    } else {
+        storage::set_program_unsettled_supply(e, token, 0);
+        let mut programs = storage::get_emission_programs(e);
+        if let Some(index) = programs.first_index_of(token) {
+            programs.remove(index);
+            storage::set_emission_programs(e, &programs);
+        }
+    }
+}
+
+/// Set the emission config for an emission program. Any tokens not yet emitted by the
+/// previous config are added to the new config.
 ///
 /// ### Arguments
+/// * `program` - The reward token of the emission program, or None for the vote token emissions
 /// * `total_supply` - The total supply of the vote token
 /// * `new_tokens` - The number of tokens being emitted
 /// * `new_expiration` - The time the emission will expire
-pub fn set_emissions(e: &Env, total_supply: i128, new_tokens: i128, new_expiration: u64) {
+///
+/// ### Returns
+/// The new emissions per second
+fn set_emission_config(
+    e: &Env,
+    program: Option<&Address>,
+    total_supply: i128,
+    new_tokens: i128,
+    new_expiration: u64,
+) -> u64 {
     if new_expiration <= e.ledger().timestamp() || new_tokens <= 0 {
         panic_with_error!(e, TokenVotesError::InvalidEmissionConfigError);
     }
     let mut tokens_left_to_emit = new_tokens;
-    if let Some(emis_config) = storage::get_emission_config(e) {
+    if let Some(emis_config) = get_config(e, program) {
         // data exists - update it with old config
-        let prev_emis_data = storage::get_emission_data(e).unwrap_optimized(); // exists if config exists
+        let prev_emis_data = get_data(e, program).unwrap_optimized(); // exists if config exists
         let mut emis_data =
             match update_emission_data(e, &prev_emis_data, &emis_config, total_supply) {
                 Some(data) => data,
@@ -99,7 +391,7 @@ pub fn set_emissions(e: &Env, total_supply: i128, new_tokens: i128, new_expirati
             // force the emission data to be updated to the current timestamp
             emis_data.last_time = e.ledger().timestamp();
         }
-        storage::set_emission_data(e, &emis_data);
+        set_data(e, program, &emis_data);
 
         // determine the amount of tokens not emitted from the last config
         if emis_config.expiration > e.ledger().timestamp() {
@@ -109,8 +401,9 @@ pub fn set_emissions(e: &Env, total_supply: i128, new_tokens: i128, new_expirati
         }
     } else {
         // no config or data exists yet - first time this reserve token will get emission
-        storage::set_emission_data(
+        set_data(
             e,
+            program,
             &EmissionData {
                 index: 0,
                 last_time: e.ledger().timestamp(),
@@ -125,9 +418,46 @@ pub fn set_emissions(e: &Env, total_supply: i128, new_tokens: i128, new_expirati
         expiration: new_expiration,
         eps,
     };
-    storage::set_emission_config(e, &new_config);
+    match program {
+        Some(token) => storage::set_program_emission_config(e, token, &new_config),
+        None => storage::set_emission_config(e, &new_config),
+    }
+    eps
+}
 
-    TokenVotesEvents::set_emissions(e, eps, new_expiration);
+fn get_config(e: &Env, program: Option<&Address>) -> Option<EmissionConfig> {
+    match program {
+        Some(token) => storage::get_program_emission_config(e, token),
+        None => storage::get_emission_config(e),
+    }
+}
+
+fn get_data(e: &Env, program: Option<&Address>) -> Option<EmissionData> {
+    match program {
+        Some(token) => storage::get_program_emission_data(e, token),
+        None => storage::get_emission_data(e),
+    }
+}
+
+fn set_data(e: &Env, program: Option<&Address>, data: &EmissionData) {
+    match program {
+        Some(token) => storage::set_program_emission_data(e, token, data),
+        None => storage::set_emission_data(e, data),
+    }
+}
+
+fn get_user_data(e: &Env, program: Option<&Address>, user: &Address) -> Option<UserEmissionData> {
+    match program {
+        Some(token) => storage::get_user_program_emission_data(e, user, token),
+        None => storage::get_user_emission_data(e, user),
+    }
+}
+
+fn set_user_data(e: &Env, program: Option<&Address>, user: &Address, data: &UserEmissionData) {
+    match program {
+        Some(token) => storage::set_user_program_emission_data(e, user, token, data),
+        None => storage::set_user_emission_data(e, user, data),
+    }
 }
 
 /// Update the emission data
@@ -219,7 +549,10 @@ fn update_user_emissions(
 
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec,
+    };
 
     use crate::checkpoints::Checkpoint;
 
@@ -278,6 +611,148 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_update_emissions_programs() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let user = Address::generate(&e);
+        let reward_token = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 1_123_456 * SCALAR_7;
+        let balance = 50_000 * SCALAR_7;
+        let emis_config = EmissionConfig {
+            expiration: t_now + ONE_DAY * 5,
+            eps: 5000000, // 0.5 tokens per second
+        };
+        let emis_data = EmissionData {
+            index: 1234567,
+            last_time: t_now - ONE_DAY,
+        };
+        let user_data = UserEmissionData {
+            index: 1000000,
+            accrued: 4,
+        };
+
+        e.as_contract(&contract, || {
+            storage::set_emission_config(&e, &emis_config);
+            storage::set_emission_data(&e, &emis_data);
+            storage::set_emission_programs(&e, &vec![&e, reward_token.clone()]);
+            storage::set_program_emission_config(
+                &e,
+                &reward_token,
+                &EmissionConfig {
+                    expiration: t_now + ONE_DAY * 5,
+                    eps: 10000000, // 1 token per second
+                },
+            );
+            storage::set_program_emission_data(&e, &reward_token, &emis_data);
+            storage::set_user_program_emission_data(&e, &user, &reward_token, &user_data);
+
+            update_emissions(&e, total_supply, &user, balance);
+
+            // default emissions are tracked independently of the program
+            let new_emis_data = storage::get_emission_data(&e).unwrap();
+            assert_eq!(new_emis_data.index, 1234567 + 384527);
+            let new_user_data = storage::get_user_emission_data(&e, &user).unwrap();
+            assert_eq!(new_user_data.index, new_emis_data.index);
+            assert_eq!(new_user_data.accrued, 8095_4700000);
+
+            let new_program_data = storage::get_program_emission_data(&e, &reward_token).unwrap();
+            assert_eq!(new_program_data.index, 1234567 + 769055);
+            assert_eq!(new_program_data.last_time, t_now);
+            let new_user_program_data =
+                storage::get_user_program_emission_data(&e, &user, &reward_token).unwrap();
+            assert_eq!(new_user_program_data.index, new_program_data.index);
+            assert_eq!(new_user_program_data.accrued, 4 + 5018_1100000);
+        });
+    }
+
+    #[test]
+    fn test_update_emissions_prunes_settled_programs() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 100 * SCALAR_7;
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &Address::generate(&e));
+            let mut tokens = vec![&e];
+            for _ in 0..MAX_EMISSION_PROGRAMS {
+                let token = Address::generate(&e);
+                set_program_emissions(&e, total_supply, &token, 1_000 * SCALAR_7, t_now + ONE_DAY);
+                tokens.push_back(token);
+            }
+
+            e.ledger().set(LedgerInfo {
+                timestamp: t_now + ONE_DAY + 1,
+                protocol_version: 20,
+                sequence_number: 456,
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 100000,
+                min_persistent_entry_ttl: 100000,
+                max_entry_ttl: 100000,
+            });
+
+            // samwise accrues the final emissions, frodo has not
+            update_emissions(&e, total_supply, &samwise, 60 * SCALAR_7);
+            assert_eq!(storage::get_emission_programs(&e), tokens);
+            let token = tokens.get_unchecked(0);
+            assert_eq!(
+                storage::get_program_unsettled_supply(&e, &token),
+                Some(40 * SCALAR_7)
+            );
+
+            // frodo accrues the final emissions, so the programs are removed
+            update_emissions(&e, total_supply, &frodo, 40 * SCALAR_7);
+            assert_eq!(storage::get_emission_programs(&e).len(), 0);
+            assert_eq!(storage::get_program_unsettled_supply(&e, &token), Some(0));
+            let user_data = storage::get_user_program_emission_data(&e, &frodo, &token).unwrap();
+            assert_eq!(user_data.accrued, 3999974400);
+
+            // frodo can still claim, and the program is not tracked again
+            let claimed = accrue_emissions(&e, Some(&token), total_supply, &frodo, 0);
+            assert_eq!(claimed, 3999974400);
+            assert_eq!(storage::get_program_unsettled_supply(&e, &token), Some(0));
+
+            // a new program can be added
+            let new_token = Address::generate(&e);
+            set_program_emissions(
+                &e,
+                total_supply,
+                &new_token,
+                1_000 * SCALAR_7,
+                t_now + ONE_DAY * 2,
+            );
+            assert_eq!(storage::get_emission_programs(&e), vec![&e, new_token]);
+        });
+    }
+
     #[test]
     fn test_update_emissions_no_user_data_with_balance_accrues() {
         let e = Env::default();
@@ -580,8 +1055,9 @@ mod tests {
             storage::set_emission_config(&e, &emis_config);
             storage::set_emission_data(&e, &emis_data);
             storage::set_user_emission_data(&e, &user, &user_data);
+            storage::set_emission_reserve(&e, 100_000 * SCALAR_7);
 
-            let result = claim_emissions(&e, total_supply, &user, balance);
+            let result = claim_emissions(&e, total_supply, &user, balance, &user);
 
             let new_emis_data = storage::get_emission_data(&e).unwrap();
             assert_eq!(new_emis_data.index, 1234567 + 384527);
@@ -593,13 +1069,17 @@ mod tests {
             assert_eq!(result, 4 + 3095_4700000);
             let balance = storage::get_balance(&e, &user);
             assert_eq!(balance, 4 + 3095_4700000);
+            assert_eq!(
+                storage::get_emission_reserve(&e),
+                Some(100_000 * SCALAR_7 - result)
+            );
             let votes = storage::get_voting_units(&e, &user);
             assert_eq!(votes.to_checkpoint_data(), (123, 4 + 3095_4700000));
         });
     }
 
     #[test]
-    fn test_claim_emissions_already_updated_and_delegated() {
+    fn test_claim_emissions_to_recipient() {
         let e = Env::default();
         let t_now = 1500000000;
         e.ledger().set(LedgerInfo {
@@ -614,7 +1094,7 @@ mod tests {
         });
 
         let user = Address::generate(&e);
-        let samwise = Address::generate(&e);
+        let recipient = Address::generate(&e);
         let contract = e.register_contract(None, crate::TokenVotes {});
 
         let total_supply = 1_123_456 * SCALAR_7;
@@ -625,40 +1105,34 @@ mod tests {
         };
         let emis_data = EmissionData {
             index: 1234567,
-            last_time: t_now,
+            last_time: t_now - ONE_DAY,
         };
         let user_data = UserEmissionData {
-            index: 1234567,
-            accrued: 3095_4700000,
+            index: 1000000,
+            accrued: 4,
         };
 
         e.as_contract(&contract, || {
             storage::set_emission_config(&e, &emis_config);
             storage::set_emission_data(&e, &emis_data);
             storage::set_user_emission_data(&e, &user, &user_data);
-            storage::set_delegate(&e, &user, &samwise);
+            storage::set_emission_reserve(&e, 100_000 * SCALAR_7);
 
-            let result = claim_emissions(&e, total_supply, &user, balance);
+            let result = claim_emissions(&e, total_supply, &user, balance, &recipient);
 
-            let new_emis_data = storage::get_emission_data(&e).unwrap();
-            assert_eq!(new_emis_data.index, 1234567);
-            assert_eq!(new_emis_data.last_time, t_now);
             let new_user_data = storage::get_user_emission_data(&e, &user).unwrap();
-            assert_eq!(new_user_data.index, new_emis_data.index);
             assert_eq!(new_user_data.accrued, 0);
 
-            assert_eq!(result, 3095_4700000);
-            let balance = storage::get_balance(&e, &user);
-            assert_eq!(balance, 3095_4700000);
-            let votes = storage::get_voting_units(&e, &user);
-            assert_eq!(votes, 0);
-            let samwise_votes = storage::get_voting_units(&e, &samwise);
-            assert_eq!(samwise_votes.to_checkpoint_data(), (123, 3095_4700000));
+            assert_eq!(result, 4 + 3095_4700000);
+            assert_eq!(storage::get_balance(&e, &user), 0);
+            assert_eq!(storage::get_balance(&e, &recipient), 4 + 3095_4700000);
+            let votes = storage::get_voting_units(&e, &recipient);
+            assert_eq!(votes.to_checkpoint_data(), (123, 4 + 3095_4700000));
         });
     }
 
     #[test]
-    fn test_claim_no_config() {
+    fn test_claim_emissions_already_updated_and_delegated() {
         let e = Env::default();
         let t_now = 1500000000;
         e.ledger().set(LedgerInfo {
@@ -673,12 +1147,72 @@ mod tests {
         });
 
         let user = Address::generate(&e);
+        let samwise = Address::generate(&e);
         let contract = e.register_contract(None, crate::TokenVotes {});
 
-        let total_supply = 100 * SCALAR_7;
+        let total_supply = 1_123_456 * SCALAR_7;
+        let balance = 50_000 * SCALAR_7;
+        let emis_config = EmissionConfig {
+            expiration: t_now + ONE_DAY * 5,
+            eps: 5000000, // 0.5 tokens per second
+        };
+        let emis_data = EmissionData {
+            index: 1234567,
+            last_time: t_now,
+        };
+        let user_data = UserEmissionData {
+            index: 1234567,
+            accrued: 3095_4700000,
+        };
+
+        e.as_contract(&contract, || {
+            storage::set_emission_config(&e, &emis_config);
+            storage::set_emission_data(&e, &emis_data);
+            storage::set_user_emission_data(&e, &user, &user_data);
+            storage::set_emission_reserve(&e, 100_000 * SCALAR_7);
+            storage::set_delegate(&e, &user, &samwise);
+
+            let result = claim_emissions(&e, total_supply, &user, balance, &user);
+
+            let new_emis_data = storage::get_emission_data(&e).unwrap();
+            assert_eq!(new_emis_data.index, 1234567);
+            assert_eq!(new_emis_data.last_time, t_now);
+            let new_user_data = storage::get_user_emission_data(&e, &user).unwrap();
+            assert_eq!(new_user_data.index, new_emis_data.index);
+            assert_eq!(new_user_data.accrued, 0);
+
+            assert_eq!(result, 3095_4700000);
+            let balance = storage::get_balance(&e, &user);
+            assert_eq!(balance, 3095_4700000);
+            let votes = storage::get_voting_units(&e, &user);
+            assert_eq!(votes, 0);
+            let samwise_votes = storage::get_voting_units(&e, &samwise);
+            assert_eq!(samwise_votes.to_checkpoint_data(), (123, 3095_4700000));
+        });
+    }
+
+    #[test]
+    fn test_claim_no_config() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 100 * SCALAR_7;
         let balance = 5 * SCALAR_7;
         e.as_contract(&contract, || {
-            let result = claim_emissions(&e, total_supply, &user, balance);
+            let result = claim_emissions(&e, total_supply, &user, balance, &user);
 
             assert_eq!(result, 0);
             assert!(storage::get_emission_data(&e).is_none());
@@ -724,7 +1258,7 @@ mod tests {
             storage::set_emission_data(&e, &emis_data);
             storage::set_user_emission_data(&e, &user, &user_data);
 
-            let result = claim_emissions(&e, total_supply, &user, balance);
+            let result = claim_emissions(&e, total_supply, &user, balance, &user);
 
             assert_eq!(result, 0);
             let new_emis_data = storage::get_emission_data(&e).unwrap();
@@ -742,6 +1276,200 @@ mod tests {
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_claim_emissions_exceeds_reserve() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let emis_config = EmissionConfig {
+            expiration: t_now + ONE_DAY * 5,
+            eps: 5000000, // 0.5 tokens per second
+        };
+        let emis_data = EmissionData {
+            index: 1234567,
+            last_time: t_now - ONE_DAY,
+        };
+
+        e.as_contract(&contract, || {
+            storage::set_emission_config(&e, &emis_config);
+            storage::set_emission_data(&e, &emis_data);
+            storage::set_emission_reserve(&e, 100 * SCALAR_7);
+
+            claim_emissions(&e, 1_123_456 * SCALAR_7, &user, 50_000 * SCALAR_7, &user);
+        });
+    }
+
+    #[test]
+    fn test_claim_emissions_untracked_reserve() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 1_123_456 * SCALAR_7;
+        let emis_config = EmissionConfig {
+            expiration: t_now + ONE_DAY * 5,
+            eps: 5000000, // 0.5 tokens per second
+        };
+        let emis_data = EmissionData {
+            index: 1234567,
+            last_time: t_now - ONE_DAY,
+        };
+        let user_data = UserEmissionData {
+            index: 1000000,
+            accrued: 4,
+        };
+
+        e.as_contract(&contract, || {
+            // emissions were set before the reserve was tracked
+            storage::set_token(&e, &Address::generate(&e));
+            storage::set_emission_config(&e, &emis_config);
+            storage::set_emission_data(&e, &emis_data);
+            storage::set_user_emission_data(&e, &user, &user_data);
+
+            let result = claim_emissions(&e, total_supply, &user, 50_000 * SCALAR_7, &user);
+            assert_eq!(result, 4 + 3095_4700000);
+            assert_eq!(storage::get_balance(&e, &user), 4 + 3095_4700000);
+            assert_eq!(storage::get_emission_reserve(&e), None);
+
+            // new emissions do not start tracking the reserve
+            set_emissions(&e, total_supply, 100 * SCALAR_7, t_now + ONE_DAY * 10);
+            assert_eq!(storage::get_emission_reserve(&e), None);
+        });
+    }
+
+    /********** views **********/
+
+    #[test]
+    fn test_get_pending_emissions() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 1_123_456 * SCALAR_7;
+        let balance = 50_000 * SCALAR_7;
+        let emis_config = EmissionConfig {
+            expiration: t_now + ONE_DAY * 5,
+            eps: 5000000, // 0.5 tokens per second
+        };
+        let emis_data = EmissionData {
+            index: 1234567,
+            last_time: t_now - ONE_DAY,
+        };
+        let user_data = UserEmissionData {
+            index: 1000000,
+            accrued: 4,
+        };
+
+        e.as_contract(&contract, || {
+            assert!(get_current_emission_data(&e, total_supply).is_none());
+            assert_eq!(get_pending_emissions(&e, total_supply, &user, balance), 0);
+
+            storage::set_emission_config(&e, &emis_config);
+            storage::set_emission_data(&e, &emis_data);
+            storage::set_user_emission_data(&e, &user, &user_data);
+
+            let current_emis_data = get_current_emission_data(&e, total_supply).unwrap();
+            assert_eq!(current_emis_data.index, 1234567 + 384527);
+            assert_eq!(current_emis_data.last_time, t_now);
+            let pending = get_pending_emissions(&e, total_supply, &user, balance);
+            assert_eq!(pending, 4 + 3095_4700000);
+
+            // nothing is written
+            let stored_emis_data = storage::get_emission_data(&e).unwrap();
+            assert_eq!(stored_emis_data.index, 1234567);
+            assert_eq!(stored_emis_data.last_time, t_now - ONE_DAY);
+            let stored_user_data = storage::get_user_emission_data(&e, &user).unwrap();
+            assert_eq!(stored_user_data.index, 1000000);
+            assert_eq!(stored_user_data.accrued, 4);
+
+            // a claim pays out the pending emissions
+            storage::set_emission_reserve(&e, 100_000 * SCALAR_7);
+            let claimed = claim_emissions(&e, total_supply, &user, balance, &user);
+            assert_eq!(claimed, pending);
+        });
+    }
+
+    #[test]
+    fn test_get_emissions_per_vote_unit() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 1_000 * SCALAR_7;
+
+        e.as_contract(&contract, || {
+            assert_eq!(get_emissions_per_vote_unit(&e, total_supply), 0);
+
+            storage::set_emission_config(
+                &e,
+                &EmissionConfig {
+                    expiration: t_now + ONE_DAY,
+                    eps: 5000000, // 0.5 tokens per second
+                },
+            );
+            assert_eq!(get_emissions_per_vote_unit(&e, total_supply), 5000);
+            assert_eq!(get_emissions_per_vote_unit(&e, 0), 0);
+
+            storage::set_emission_config(
+                &e,
+                &EmissionConfig {
+                    expiration: t_now,
+                    eps: 5000000, // 0.5 tokens per second
+                },
+            );
+            assert_eq!(get_emissions_per_vote_unit(&e, total_supply), 0);
+        });
+    }
+
     /********** set_emissions **********/
 
     #[test]
@@ -770,6 +1498,7 @@ mod tests {
             let new_config = storage::get_emission_config(&e).unwrap();
             assert_eq!(new_config.expiration, new_expiration);
             assert_eq!(new_config.eps, 115740);
+            assert_eq!(storage::get_emission_reserve(&e), Some(new_tokens));
             let new_emis_data = storage::get_emission_data(&e).unwrap();
             assert_eq!(new_emis_data.index, 0);
             assert_eq!(new_emis_data.last_time, t_now);
@@ -865,4 +1594,177 @@ mod tests {
             assert_eq!(new_emis_data.last_time, t_now);
         });
     }
+
+    /********** set_emission_payout **********/
+
+    #[test]
+    fn test_set_emission_payout() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let token = Address::generate(&e);
+        let reward_token = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &token);
+            assert_eq!(get_emission_token(&e), token);
+
+            let payout = EmissionPayout::Transfer(reward_token.clone());
+            set_emission_payout(&e, &payout);
+            assert_eq!(storage::get_emission_payout(&e), payout);
+            assert_eq!(get_emission_token(&e), reward_token);
+
+            // paying out the underlying token can be changed once emissions are set
+            set_emission_payout(&e, &EmissionPayout::Compound);
+            set_emissions(&e, 1, 14_000 * SCALAR_7, t_now + ONE_DAY);
+            let payout = EmissionPayout::Transfer(token.clone());
+            set_emission_payout(&e, &payout);
+            assert_eq!(storage::get_emission_payout(&e), payout);
+            assert_eq!(get_emission_token(&e), token);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #104)")]
+    fn test_set_emission_payout_new_token_after_emissions() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &Address::generate(&e));
+            set_emissions(&e, 1, 14_000 * SCALAR_7, t_now + ONE_DAY);
+
+            set_emission_payout(&e, &EmissionPayout::Transfer(Address::generate(&e)));
+        });
+    }
+
+    /********** set_program_emissions **********/
+
+    #[test]
+    fn test_set_program_emissions_init() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let reward_token = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let total_supply = 654_321 * SCALAR_7;
+        let new_tokens = 14_000 * SCALAR_7;
+        let new_expiration = t_now + ONE_DAY * 14;
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &Address::generate(&e));
+
+            set_program_emissions(&e, total_supply, &reward_token, new_tokens, new_expiration);
+
+            assert_eq!(
+                storage::get_emission_programs(&e),
+                vec![&e, reward_token.clone()]
+            );
+            assert!(storage::get_emission_config(&e).is_none());
+            let new_config = storage::get_program_emission_config(&e, &reward_token).unwrap();
+            assert_eq!(new_config.expiration, new_expiration);
+            assert_eq!(new_config.eps, 115740);
+            let new_emis_data = storage::get_program_emission_data(&e, &reward_token).unwrap();
+            assert_eq!(new_emis_data.index, 0);
+            assert_eq!(new_emis_data.last_time, t_now);
+
+            // updating the program does not add it again
+            set_program_emissions(&e, total_supply, &reward_token, new_tokens, new_expiration);
+            assert_eq!(storage::get_emission_programs(&e).len(), 1);
+            let new_config = storage::get_program_emission_config(&e, &reward_token).unwrap();
+            assert_eq!(new_config.eps, 231480);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #104)")]
+    fn test_set_program_emissions_underlying_token() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let token = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &token);
+
+            set_program_emissions(&e, 1, &token, 14_000 * SCALAR_7, t_now + ONE_DAY);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #104)")]
+    fn test_set_program_emissions_too_many_programs() {
+        let e = Env::default();
+        let t_now = 1500000000;
+        e.ledger().set(LedgerInfo {
+            timestamp: t_now,
+            protocol_version: 20,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 100000,
+        });
+
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        e.as_contract(&contract, || {
+            storage::set_token(&e, &Address::generate(&e));
+
+            for _ in 0..(MAX_EMISSION_PROGRAMS + 1) {
+                set_program_emissions(
+                    &e,
+                    1,
+                    &Address::generate(&e),
+                    14_000 * SCALAR_7,
+                    t_now + ONE_DAY,
+                );
+            }
+        });
+    }
 }
@@ -21,4 +21,15 @@ pub enum TokenVotesError {
     InvalidCheckpointError = 102,
     SequenceNotClosedError = 103,
     InvalidEmissionConfigError = 104,
+    InvalidDelegateSplitError = 105,
+    SignatureExpiredError = 106,
+    InvalidNonceError = 107,
+    InvalidLockError = 108,
+    LockNotExpiredError = 109,
+    UnbondNotCompleteError = 110,
+    InvalidUnbondDelayError = 111,
+    BalanceLockedError = 112,
+    InvalidAssetError = 113,
+    NonTransferableError = 114,
+    ReputationNotExpiredError = 115,
 }
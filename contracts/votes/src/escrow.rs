@@ -0,0 +1,392 @@
+#![cfg(feature = "vote-escrow")]
+
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::{
+    constants::{BPS_SCALAR, LOCK_PERIOD_LEDGERS, MAX_CHECKPOINT_AGE_LEDGERS, MAX_LOCK_LEDGERS},
+    delegation::get_delegate_split,
+    error::TokenVotesError,
+    storage::{self, Lock, VotePoint},
+};
+
+#[cfg(feature = "extended-delegation")]
+use crate::delegation::update_delegator_status;
+
+/// Round a ledger down to the start of its lock period
+pub fn round_to_period(ledger: u32) -> u32 {
+    ledger / LOCK_PERIOD_LEDGERS * LOCK_PERIOD_LEDGERS
+}
+
+/// Require that a lock is valid. The lock must end after the current ledger, within
+/// `MAX_LOCK_LEDGERS`, and cannot end before the account's existing lock.
+///
+/// ### Arguments
+/// * `old_lock` - The existing lock of the account
+/// * `new_lock` - The new lock of the account
+pub fn require_valid_lock(e: &Env, old_lock: &Lock, new_lock: &Lock) {
+    let cur_ledger = e.ledger().sequence();
+    if new_lock.end <= cur_ledger
+        || new_lock.end > cur_ledger + MAX_LOCK_LEDGERS
+        || new_lock.end < old_lock.end
+    {
+        panic_with_error!(e, TokenVotesError::InvalidLockError);
+    }
+}
+
+/// Get the voting power of a delegatee, or of the total supply if `address` is None, at a ledger
+///
+/// ### Arguments
+/// * `address` - The address of the delegatee, or None for the total supply
+/// * `sequence` - The ledger sequence to get the voting power at
+pub fn get_voting_power(e: &Env, address: Option<&Address>, sequence: u32) -> i128 {
+    let mut point = storage::get_vote_point(e, address);
+    if point.ledger > sequence {
+        // find the last point written at or before `sequence`
+        let checkpoints = storage::get_vote_point_checkpoints(e, address);
+        let mut low = 0;
+        let mut high = checkpoints.len();
+        while low < high {
+            let mid = (low + high) / 2;
+            if checkpoints.get_unchecked(mid).ledger <= sequence {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low == 0 {
+            return 0;
+        }
+        point = checkpoints.get_unchecked(low - 1);
+    }
+    advance_point(e, address, point, sequence).bias / MAX_LOCK_LEDGERS as i128
+}
+
+/// Update the voting power of an account's delegatees and the total supply for a change in the
+/// account's lock
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `old_lock` - The lock before the change
+/// * `new_lock` - The lock after the change
+pub fn update_lock_power(e: &Env, account: &Address, old_lock: &Lock, new_lock: &Lock) {
+    update_split_points(e, &get_delegate_split(e, account), old_lock, new_lock);
+    update_point(e, None, old_lock, new_lock);
+    #[cfg(feature = "extended-delegation")]
+    update_delegator_status(e, account, old_lock.amount > 0, new_lock.amount > 0);
+}
+
+/// Move the voting power of an account's lock from one set of delegatees to another
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `from` - The delegatees and their share in basis points before the change
+/// * `to` - The delegatees and their share in basis points after the change
+pub fn move_lock_power(
+    e: &Env,
+    account: &Address,
+    from: &Vec<(Address, u32)>,
+    to: &Vec<(Address, u32)>,
+) {
+    let lock = storage::get_lock(e, account);
+    if lock.end > e.ledger().sequence() {
+        let empty_lock = Lock { amount: 0, end: 0 };
+        update_split_points(e, from, &lock, &empty_lock);
+        update_split_points(e, to, &empty_lock, &lock);
+    }
+}
+
+/// Update the vote points of each delegatee in a split for a change in a lock. Each delegatee
+/// receives their share of the locked amount rounded down, and the last delegatee receives
+/// any remainder.
+fn update_split_points(e: &Env, split: &Vec<(Address, u32)>, old_lock: &Lock, new_lock: &Lock) {
+    let mut old_remaining = old_lock.amount;
+    let mut new_remaining = new_lock.amount;
+    let last_index = split.len() - 1;
+    for (index, (delegatee, bps)) in split.iter().enumerate() {
+        let (old_amount, new_amount) = if index as u32 == last_index {
+            (old_remaining, new_remaining)
+        } else {
+            (
+                old_lock.amount * bps as i128 / BPS_SCALAR as i128,
+                new_lock.amount * bps as i128 / BPS_SCALAR as i128,
+            )
+        };
+        old_remaining -= old_amount;
+        new_remaining -= new_amount;
+        update_point(
+            e,
+            Some(&delegatee),
+            &Lock {
+                amount: old_amount,
+                end: old_lock.end,
+            },
+            &Lock {
+                amount: new_amount,
+                end: new_lock.end,
+            },
+        );
+    }
+}
+
+/// Update the vote point of a delegatee, or of the total supply if `address` is None, by
+/// removing the voting power of `old_lock` and adding the voting power of `new_lock`
+fn update_point(e: &Env, address: Option<&Address>, old_lock: &Lock, new_lock: &Lock) {
+    let cur_ledger = e.ledger().sequence();
+    let prev_point = storage::get_vote_point(e, address);
+    let mut point = advance_point(e, address, prev_point.clone(), cur_ledger);
+    if old_lock.end > cur_ledger && old_lock.amount > 0 {
+        point.bias -= old_lock.amount * (old_lock.end - cur_ledger) as i128;
+        point.slope -= old_lock.amount;
+        let slope_change = storage::get_slope_change(e, address, old_lock.end);
+        storage::set_slope_change(e, address, old_lock.end, slope_change - old_lock.amount);
+    }
+    if new_lock.end > cur_ledger && new_lock.amount > 0 {
+        point.bias += new_lock.amount * (new_lock.end - cur_ledger) as i128;
+        point.slope += new_lock.amount;
+        let slope_change = storage::get_slope_change(e, address, new_lock.end);
+        storage::set_slope_change(e, address, new_lock.end, slope_change + new_lock.amount);
+    }
+
+    // push the previous point to the checkpoints so past voting power can be computed
+    if prev_point.ledger < cur_ledger {
+        let mut checkpoints = storage::get_vote_point_checkpoints(e, address);
+        checkpoints.push_back(prev_point);
+        // remove any checkpoints that can no longer be used for a vote
+        if let Some(ledger_cutoff) = cur_ledger.checked_sub(MAX_CHECKPOINT_AGE_LEDGERS) {
+            while checkpoints.len() > 1 && checkpoints.get_unchecked(1).ledger <= ledger_cutoff {
+                checkpoints.pop_front();
+            }
+        }
+        storage::set_vote_point_checkpoints(e, address, &checkpoints);
+    }
+    storage::set_vote_point(e, address, &point);
+}
+
+/// Advance a vote point to `ledger`, applying any slope changes that occur in between
+fn advance_point(e: &Env, address: Option<&Address>, point: VotePoint, ledger: u32) -> VotePoint {
+    let mut point = point;
+    let mut period_end = round_to_period(point.ledger) + LOCK_PERIOD_LEDGERS;
+    while point.slope > 0 && period_end <= ledger {
+        point.bias -= point.slope * (period_end - point.ledger) as i128;
+        point.slope -= storage::get_slope_change(e, address, period_end);
+        point.ledger = period_end;
+        period_end += LOCK_PERIOD_LEDGERS;
+    }
+    point.bias -= point.slope * (ledger - point.ledger) as i128;
+    point.ledger = ledger;
+    point
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+
+    use super::*;
+
+    const START_LEDGER: u32 = 10 * LOCK_PERIOD_LEDGERS;
+
+    fn set_ledger(e: &Env, sequence: u32) {
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 20,
+            sequence_number: sequence,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 100000,
+            min_persistent_entry_ttl: 100000,
+            max_entry_ttl: 10000000,
+        });
+    }
+
+    /********** round_to_period **********/
+
+    #[test]
+    fn test_round_to_period() {
+        assert_eq!(round_to_period(0), 0);
+        assert_eq!(round_to_period(LOCK_PERIOD_LEDGERS - 1), 0);
+        assert_eq!(round_to_period(LOCK_PERIOD_LEDGERS), LOCK_PERIOD_LEDGERS);
+        assert_eq!(
+            round_to_period(START_LEDGER + LOCK_PERIOD_LEDGERS + 1),
+            START_LEDGER + LOCK_PERIOD_LEDGERS
+        );
+    }
+
+    /********** require_valid_lock **********/
+
+    #[test]
+    fn test_require_valid_lock() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER + 100);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let old_lock = Lock {
+            amount: 100,
+            end: START_LEDGER + LOCK_PERIOD_LEDGERS,
+        };
+        e.as_contract(&contract, || {
+            // same end as existing lock
+            require_valid_lock(&e, &old_lock, &old_lock);
+            // maximum lock length
+            let max_lock = Lock {
+                amount: 100,
+                end: START_LEDGER + 100 + MAX_LOCK_LEDGERS,
+            };
+            require_valid_lock(&e, &old_lock, &max_lock);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #108)")]
+    fn test_require_valid_lock_ends_at_current_ledger() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let old_lock = Lock { amount: 0, end: 0 };
+        let new_lock = Lock {
+            amount: 100,
+            end: START_LEDGER,
+        };
+        e.as_contract(&contract, || {
+            require_valid_lock(&e, &old_lock, &new_lock);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #108)")]
+    fn test_require_valid_lock_over_max_lock() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let old_lock = Lock { amount: 0, end: 0 };
+        let new_lock = Lock {
+            amount: 100,
+            end: START_LEDGER + MAX_LOCK_LEDGERS + 1,
+        };
+        e.as_contract(&contract, || {
+            require_valid_lock(&e, &old_lock, &new_lock);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #108)")]
+    fn test_require_valid_lock_before_old_end() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let old_lock = Lock {
+            amount: 100,
+            end: START_LEDGER + 2 * LOCK_PERIOD_LEDGERS,
+        };
+        let new_lock = Lock {
+            amount: 200,
+            end: START_LEDGER + LOCK_PERIOD_LEDGERS,
+        };
+        e.as_contract(&contract, || {
+            require_valid_lock(&e, &old_lock, &new_lock);
+        });
+    }
+
+    /********** get_voting_power **********/
+
+    #[test]
+    fn test_voting_power_max_lock_decays() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER);
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let amount: i128 = 1000_0000000;
+        let lock = Lock {
+            amount,
+            end: START_LEDGER + MAX_LOCK_LEDGERS,
+        };
+        e.as_contract(&contract, || {
+            update_lock_power(&e, &user, &Lock { amount: 0, end: 0 }, &lock);
+
+            // a maximum length lock has one vote per token
+            assert_eq!(get_voting_power(&e, Some(&user), START_LEDGER), amount);
+            assert_eq!(get_voting_power(&e, None, START_LEDGER), amount);
+
+            // voting power decays linearly to zero at the end of the lock
+            let halfway = START_LEDGER + MAX_LOCK_LEDGERS / 2;
+            assert_eq!(get_voting_power(&e, Some(&user), halfway), amount / 2);
+            assert_eq!(get_voting_power(&e, None, halfway), amount / 2);
+            assert_eq!(
+                get_voting_power(&e, Some(&user), lock.end - 1),
+                amount / MAX_LOCK_LEDGERS as i128
+            );
+            assert_eq!(get_voting_power(&e, Some(&user), lock.end), 0);
+            assert_eq!(
+                get_voting_power(&e, Some(&user), lock.end + LOCK_PERIOD_LEDGERS),
+                0
+            );
+            assert_eq!(
+                get_voting_power(&e, None, lock.end + LOCK_PERIOD_LEDGERS),
+                0
+            );
+        });
+    }
+
+    #[test]
+    fn test_voting_power_past_period_lookup() {
+        let e = Env::default();
+        set_ledger(&e, START_LEDGER);
+        let user = Address::generate(&e);
+        let contract = e.register_contract(None, crate::TokenVotes {});
+
+        let amount: i128 = 1000_0000000;
+        let lock_end = START_LEDGER + 4 * LOCK_PERIOD_LEDGERS;
+        let lock = Lock {
+            amount,
+            end: lock_end,
+        };
+        let increased_lock = Lock {
+            amount: 2 * amount,
+            end: lock_end,
+        };
+        let update_ledger = START_LEDGER + 2 * LOCK_PERIOD_LEDGERS + 100;
+        e.as_contract(&contract, || {
+            update_lock_power(&e, &user, &Lock { amount: 0, end: 0 }, &lock);
+        });
+
+        set_ledger(&e, update_ledger);
+        e.as_contract(&contract, || {
+            update_lock_power(&e, &user, &lock, &increased_lock);
+
+            // before the first lock
+            assert_eq!(get_voting_power(&e, Some(&user), START_LEDGER - 1), 0);
+            assert_eq!(get_voting_power(&e, None, START_LEDGER - 1), 0);
+
+            // a past period uses the original lock
+            let past_ledger = START_LEDGER + LOCK_PERIOD_LEDGERS;
+            let expected_past =
+                amount * (lock_end - past_ledger) as i128 / MAX_LOCK_LEDGERS as i128;
+            assert_eq!(
+                get_voting_power(&e, Some(&user), past_ledger),
+                expected_past
+            );
+            assert_eq!(get_voting_power(&e, None, past_ledger), expected_past);
+
+            // the ledger before the update still uses the original lock
+            let expected_before =
+                amount * (lock_end - update_ledger + 1) as i128 / MAX_LOCK_LEDGERS as i128;
+            assert_eq!(
+                get_voting_power(&e, Some(&user), update_ledger - 1),
+                expected_before
+            );
+
+            // the current ledger uses the increased lock
+            let expected_now =
+                2 * amount * (lock_end - update_ledger) as i128 / MAX_LOCK_LEDGERS as i128;
+            assert_eq!(
+                get_voting_power(&e, Some(&user), update_ledger),
+                expected_now
+            );
+            assert_eq!(get_voting_power(&e, None, update_ledger), expected_now);
+            assert_eq!(get_voting_power(&e, Some(&user), lock_end), 0);
+        });
+    }
+}
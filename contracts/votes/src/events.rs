@@ -1,5 +1,11 @@
 use soroban_sdk::{Address, Env, Symbol};
 
+#[cfg(any(feature = "bonding", feature = "extended-delegation"))]
+use soroban_sdk::Vec;
+
+#[cfg(feature = "bonding")]
+use crate::storage::EmissionPayout;
+
 pub struct TokenVotesEvents {}
 
 impl TokenVotesEvents {
@@ -12,6 +18,27 @@ impl TokenVotesEvents {
         e.events().publish(topics, old_delegatee);
     }
 
+    #[cfg(feature = "extended-delegation")]
+    /// Emitted when a voter splits their votes between multiple addresses
+    ///
+    /// - topics - `["delegate_split", delegator: Address]`
+    /// - data - `[split: Vec<(Address, u32)>]`
+    pub fn delegate_split(e: &Env, delegator: Address, split: Vec<(Address, u32)>) {
+        let topics = (Symbol::new(e, "delegate_split"), delegator);
+        e.events().publish(topics, split);
+    }
+
+    #[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+    /// Emitted when a voter stops delegating their votes
+    ///
+    /// - topics - `["abstain", delegator: Address]`
+    /// - data - `[old_split: Vec<(Address, u32)>]`
+    pub fn abstain(e: &Env, delegator: Address, old_split: Vec<(Address, u32)>) {
+        let topics = (Symbol::new(e, "abstain"), delegator);
+        e.events().publish(topics, old_split);
+    }
+
+    #[cfg(not(feature = "vote-escrow"))]
     /// Emitted when a delagate's votes are changed
     ///
     /// This event is emitted for the delegated account's votes if a transfer, deposit, or withdraw occurs
@@ -23,13 +50,53 @@ impl TokenVotesEvents {
         e.events().publish(topics, (old_votes, new_votes));
     }
 
-    #[cfg(not(feature = "bonding"))]
+    #[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
     pub fn set_admin(e: &Env, admin: Address, new_admin: Address) {
         let topics = (Symbol::new(e, "set_admin"), admin);
         e.events().publish(topics, new_admin);
     }
 
-    #[cfg(feature = "bonding")]
+    #[cfg(feature = "soulbound")]
+    /// Emitted when the admin sets the expiry period of minted reputation
+    ///
+    /// - topics - `["set_expiry_period"]`
+    /// - data - `[period: u32]`
+    pub fn set_expiry_period(e: &Env, period: u32) {
+        let topics = (Symbol::new(e, "set_expiry_period"),);
+        e.events().publish(topics, period);
+    }
+
+    #[cfg(feature = "soulbound")]
+    /// Emitted when the balance of an account with expired reputation is burned
+    ///
+    /// - topics - `["expire", account: Address]`
+    /// - data - `[amount: i128]`
+    pub fn expire(e: &Env, account: Address, amount: i128) {
+        let topics = (Symbol::new(e, "expire"), account);
+        e.events().publish(topics, amount);
+    }
+
+    #[cfg(feature = "nft")]
+    /// Emitted when an account deposits an NFT into the votes contract
+    ///
+    /// - topics - `["deposit", account: Address]`
+    /// - data - `[token_id: u32]`
+    pub fn deposit_nft(e: &Env, account: Address, token_id: u32) {
+        let topics = (Symbol::new(e, "deposit"), account);
+        e.events().publish(topics, token_id);
+    }
+
+    #[cfg(feature = "nft")]
+    /// Emitted when an account withdraws an NFT from the votes contract
+    ///
+    /// - topics - `["withdraw", account: Address]`
+    /// - data - `[token_id: u32]`
+    pub fn withdraw_nft(e: &Env, account: Address, token_id: u32) {
+        let topics = (Symbol::new(e, "withdraw"), account);
+        e.events().publish(topics, token_id);
+    }
+
+    #[cfg(any(feature = "bonding", feature = "vote-escrow"))]
     /// Emitted when an account deposits tokens into the votes contract
     ///
     /// - topics - `["deposit", account: Address]`
@@ -39,7 +106,7 @@ impl TokenVotesEvents {
         e.events().publish(topics, amount);
     }
 
-    #[cfg(feature = "bonding")]
+    #[cfg(any(feature = "bonding", feature = "vote-escrow"))]
     /// Emitted when an account withdraws tokens from the votes contract
     ///
     /// - topics - `["withdraw", account: Address]`
@@ -50,12 +117,118 @@ impl TokenVotesEvents {
     }
 
     #[cfg(feature = "bonding")]
-    /// Emitted when an account claims emissions
+    /// Emitted when an account starts unbonding tokens from the votes contract
+    ///
+    /// - topics - `["unbond", account: Address]`
+    /// - data - `[amount: i128, unlock_ledger: u32]`
+    pub fn unbond(e: &Env, account: Address, amount: i128, unlock_ledger: u32) {
+        let topics = (Symbol::new(e, "unbond"), account);
+        e.events().publish(topics, (amount, unlock_ledger));
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account deposits an additional asset into the votes contract
     ///
-    /// - topics - `["claim", account: Address]`
+    /// - topics - `["deposit_asset", account: Address, token: Address]`
     /// - data - `[amount: i128]`
-    pub fn claim(e: &Env, account: Address, amount: i128) {
-        let topics = (Symbol::new(e, "claim"), account);
+    pub fn deposit_asset(e: &Env, account: Address, token: Address, amount: i128) {
+        let topics = (Symbol::new(e, "deposit_asset"), account, token);
+        e.events().publish(topics, amount);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account withdraws an additional asset from the votes contract
+    ///
+    /// - topics - `["withdraw_asset", account: Address, token: Address]`
+    /// - data - `[amount: i128]`
+    pub fn withdraw_asset(e: &Env, account: Address, token: Address, amount: i128) {
+        let topics = (Symbol::new(e, "withdraw_asset"), account, token);
+        e.events().publish(topics, amount);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account's voting tokens for an additional asset are synced to the asset's weight
+    ///
+    /// - topics - `["sync_asset", account: Address, token: Address]`
+    /// - data - `[units: i128]`
+    pub fn sync_asset(e: &Env, account: Address, token: Address, units: i128) {
+        let topics = (Symbol::new(e, "sync_asset"), account, token);
+        e.events().publish(topics, units);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account starts unbonding an additional asset from the votes contract
+    ///
+    /// - topics - `["unbond_asset", account: Address, token: Address]`
+    /// - data - `[amount: i128, unlock_ledger: u32]`
+    pub fn unbond_asset(
+        e: &Env,
+        account: Address,
+        token: Address,
+        amount: i128,
+        unlock_ledger: u32,
+    ) {
+        let topics = (Symbol::new(e, "unbond_asset"), account, token);
+        e.events().publish(topics, (amount, unlock_ledger));
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when the additional bonding assets are set
+    ///
+    /// - topics - `["set_assets"]`
+    /// - data - `[assets: Vec<(Address, u32)>]`
+    pub fn set_assets(e: &Env, assets: Vec<(Address, u32)>) {
+        let topics = (Symbol::new(e, "set_assets"),);
+        e.events().publish(topics, assets);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when a new unbonding delay is set
+    ///
+    /// - topics - `["set_unbond_delay"]`
+    /// - data - `[delay: u32]`
+    pub fn set_unbond_delay(e: &Env, delay: u32) {
+        let topics = (Symbol::new(e, "set_unbond_delay"),);
+        e.events().publish(topics, delay);
+    }
+
+    #[cfg(feature = "vote-escrow")]
+    /// Emitted when an account's lock is updated
+    ///
+    /// - topics - `["lock", account: Address]`
+    /// - data - `[amount: i128, end: u32]`
+    pub fn lock(e: &Env, account: Address, amount: i128, end: u32) {
+        let topics = (Symbol::new(e, "lock"), account);
+        e.events().publish(topics, (amount, end));
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account's emissions are claimed
+    ///
+    /// - topics - `["claim", account: Address, recipient: Address]`
+    /// - data - `[amount: i128]`
+    pub fn claim(e: &Env, account: Address, recipient: Address, amount: i128) {
+        let topics = (Symbol::new(e, "claim"), account, recipient);
+        e.events().publish(topics, amount);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account approves or revokes an operator to claim emissions on its behalf
+    ///
+    /// - topics - `["approve_operator", account: Address, operator: Address]`
+    /// - data - `[approved: bool]`
+    pub fn approve_operator(e: &Env, account: Address, operator: Address, approved: bool) {
+        let topics = (Symbol::new(e, "approve_operator"), account, operator);
+        e.events().publish(topics, approved);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when an account claims emissions from an emission program
+    ///
+    /// - topics - `["claim_program", account: Address, token: Address]`
+    /// - data - `[amount: i128]`
+    pub fn claim_program(e: &Env, account: Address, token: Address, amount: i128) {
+        let topics = (Symbol::new(e, "claim_program"), account, token);
         e.events().publish(topics, amount);
     }
 
@@ -68,4 +241,29 @@ impl TokenVotesEvents {
         let topics = (Symbol::new(e, "set_emissions"), eps, expiration);
         e.events().publish(topics, ());
     }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when the emission payout is set
+    ///
+    /// - topics - `["set_emission_payout"]`
+    /// - data - `[payout: EmissionPayout]`
+    pub fn set_emission_payout(e: &Env, payout: EmissionPayout) {
+        let topics = (Symbol::new(e, "set_emission_payout"),);
+        e.events().publish(topics, payout);
+    }
+
+    #[cfg(feature = "bonding")]
+    /// Emitted when a new emission configuration is set for an emission program
+    ///
+    /// - topics - `["set_program_emissions", token: Address, eps: u64, expiration: u64]`
+    /// - data - `[]`
+    pub fn set_program_emissions(e: &Env, token: Address, eps: u64, expiration: u64) {
+        let topics = (
+            Symbol::new(e, "set_program_emissions"),
+            token,
+            eps,
+            expiration,
+        );
+        e.events().publish(topics, ());
+    }
 }
@@ -3,22 +3,56 @@
 #[cfg(any(test, feature = "testutils"))]
 extern crate std;
 
+#[cfg(all(feature = "balance-checkpoints", feature = "vote-escrow"))]
+compile_error!("the `balance-checkpoints` feature is not supported by `vote-escrow`");
+#[cfg(all(
+    feature = "soulbound",
+    any(feature = "bonding", feature = "vote-escrow")
+))]
+compile_error!("the `soulbound` feature is not supported by `bonding` or `vote-escrow`");
+#[cfg(all(
+    feature = "nft",
+    any(
+        feature = "bonding",
+        feature = "vote-escrow",
+        feature = "sep-0041",
+        feature = "soulbound"
+    )
+))]
+compile_error!(
+    "the `nft` feature is not supported by `bonding`, `vote-escrow`, `sep-0041` or `soulbound`"
+);
+
 #[cfg(feature = "sep-0041")]
 mod allowance;
 
+#[cfg(not(feature = "vote-escrow"))]
 mod balance;
+#[cfg(feature = "bonding")]
+mod bonding;
 mod checkpoints;
 mod constants;
 mod contract;
+mod delegation;
 mod error;
 
 #[cfg(feature = "bonding")]
 mod emissions;
 
+#[cfg(feature = "vote-escrow")]
+mod escrow;
+
 mod events;
+#[cfg(feature = "nft")]
+mod nft;
+#[cfg(feature = "soulbound")]
+mod soulbound;
 mod storage;
+#[cfg(any(feature = "extended-delegation", not(feature = "nft")))]
 mod validation;
+mod vote_lock;
 mod votes;
+#[cfg(not(feature = "vote-escrow"))]
 mod voting_units;
 
 pub use contract::*;
@@ -0,0 +1,47 @@
+#![cfg(feature = "nft")]
+
+use soroban_sdk::{contractclient, panic_with_error, Address, Env};
+
+use crate::{balance, error::TokenVotesError, storage};
+
+/// The subset of an NFT collection's interface used by the votes contract
+#[allow(dead_code)]
+#[contractclient(name = "NftClient")]
+pub trait NonFungibleToken {
+    /// Transfer the NFT with `token_id` from `from` to `to`
+    fn transfer(e: Env, from: Address, to: Address, token_id: u32);
+}
+
+/// Transfer an NFT from an account into the votes contract and give the account
+/// one voting unit for it
+///
+/// ### Arguments
+/// * `from` - The address of the account depositing the NFT
+/// * `token_id` - The id of the NFT
+pub fn deposit(e: &Env, from: &Address, token_id: u32) {
+    let collection = NftClient::new(e, &storage::get_token(e));
+    collection.transfer(from, &e.current_contract_address(), &token_id);
+
+    storage::set_nft_owner(e, token_id, from);
+    balance::mint_balance(e, from, 1);
+}
+
+/// Remove the voting unit of a deposited NFT and transfer the NFT back to the account
+/// that deposited it
+///
+/// ### Arguments
+/// * `from` - The address of the account withdrawing the NFT
+/// * `token_id` - The id of the NFT
+///
+/// ### Panics
+/// If the NFT was not deposited by the account, or the account's balance is locked
+pub fn withdraw(e: &Env, from: &Address, token_id: u32) {
+    if storage::get_nft_owner(e, token_id).as_ref() != Some(from) {
+        panic_with_error!(e, TokenVotesError::UnauthorizedError);
+    }
+    balance::burn_unlocked_balance(e, from, 1);
+    storage::del_nft_owner(e, token_id);
+
+    let collection = NftClient::new(e, &storage::get_token(e));
+    collection.transfer(&e.current_contract_address(), from, &token_id);
+}
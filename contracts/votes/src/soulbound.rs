@@ -0,0 +1,38 @@
+#![cfg(feature = "soulbound")]
+
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::{balance, error::TokenVotesError, storage};
+
+/// Reset the expiration of an account's reputation after tokens are minted to it. Does
+/// nothing if no expiry period is set.
+///
+/// ### Arguments
+/// * `account` - The address of the account
+pub fn extend_expiration(e: &Env, account: &Address) {
+    let period = storage::get_expiry_period(e);
+    if period > 0 {
+        storage::set_expiration(e, account, e.ledger().sequence() + period);
+    }
+}
+
+/// Burn the balance of an account whose reputation has expired. The balance cannot be burned
+/// while it is locked by a vote.
+///
+/// Returns the amount of tokens burned
+///
+/// ### Arguments
+/// * `account` - The address of the account
+///
+/// ### Panics
+/// If the account's reputation does not expire or has not expired yet, or the balance is locked
+pub fn expire(e: &Env, account: &Address) -> i128 {
+    let expiration = storage::get_expiration(e, account);
+    if expiration == 0 || expiration > e.ledger().sequence() {
+        panic_with_error!(e, TokenVotesError::ReputationNotExpiredError);
+    }
+    let balance = storage::get_balance(e, account);
+    balance::burn_unlocked_balance(e, account, balance);
+    storage::del_expiration(e, account);
+    balance
+}
@@ -17,19 +17,41 @@ pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - 10 * DA
 const IS_INIT_KEY: Symbol = symbol_short!("IsInit");
 const GOV_KEY: Symbol = symbol_short!("GOV");
 const METADATA_KEY: Symbol = symbol_short!("METADATA");
+#[cfg(not(feature = "vote-escrow"))]
 const TOTAL_SUPPLY_KEY: Symbol = symbol_short!("SUPPLY");
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+const ABSTAIN_SUPPLY_KEY: Symbol = symbol_short!("ABSTAIN");
+#[cfg(not(feature = "vote-escrow"))]
 const TOTAL_SUPPLY_CHECK_KEY: Symbol = symbol_short!("SPLYCHECK");
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+const ABSTAIN_SUPPLY_CHECK_KEY: Symbol = symbol_short!("ABSTCHECK");
 const VOTE_LEDGERS_KEY: Symbol = symbol_short!("VOTE_SEQ");
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 const ADMIN_KEY: Symbol = symbol_short!("ADMIN");
 
-#[cfg(feature = "bonding")]
+#[cfg(any(feature = "bonding", feature = "vote-escrow", feature = "nft"))]
 const TOKEN_KEY: Symbol = symbol_short!("TOKEN");
 #[cfg(feature = "bonding")]
 const EMIS_CONFIG: Symbol = symbol_short!("EMIS_CFG");
 #[cfg(feature = "bonding")]
 const EMIS_DATA: Symbol = symbol_short!("EMIS_DATA");
+#[cfg(feature = "bonding")]
+const EMIS_PAYOUT_KEY: Symbol = symbol_short!("EMIS_PAY");
+#[cfg(feature = "bonding")]
+const EMIS_RESERVE_KEY: Symbol = symbol_short!("EMIS_RSRV");
+#[cfg(feature = "bonding")]
+const EMIS_PROGRAMS_KEY: Symbol = symbol_short!("EMIS_PRGS");
+#[cfg(feature = "bonding")]
+const UNBOND_DELAY_KEY: Symbol = symbol_short!("UNBOND");
+#[cfg(feature = "bonding")]
+const ASSETS_KEY: Symbol = symbol_short!("ASSETS");
+#[cfg(feature = "vote-escrow")]
+const TOTAL_POINT_KEY: Symbol = symbol_short!("TOTPOINT");
+#[cfg(feature = "vote-escrow")]
+const TOTAL_POINT_CHECK_KEY: Symbol = symbol_short!("TOTPTCHK");
+#[cfg(feature = "soulbound")]
+const EXPIRY_PERIOD_KEY: Symbol = symbol_short!("EXP_PRD");
 
 #[derive(Clone)]
 #[contracttype]
@@ -52,6 +74,19 @@ pub enum DataKey {
     Votes(Address),
     VotesCheck(Address),
     Delegate(Address),
+    VoteLock(Address),
+}
+
+#[cfg(feature = "extended-delegation")]
+#[derive(Clone)]
+#[contracttype]
+pub enum DelegationDataKey {
+    DelegateCheck(Address),
+    DelegateSplit(Address),
+    DelegatorCount(Address),
+    Delegator(Address, u32),
+    DelegatorIndex(Address, Address),
+    Nonce(Address),
 }
 
 #[cfg(feature = "bonding")]
@@ -59,6 +94,59 @@ pub enum DataKey {
 #[contracttype]
 pub struct EmisKey(Address);
 
+#[cfg(feature = "bonding")]
+#[derive(Clone)]
+#[contracttype]
+pub enum EmisDataKey {
+    Config(Address),
+    Data(Address),
+    User(Address, Address),
+    Operator(Address, Address),
+    Unsettled(Address),
+}
+
+#[cfg(feature = "bonding")]
+#[derive(Clone)]
+#[contracttype]
+pub enum BondingDataKey {
+    Asset(Address, Address),
+    AssetUnits(Address),
+    Unbond(Address, Address),
+}
+
+#[cfg(feature = "balance-checkpoints")]
+#[derive(Clone)]
+#[contracttype]
+pub enum BalanceDataKey {
+    Ledger(Address),
+    Check(Address),
+}
+
+#[cfg(feature = "nft")]
+#[derive(Clone)]
+#[contracttype]
+pub enum NftDataKey {
+    Owner(u32),
+}
+
+#[cfg(feature = "soulbound")]
+#[derive(Clone)]
+#[contracttype]
+pub enum SoulboundDataKey {
+    Expiration(Address),
+}
+
+#[cfg(feature = "vote-escrow")]
+#[derive(Clone)]
+#[contracttype]
+pub enum EscrowDataKey {
+    Lock(Address),
+    Point(Address),
+    PointCheck(Address),
+    SlopeChg(Address, u32),
+    TotSlopeChg(u32),
+}
+
 //********** Storage Types **********//
 
 #[derive(Clone)]
@@ -69,6 +157,76 @@ pub struct TokenMetadata {
     pub symbol: String,
 }
 
+// The balance of an account locked by the governor until a ledger
+#[derive(Clone)]
+#[contracttype]
+pub struct VoteLock {
+    pub amount: i128,
+    pub until: u32,
+}
+
+#[cfg(feature = "bonding")]
+// The amount of an additional asset bonded by an account, and the voting units it was credited
+#[derive(Clone)]
+#[contracttype]
+pub struct AssetBalance {
+    pub amount: i128,
+    pub units: i128,
+}
+
+#[cfg(feature = "bonding")]
+// The underlying tokens an account is unbonding
+#[derive(Clone)]
+#[contracttype]
+pub struct Unbond {
+    pub amount: i128,
+    pub unlock_ledger: u32,
+}
+
+#[cfg(feature = "vote-escrow")]
+// The underlying tokens locked by an account
+#[derive(Clone)]
+#[contracttype]
+pub struct Lock {
+    pub amount: i128,
+    pub end: u32,
+}
+
+#[cfg(feature = "vote-escrow")]
+// The voting power of a delegatee or of the total supply at a ledger. The voting power scaled
+// by `MAX_LOCK_LEDGERS` is `bias`, which decreases by `slope` each ledger.
+#[derive(Clone)]
+#[contracttype]
+pub struct VotePoint {
+    pub bias: i128,
+    pub slope: i128,
+    pub ledger: u32,
+}
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+// How an account's voting units are delegated
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum DelegationMode {
+    // The account's voting units are delegated to itself
+    SelfDelegated,
+    // The account's voting units are delegated to one or more other addresses
+    Delegated,
+    // The account's voting units are not delegated and do not count towards the active supply
+    Abstain,
+}
+
+#[cfg(feature = "bonding")]
+// How claimed emissions are paid out
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum EmissionPayout {
+    // Claimed emissions are added to the claimer's vote token balance
+    Compound,
+    // Claimed emissions are transferred to the claimer as the token
+    Transfer(Address),
+}
+
 #[cfg(feature = "bonding")]
 // The emission configuration
 #[derive(Clone)]
@@ -174,32 +332,89 @@ pub fn set_metadata(e: &Env, metadata: &TokenMetadata) {
 
 // --- Admin
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 pub fn get_admin(e: &Env) -> Address {
     e.storage().instance().get(&ADMIN_KEY).unwrap_optimized()
 }
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 pub fn set_admin(e: &Env, address: &Address) {
     e.storage().instance().set(&ADMIN_KEY, address);
 }
 
 // --- Wrapped Token
 
-#[cfg(feature = "bonding")]
+#[cfg(any(feature = "bonding", feature = "vote-escrow", feature = "nft"))]
 pub fn get_token(e: &Env) -> Address {
     e.storage().instance().get(&TOKEN_KEY).unwrap_optimized()
 }
 
-#[cfg(feature = "bonding")]
+#[cfg(any(feature = "bonding", feature = "vote-escrow", feature = "nft"))]
 pub fn set_token(e: &Env, address: &Address) {
     e.storage().instance().set(&TOKEN_KEY, address);
 }
 
+#[cfg(feature = "bonding")]
+pub fn get_unbond_delay(e: &Env) -> u32 {
+    e.storage().instance().get(&UNBOND_DELAY_KEY).unwrap_or(0)
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_unbond_delay(e: &Env, delay: u32) {
+    e.storage().instance().set(&UNBOND_DELAY_KEY, &delay);
+}
+
+#[cfg(feature = "soulbound")]
+pub fn get_expiry_period(e: &Env) -> u32 {
+    e.storage().instance().get(&EXPIRY_PERIOD_KEY).unwrap_or(0)
+}
+
+#[cfg(feature = "soulbound")]
+pub fn set_expiry_period(e: &Env, period: u32) {
+    e.storage().instance().set(&EXPIRY_PERIOD_KEY, &period);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_assets(e: &Env) -> Vec<(Address, u32)> {
+    e.storage()
+        .instance()
+        .get(&ASSETS_KEY)
+        .unwrap_or(Vec::new(e))
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_assets(e: &Env, assets: &Vec<(Address, u32)>) {
+    e.storage().instance().set(&ASSETS_KEY, assets);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_emission_payout(e: &Env) -> EmissionPayout {
+    e.storage()
+        .instance()
+        .get(&EMIS_PAYOUT_KEY)
+        .unwrap_or(EmissionPayout::Compound)
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_emission_payout(e: &Env, payout: &EmissionPayout) {
+    e.storage().instance().set(&EMIS_PAYOUT_KEY, payout);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_emission_reserve(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&EMIS_RESERVE_KEY)
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_emission_reserve(e: &Env, amount: i128) {
+    e.storage().instance().set(&EMIS_RESERVE_KEY, &amount);
+}
+
 //********** Persistent **********//
 
 // Total Supply
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn get_total_supply(e: &Env) -> u128 {
     get_persistent_default(
         e,
@@ -210,6 +425,7 @@ pub fn get_total_supply(e: &Env) -> u128 {
     )
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn set_total_supply(e: &Env, checkpoint: &u128) {
     e.storage().persistent().set(&TOTAL_SUPPLY_KEY, checkpoint);
     e.storage().persistent().extend_ttl(
@@ -219,6 +435,31 @@ pub fn set_total_supply(e: &Env, checkpoint: &u128) {
     );
 }
 
+// Abstain Supply
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn get_abstain_supply(e: &Env) -> u128 {
+    get_persistent_default(
+        e,
+        &ABSTAIN_SUPPLY_KEY,
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn set_abstain_supply(e: &Env, checkpoint: &u128) {
+    e.storage()
+        .persistent()
+        .set(&ABSTAIN_SUPPLY_KEY, checkpoint);
+    e.storage().persistent().extend_ttl(
+        &ABSTAIN_SUPPLY_KEY,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    );
+}
+
 // Balance
 
 pub fn get_balance(e: &Env, address: &Address) -> i128 {
@@ -239,8 +480,32 @@ pub fn set_balance(e: &Env, address: &Address, balance: &i128) {
         .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
 }
 
+// Balance Ledger
+
+/// Get the ledger sequence the balance of an address was last changed at
+#[cfg(feature = "balance-checkpoints")]
+pub fn get_balance_ledger(e: &Env, address: &Address) -> u32 {
+    get_persistent_default(
+        e,
+        &BalanceDataKey::Ledger(address.clone()),
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "balance-checkpoints")]
+pub fn set_balance_ledger(e: &Env, address: &Address, sequence: u32) {
+    let key = BalanceDataKey::Ledger(address.clone());
+    e.storage().persistent().set(&key, &sequence);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
 // Vote Units
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn get_voting_units(e: &Env, address: &Address) -> u128 {
     get_persistent_default(
         e,
@@ -251,6 +516,7 @@ pub fn get_voting_units(e: &Env, address: &Address) -> u128 {
     )
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn set_voting_units(e: &Env, address: &Address, checkpoint: &u128) {
     let key = DataKey::Votes(address.clone());
     e.storage().persistent().set(&key, checkpoint);
@@ -279,6 +545,323 @@ pub fn set_delegate(e: &Env, address: &Address, delegatee: &Address) {
         .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
 }
 
+// Delegate Split
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegate_split(e: &Env, address: &Address) -> Option<Vec<(Address, u32)>> {
+    get_persistent_default(
+        e,
+        &DelegationDataKey::DelegateSplit(address.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_delegate_split(e: &Env, address: &Address, split: &Vec<(Address, u32)>) {
+    let key = DelegationDataKey::DelegateSplit(address.clone());
+    e.storage().persistent().set(&key, split);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn del_delegate_split(e: &Env, address: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DelegationDataKey::DelegateSplit(address.clone()));
+}
+
+// Delegators
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegator_count(e: &Env, delegatee: &Address) -> u32 {
+    get_persistent_default(
+        e,
+        &DelegationDataKey::DelegatorCount(delegatee.clone()),
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_delegator_count(e: &Env, delegatee: &Address, count: u32) {
+    let key = DelegationDataKey::DelegatorCount(delegatee.clone());
+    e.storage().persistent().set(&key, &count);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegator(e: &Env, delegatee: &Address, index: u32) -> Option<Address> {
+    get_persistent_default(
+        e,
+        &DelegationDataKey::Delegator(delegatee.clone(), index),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_delegator(e: &Env, delegatee: &Address, index: u32, account: &Address) {
+    let key = DelegationDataKey::Delegator(delegatee.clone(), index);
+    e.storage().persistent().set(&key, account);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn del_delegator(e: &Env, delegatee: &Address, index: u32) {
+    e.storage()
+        .persistent()
+        .remove(&DelegationDataKey::Delegator(delegatee.clone(), index));
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegator_index(e: &Env, delegatee: &Address, account: &Address) -> Option<u32> {
+    get_persistent_default(
+        e,
+        &DelegationDataKey::DelegatorIndex(delegatee.clone(), account.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_delegator_index(e: &Env, delegatee: &Address, account: &Address, index: u32) {
+    let key = DelegationDataKey::DelegatorIndex(delegatee.clone(), account.clone());
+    e.storage().persistent().set(&key, &index);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn del_delegator_index(e: &Env, delegatee: &Address, account: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DelegationDataKey::DelegatorIndex(
+            delegatee.clone(),
+            account.clone(),
+        ));
+}
+
+// Nonce
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_nonce(e: &Env, address: &Address) -> u64 {
+    get_persistent_default(
+        e,
+        &DelegationDataKey::Nonce(address.clone()),
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_nonce(e: &Env, address: &Address, nonce: u64) {
+    let key = DelegationDataKey::Nonce(address.clone());
+    e.storage().persistent().set(&key, &nonce);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// NFT Owner
+
+#[cfg(feature = "nft")]
+pub fn get_nft_owner(e: &Env, token_id: u32) -> Option<Address> {
+    get_persistent_default(
+        e,
+        &NftDataKey::Owner(token_id),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "nft")]
+pub fn set_nft_owner(e: &Env, token_id: u32, owner: &Address) {
+    let key = NftDataKey::Owner(token_id);
+    e.storage().persistent().set(&key, owner);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "nft")]
+pub fn del_nft_owner(e: &Env, token_id: u32) {
+    e.storage()
+        .persistent()
+        .remove(&NftDataKey::Owner(token_id));
+}
+
+// Expiration
+
+#[cfg(feature = "soulbound")]
+pub fn get_expiration(e: &Env, address: &Address) -> u32 {
+    get_persistent_default(
+        e,
+        &SoulboundDataKey::Expiration(address.clone()),
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "soulbound")]
+pub fn set_expiration(e: &Env, address: &Address, expiration: u32) {
+    let key = SoulboundDataKey::Expiration(address.clone());
+    e.storage().persistent().set(&key, &expiration);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "soulbound")]
+pub fn del_expiration(e: &Env, address: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&SoulboundDataKey::Expiration(address.clone()));
+}
+
+// Vote Escrow
+
+// Vote Lock
+
+pub fn get_vote_lock(e: &Env, address: &Address) -> VoteLock {
+    get_persistent_default(
+        e,
+        &DataKey::VoteLock(address.clone()),
+        || VoteLock {
+            amount: 0,
+            until: 0,
+        },
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+pub fn set_vote_lock(e: &Env, address: &Address, vote_lock: &VoteLock) {
+    let key = DataKey::VoteLock(address.clone());
+    e.storage().persistent().set(&key, vote_lock);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "vote-escrow")]
+pub fn get_lock(e: &Env, address: &Address) -> Lock {
+    get_persistent_default(
+        e,
+        &EscrowDataKey::Lock(address.clone()),
+        || Lock { amount: 0, end: 0 },
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "vote-escrow")]
+pub fn set_lock(e: &Env, address: &Address, lock: &Lock) {
+    let key = EscrowDataKey::Lock(address.clone());
+    e.storage().persistent().set(&key, lock);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+/// Get the current vote point of a delegatee, or of the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn get_vote_point(e: &Env, address: Option<&Address>) -> VotePoint {
+    let default = || VotePoint {
+        bias: 0,
+        slope: 0,
+        ledger: 0,
+    };
+    match address {
+        Some(address) => get_persistent_default(
+            e,
+            &EscrowDataKey::Point(address.clone()),
+            default,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        ),
+        None => get_persistent_default(
+            e,
+            &TOTAL_POINT_KEY,
+            default,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        ),
+    }
+}
+
+/// Set the current vote point of a delegatee, or of the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn set_vote_point(e: &Env, address: Option<&Address>, point: &VotePoint) {
+    match address {
+        Some(address) => {
+            let key = EscrowDataKey::Point(address.clone());
+            e.storage().persistent().set(&key, point);
+            e.storage().persistent().extend_ttl(
+                &key,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+        }
+        None => {
+            e.storage().persistent().set(&TOTAL_POINT_KEY, point);
+            e.storage().persistent().extend_ttl(
+                &TOTAL_POINT_KEY,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+        }
+    }
+}
+
+/// Get the slope change that occurs at `ledger` for a delegatee, or for the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn get_slope_change(e: &Env, address: Option<&Address>, ledger: u32) -> i128 {
+    match address {
+        Some(address) => get_persistent_default(
+            e,
+            &EscrowDataKey::SlopeChg(address.clone(), ledger),
+            || 0,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        ),
+        None => get_persistent_default(
+            e,
+            &EscrowDataKey::TotSlopeChg(ledger),
+            || 0,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        ),
+    }
+}
+
+/// Set the slope change that occurs at `ledger` for a delegatee, or for the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn set_slope_change(e: &Env, address: Option<&Address>, ledger: u32, slope_change: i128) {
+    let key = match address {
+        Some(address) => EscrowDataKey::SlopeChg(address.clone(), ledger),
+        None => EscrowDataKey::TotSlopeChg(ledger),
+    };
+    e.storage().persistent().set(&key, &slope_change);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
 //********** Temporary **********//
 
 // Allowance
@@ -323,6 +906,25 @@ pub fn set_allowance(
     }
 }
 
+// Abstain Supply Checkpoints
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn get_abstain_supply_checkpoints(e: &Env) -> Vec<u128> {
+    get_temporary_default(e, &ABSTAIN_SUPPLY_CHECK_KEY, || Vec::new(e))
+}
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+pub fn set_abstain_supply_checkpoints(e: &Env, balance: &Vec<u128>) {
+    e.storage()
+        .temporary()
+        .set(&ABSTAIN_SUPPLY_CHECK_KEY, balance);
+    e.storage().temporary().extend_ttl(
+        &ABSTAIN_SUPPLY_CHECK_KEY,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+    );
+}
+
 // Vote Units Checkpoints
 
 pub fn get_vote_ledgers(e: &Env) -> Vec<u32> {
@@ -342,10 +944,12 @@ pub fn set_vote_ledgers(e: &Env, vote_ledgers: &Vec<u32>) {
 
 // Total Supply Checkpoints
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn get_total_supply_checkpoints(e: &Env) -> Vec<u128> {
     get_temporary_default(e, &TOTAL_SUPPLY_CHECK_KEY, || Vec::new(&e))
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn set_total_supply_checkpoints(e: &Env, balance: &Vec<u128>) {
     e.storage()
         .temporary()
@@ -362,10 +966,12 @@ pub fn set_total_supply_checkpoints(e: &Env, balance: &Vec<u128>) {
 
 // Vote Units Checkpoints
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn get_voting_units_checkpoints(e: &Env, address: &Address) -> Vec<u128> {
     get_temporary_default(e, &DataKey::VotesCheck(address.clone()), || Vec::new(&e))
 }
 
+#[cfg(not(feature = "vote-escrow"))]
 pub fn set_voting_units_checkpoints(e: &Env, address: &Address, balance: &Vec<u128>) {
     let key = DataKey::VotesCheck(address.clone());
     e.storage().temporary().set(&key, balance);
@@ -379,6 +985,89 @@ pub fn set_voting_units_checkpoints(e: &Env, address: &Address, balance: &Vec<u1
     );
 }
 
+// Balance Checkpoints
+
+#[cfg(feature = "balance-checkpoints")]
+pub fn get_balance_checkpoints(e: &Env, address: &Address) -> Vec<u128> {
+    get_temporary_default(e, &BalanceDataKey::Check(address.clone()), || Vec::new(e))
+}
+
+#[cfg(feature = "balance-checkpoints")]
+pub fn set_balance_checkpoints(e: &Env, address: &Address, balance: &Vec<u128>) {
+    let key = BalanceDataKey::Check(address.clone());
+    e.storage().temporary().set(&key, balance);
+    // Checkpoints only need to exist for at least 7 days to ensure that correct
+    // vote periods can be tracked for the entire max voting period of 7 days.
+    e.storage().temporary().extend_ttl(
+        &key,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+    );
+}
+
+// Delegate Checkpoints
+
+#[cfg(feature = "extended-delegation")]
+pub fn get_delegate_checkpoints(e: &Env, address: &Address) -> Vec<(u32, Address)> {
+    get_temporary_default(
+        e,
+        &DelegationDataKey::DelegateCheck(address.clone()),
+        || Vec::new(e),
+    )
+}
+
+#[cfg(feature = "extended-delegation")]
+pub fn set_delegate_checkpoints(e: &Env, address: &Address, checkpoints: &Vec<(u32, Address)>) {
+    let key = DelegationDataKey::DelegateCheck(address.clone());
+    e.storage().temporary().set(&key, checkpoints);
+    // Checkpoints only need to exist for at least 7 days to ensure that correct
+    // vote periods can be tracked for the entire max voting period of 7 days.
+    e.storage().temporary().extend_ttl(
+        &key,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+        MAX_CHECKPOINT_AGE_LEDGERS,
+    );
+}
+
+// Vote Point Checkpoints
+
+/// Get the previous vote points of a delegatee, or of the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn get_vote_point_checkpoints(e: &Env, address: Option<&Address>) -> Vec<VotePoint> {
+    match address {
+        Some(address) => {
+            get_temporary_default(e, &EscrowDataKey::PointCheck(address.clone()), || {
+                Vec::new(&e)
+            })
+        }
+        None => get_temporary_default(e, &TOTAL_POINT_CHECK_KEY, || Vec::new(&e)),
+    }
+}
+
+/// Set the previous vote points of a delegatee, or of the total supply if `address` is None
+#[cfg(feature = "vote-escrow")]
+pub fn set_vote_point_checkpoints(e: &Env, address: Option<&Address>, points: &Vec<VotePoint>) {
+    match address {
+        Some(address) => {
+            let key = EscrowDataKey::PointCheck(address.clone());
+            e.storage().temporary().set(&key, points);
+            e.storage().temporary().extend_ttl(
+                &key,
+                MAX_CHECKPOINT_AGE_LEDGERS,
+                MAX_CHECKPOINT_AGE_LEDGERS,
+            );
+        }
+        None => {
+            e.storage().temporary().set(&TOTAL_POINT_CHECK_KEY, points);
+            e.storage().temporary().extend_ttl(
+                &TOTAL_POINT_CHECK_KEY,
+                MAX_CHECKPOINT_AGE_LEDGERS,
+                MAX_CHECKPOINT_AGE_LEDGERS,
+            );
+        }
+    }
+}
+
 // ********** Emissions **********
 
 // Emission config
@@ -448,3 +1137,217 @@ pub fn set_user_emission_data(e: &Env, user: &Address, data: &UserEmissionData)
         .persistent()
         .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
 }
+
+// Emission programs
+
+#[cfg(feature = "bonding")]
+pub fn get_emission_programs(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Vec<Address>>(&EMIS_PROGRAMS_KEY)
+        .unwrap_or(Vec::new(e))
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_emission_programs(e: &Env, programs: &Vec<Address>) {
+    e.storage().instance().set(&EMIS_PROGRAMS_KEY, programs);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_program_emission_config(e: &Env, token: &Address) -> Option<EmissionConfig> {
+    get_persistent_default(
+        e,
+        &EmisDataKey::Config(token.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_program_emission_config(e: &Env, token: &Address, config: &EmissionConfig) {
+    let key = EmisDataKey::Config(token.clone());
+    e.storage().persistent().set(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_program_emission_data(e: &Env, token: &Address) -> Option<EmissionData> {
+    get_persistent_default(
+        e,
+        &EmisDataKey::Data(token.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_program_emission_data(e: &Env, token: &Address, data: &EmissionData) {
+    let key = EmisDataKey::Data(token.clone());
+    e.storage().persistent().set(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_user_program_emission_data(
+    e: &Env,
+    user: &Address,
+    token: &Address,
+) -> Option<UserEmissionData> {
+    get_persistent_default(
+        e,
+        &EmisDataKey::User(user.clone(), token.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_user_program_emission_data(
+    e: &Env,
+    user: &Address,
+    token: &Address,
+    data: &UserEmissionData,
+) {
+    let key = EmisDataKey::User(user.clone(), token.clone());
+    e.storage().persistent().set(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_program_unsettled_supply(e: &Env, token: &Address) -> Option<i128> {
+    get_persistent_default(
+        e,
+        &EmisDataKey::Unsettled(token.clone()),
+        || None,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_program_unsettled_supply(e: &Env, token: &Address, supply: i128) {
+    let key = EmisDataKey::Unsettled(token.clone());
+    e.storage().persistent().set(&key, &supply);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn del_program_unsettled_supply(e: &Env, token: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&EmisDataKey::Unsettled(token.clone()));
+}
+
+// Emission operators
+
+#[cfg(feature = "bonding")]
+pub fn get_emission_operator(e: &Env, user: &Address, operator: &Address) -> bool {
+    get_persistent_default(
+        e,
+        &EmisDataKey::Operator(user.clone(), operator.clone()),
+        || false,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_emission_operator(e: &Env, user: &Address, operator: &Address, approved: bool) {
+    let key = EmisDataKey::Operator(user.clone(), operator.clone());
+    if approved {
+        e.storage().persistent().set(&key, &approved);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+// ********** Bonded Assets **********
+
+#[cfg(feature = "bonding")]
+pub fn get_asset_balance(e: &Env, user: &Address, token: &Address) -> AssetBalance {
+    get_persistent_default(
+        e,
+        &BondingDataKey::Asset(user.clone(), token.clone()),
+        || AssetBalance {
+            amount: 0,
+            units: 0,
+        },
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_asset_balance(e: &Env, user: &Address, token: &Address, balance: &AssetBalance) {
+    let key = BondingDataKey::Asset(user.clone(), token.clone());
+    e.storage().persistent().set(&key, balance);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn get_asset_units(e: &Env, user: &Address) -> i128 {
+    get_persistent_default(
+        e,
+        &BondingDataKey::AssetUnits(user.clone()),
+        || 0,
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_asset_units(e: &Env, user: &Address, units: i128) {
+    let key = BondingDataKey::AssetUnits(user.clone());
+    e.storage().persistent().set(&key, &units);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// ********** Unbonding **********
+
+#[cfg(feature = "bonding")]
+pub fn get_unbond(e: &Env, user: &Address, token: &Address) -> Unbond {
+    get_persistent_default(
+        e,
+        &BondingDataKey::Unbond(user.clone(), token.clone()),
+        || Unbond {
+            amount: 0,
+            unlock_ledger: 0,
+        },
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    )
+}
+
+#[cfg(feature = "bonding")]
+pub fn set_unbond(e: &Env, user: &Address, token: &Address, unbond: &Unbond) {
+    let key = BondingDataKey::Unbond(user.clone(), token.clone());
+    e.storage().persistent().set(&key, unbond);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[cfg(feature = "bonding")]
+pub fn del_unbond(e: &Env, user: &Address, token: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&BondingDataKey::Unbond(user.clone(), token.clone()));
+}
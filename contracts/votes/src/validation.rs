@@ -2,8 +2,48 @@ use soroban_sdk::{panic_with_error, Env};
 
 use crate::error::TokenVotesError;
 
+#[cfg(feature = "extended-delegation")]
+use crate::constants::{BPS_SCALAR, MAX_DELEGATE_SPLITS};
+#[cfg(feature = "extended-delegation")]
+use soroban_sdk::{Address, Vec};
+
+#[cfg(not(feature = "nft"))]
 pub fn require_nonnegative_amount(e: &Env, amount: i128) {
     if amount < 0 {
         panic_with_error!(e, TokenVotesError::NegativeAmountError);
     }
 }
+
+/// Require that the token can be transferred. Soulbound tokens can only be minted and clawed back.
+#[cfg(feature = "sep-0041")]
+pub fn require_transferable(e: &Env) {
+    if cfg!(feature = "soulbound") {
+        panic_with_error!(e, TokenVotesError::NonTransferableError);
+    }
+}
+
+/// Require that a delegate split is valid. A split must contain between 1 and `MAX_DELEGATE_SPLITS`
+/// unique delegatees, each with a positive share, where the shares sum to `BPS_SCALAR`.
+#[cfg(feature = "extended-delegation")]
+pub fn require_valid_delegate_split(e: &Env, split: &Vec<(Address, u32)>) {
+    if split.is_empty() || split.len() > MAX_DELEGATE_SPLITS {
+        panic_with_error!(e, TokenVotesError::InvalidDelegateSplitError);
+    }
+    let mut total_bps: u32 = 0;
+    for (index, (delegatee, bps)) in split.iter().enumerate() {
+        if bps == 0 || bps > BPS_SCALAR {
+            panic_with_error!(e, TokenVotesError::InvalidDelegateSplitError);
+        }
+        if split
+            .iter()
+            .skip(index + 1)
+            .any(|(other, _)| other == delegatee)
+        {
+            panic_with_error!(e, TokenVotesError::InvalidDelegateSplitError);
+        }
+        total_bps += bps;
+    }
+    if total_bps != BPS_SCALAR {
+        panic_with_error!(e, TokenVotesError::InvalidDelegateSplitError);
+    }
+}
@@ -0,0 +1,41 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::{
+    error::TokenVotesError,
+    storage::{self, VoteLock},
+};
+
+/// Lock the current balance of an account until `until`. If the account has an active lock,
+/// the larger balance and the later sequence are kept.
+///
+/// ### Arguments
+/// * `account` - The address of the account to lock
+/// * `until` - The sequence number the lock expires at
+pub fn lock_balance(e: &Env, account: &Address, until: u32) {
+    let balance = storage::get_balance(e, account);
+    let mut vote_lock = storage::get_vote_lock(e, account);
+    if vote_lock.until <= e.ledger().sequence() {
+        vote_lock = VoteLock {
+            amount: 0,
+            until: 0,
+        };
+    }
+    vote_lock.amount = vote_lock.amount.max(balance);
+    vote_lock.until = vote_lock.until.max(until);
+    storage::set_vote_lock(e, account, &vote_lock);
+}
+
+/// Require that an account's balance after a change does not fall below its locked balance
+///
+/// ### Arguments
+/// * `account` - The address of the account
+/// * `new_balance` - The balance of the account after the change
+///
+/// ### Panics
+/// If the new balance is less than the locked balance and the lock has not expired
+pub fn require_unlocked_balance(e: &Env, account: &Address, new_balance: i128) {
+    let vote_lock = storage::get_vote_lock(e, account);
+    if vote_lock.until > e.ledger().sequence() && new_balance < vote_lock.amount {
+        panic_with_error!(e, TokenVotesError::BalanceLockedError);
+    }
+}
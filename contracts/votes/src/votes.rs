@@ -1,9 +1,26 @@
 use soroban_sdk::{Address, Env, String};
 
+#[cfg(feature = "extended-delegation")]
+use soroban_sdk::BytesN;
+#[cfg(any(feature = "bonding", feature = "extended-delegation"))]
+use soroban_sdk::Vec;
+
+#[cfg(all(feature = "extended-delegation", not(feature = "vote-escrow")))]
+use crate::storage::DelegationMode;
+#[cfg(feature = "vote-escrow")]
+use crate::storage::Lock;
+#[cfg(feature = "bonding")]
+use crate::storage::{AssetBalance, EmissionConfig, EmissionData, EmissionPayout, Unbond};
+
 pub trait Votes {
     /// Get the total supply of voting tokens
     fn total_supply(e: Env) -> i128;
 
+    /// Get the total supply of voting tokens, excluding the balances of abstaining accounts.
+    /// Accounts can only abstain with the `extended-delegation` feature.
+    #[cfg(not(feature = "vote-escrow"))]
+    fn active_supply(e: Env) -> i128;
+
     /// Set a new sequence number of a future vote. This ensures vote history is maintained
     /// for old votes.
     ///
@@ -13,6 +30,18 @@ pub trait Votes {
     /// * `sequence` - The sequence number of the vote
     fn set_vote_sequence(e: Env, sequence: u32);
 
+    /// Lock the current balance of an account until a sequence number. The account cannot
+    /// transfer, withdraw or burn any of the locked balance until the lock expires. Locking an account
+    /// with an active lock keeps the larger of the two balances and sequence numbers. The lock does
+    /// not apply to balance removed by an admin, such as a clawback.
+    ///
+    /// Requires auth from the governor contract
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account to lock
+    /// * `until_sequence` - The sequence number the lock expires at
+    fn lock(e: Env, account: Address, until_sequence: u32);
+
     /// Get the total supply of voting tokens at a specific ledger sequence number.
     /// The ledger must be finalized before the sequence number can be used.
     ///
@@ -23,6 +52,18 @@ pub trait Votes {
     /// Panics if the sequence number is greater than or equal to the current ledger sequence.
     fn get_past_total_supply(e: Env, sequence: u32) -> i128;
 
+    /// Get the total supply of voting tokens at a specific ledger sequence number, excluding
+    /// the balances of abstaining accounts. The ledger must be finalized before the sequence
+    /// number can be used. For vote escrow, or without the `extended-delegation` feature, this is
+    /// the same as the past total supply.
+    ///
+    /// ### Arguments
+    /// * `sequence` - The sequence number to get the active supply at
+    ///
+    /// ### Panics
+    /// Panics if the sequence number is greater than or equal to the current ledger sequence.
+    fn get_past_active_supply(e: Env, sequence: u32) -> i128;
+
     /// Get the current voting power of an account
     ///
     /// ### Arguments
@@ -40,7 +81,8 @@ pub trait Votes {
     /// Panics if the sequence number is greater than or equal to the current ledger sequence.
     fn get_past_votes(e: Env, user: Address, sequence: u32) -> i128;
 
-    /// Get the deletage that account has chosen
+    /// Get the deletage that account has chosen. If the account splits its voting power,
+    /// returns the delegatee with the largest share.
     ///
     /// ### Arguments
     /// * `account` - The address of the account
@@ -53,6 +95,121 @@ pub trait Votes {
     fn delegate(e: Env, account: Address, delegatee: Address);
 }
 
+#[cfg(feature = "balance-checkpoints")]
+pub trait BalanceCheckpoints {
+    /// Get the balance of an account at a specific ledger sequence number.
+    /// The ledger must be finalized before the sequence number can be used.
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `sequence` - The sequence number to get the balance at
+    ///
+    /// ### Panics
+    /// Panics if the sequence number is greater than or equal to the current ledger sequence.
+    fn get_past_balance(e: Env, account: Address, sequence: u32) -> i128;
+}
+
+#[cfg(feature = "extended-delegation")]
+pub trait ExtendedDelegation {
+    /// Get the delegate of an account at a specific ledger sequence number. If the account split
+    /// its voting power, returns the delegatee with the largest share. The ledger must be
+    /// finalized before the sequence number can be used.
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `sequence` - The sequence number to get the delegate at
+    ///
+    /// ### Panics
+    /// Panics if the sequence number is greater than or equal to the current ledger sequence.
+    fn get_past_delegate(e: Env, account: Address, sequence: u32) -> Address;
+
+    /// Get the delegatees the account has chosen and the share (in basis points) of the
+    /// account's voting power delegated to each
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    fn get_delegate_split(e: Env, account: Address) -> Vec<(Address, u32)>;
+
+    /// Get a page of the accounts with voting units that delegate to a delegatee. Accounts
+    /// delegating to themselves are not included. Removing a delegator moves the last
+    /// delegator into its place, so the order is not stable between pages.
+    ///
+    /// ### Arguments
+    /// * `delegatee` - The address of the delegatee
+    /// * `start` - The index of the first delegator to return
+    /// * `limit` - The maximum number of delegators to return
+    fn get_delegators(e: Env, delegatee: Address, start: u32, limit: u32) -> Vec<Address>;
+
+    /// Get the number of accounts with voting units that delegate to a delegatee
+    ///
+    /// ### Arguments
+    /// * `delegatee` - The address of the delegatee
+    fn delegator_count(e: Env, delegatee: Address) -> u32;
+
+    /// Split the voting power of the account between multiple delegates. Each delegate receives
+    /// their share (in basis points) of the account's balance, rounded down, with any remainder
+    /// delegated to the last delegate.
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `split` - The addresses of the delegates and their shares in basis points. The shares must
+    ///   be positive and sum to 10000.
+    ///
+    /// ### Panics
+    /// If the split contains no delegates, more than 10 delegates, or duplicate delegates, or the shares
+    /// are not positive or do not sum to 10000.
+    fn delegate_split(e: Env, account: Address, split: Vec<(Address, u32)>);
+
+    /// Stop delegating the voting power of the account. The account's balance is removed from
+    /// its delegates and from the active supply until the account delegates again.
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    ///
+    /// ### Panics
+    /// If the account is already abstaining
+    #[cfg(not(feature = "vote-escrow"))]
+    fn abstain(e: Env, account: Address);
+
+    /// Get whether the account delegates to itself, delegates to other addresses, or abstains
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    #[cfg(not(feature = "vote-escrow"))]
+    fn get_delegation_mode(e: Env, account: Address) -> DelegationMode;
+
+    /// Delegate the voting power of a Stellar account to a delegate using a signature from the
+    /// account's ed25519 key, so the account does not need to submit the transaction.
+    ///
+    /// The signed message is the XDR encoding of the tuple
+    /// `("delegate_by_sig", network_id, votes_contract, delegatee, nonce, expiry)`
+    ///
+    /// ### Arguments
+    /// * `public_key` - The ed25519 public key of the account
+    /// * `delegatee` - The address of the delegate
+    /// * `nonce` - The current nonce of the account
+    /// * `expiry` - The last ledger sequence the signature is valid for
+    /// * `signature` - The ed25519 signature of the message
+    ///
+    /// ### Panics
+    /// If the signature is expired, the nonce is not the account's current nonce, or the signature
+    /// is invalid
+    fn delegate_by_sig(
+        e: Env,
+        public_key: BytesN<32>,
+        delegatee: Address,
+        nonce: u64,
+        expiry: u32,
+        signature: BytesN<64>,
+    );
+
+    /// Get the current nonce of an account for delegations authorized ahead of time
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    fn get_nonce(e: Env, account: Address) -> u64;
+}
+
 #[cfg(feature = "bonding")]
 pub trait Bonding {
     /// Setup the bonding votes contract
@@ -74,12 +231,112 @@ pub trait Bonding {
 
     /// Burn voting tokens and withdraw the corresponding amount of underlying tokens
     ///
+    /// If an unbonding delay is set, the underlying tokens are instead added to the account's
+    /// unbond and can be withdrawn with `complete_withdraw` once the delay has passed. Adding to
+    /// an existing unbond restarts its delay.
+    ///
     /// ### Arguments
     /// * `from` - The address of the account to withdraw for
     /// * `amount` - The amount of underlying tokens to withdraw
     fn withdraw(e: Env, from: Address, amount: i128);
 
-    /// Claim emissions for a user into their vote token balance
+    /// Withdraw the underlying tokens of an account's unbond once the unbonding delay has passed
+    ///
+    /// Returns the amount of underlying tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to withdraw for
+    ///
+    /// ### Panics
+    /// If the unbonding delay has not passed
+    fn complete_withdraw(e: Env, from: Address) -> i128;
+
+    /// Get the underlying tokens an account is unbonding
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    fn get_unbond(e: Env, account: Address) -> Unbond;
+
+    /// Get the number of ledgers withdrawn tokens must unbond for
+    fn get_unbond_delay(e: Env) -> u32;
+
+    /// Deposit an additional asset into the votes contract and mint voting tokens based on the
+    /// asset's weight
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to deposit for
+    /// * `token` - The address of the asset
+    /// * `amount` - The amount of the asset to deposit
+    ///
+    /// ### Panics
+    /// If the asset is not an accepted bonding asset
+    fn deposit_asset(e: Env, from: Address, token: Address, amount: i128);
+
+    /// Withdraw an additional asset from the votes contract and burn the voting tokens it was
+    /// credited. Follows the same unbonding rules as `withdraw`.
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to withdraw for
+    /// * `token` - The address of the asset
+    /// * `amount` - The amount of the asset to withdraw
+    fn withdraw_asset(e: Env, from: Address, token: Address, amount: i128);
+
+    /// Withdraw an account's unbond of an additional asset once the unbonding delay has passed
+    ///
+    /// Returns the amount of the asset withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to withdraw for
+    /// * `token` - The address of the asset
+    ///
+    /// ### Panics
+    /// If the unbonding delay has not passed
+    fn complete_withdraw_asset(e: Env, from: Address, token: Address) -> i128;
+
+    /// Update the voting tokens an account was credited for an additional asset to match the
+    /// asset's current weight. Can be called by anyone.
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `token` - The address of the asset
+    fn sync_asset(e: Env, account: Address, token: Address);
+
+    /// Get the amount of an additional asset bonded by an account and the voting tokens
+    /// it was credited
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `token` - The address of the asset
+    fn get_asset_balance(e: Env, account: Address, token: Address) -> AssetBalance;
+
+    /// Get the amount of an additional asset an account is unbonding
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    /// * `token` - The address of the asset
+    fn get_asset_unbond(e: Env, account: Address, token: Address) -> Unbond;
+
+    /// Get the additional assets that can be bonded and their weights in basis points
+    fn get_assets(e: Env) -> Vec<(Address, u32)>;
+
+    /// (Governor only) Set the additional assets that can be bonded and their weights in basis
+    /// points, where 10000 mints one voting token per asset token. An account's voting tokens
+    /// for an asset are updated to its new weight the next time it deposits or withdraws the asset,
+    /// or when `sync_asset` is called for the account.
+    ///
+    /// ### Arguments
+    /// * `assets` - The assets and their weights in basis points
+    fn set_assets(e: Env, assets: Vec<(Address, u32)>);
+
+    /// (Governor only) Set the number of ledgers withdrawn tokens must unbond for before they
+    /// can be withdrawn. A delay of 0 returns tokens immediately on `withdraw`.
+    ///
+    /// ### Arguments
+    /// * `delay` - The number of ledgers to unbond for
+    fn set_unbond_delay(e: Env, delay: u32);
+
+    /// Claim emissions for a user. The emissions are added to the user's vote token balance, or
+    /// transferred to the user, based on the emission payout.
     ///
     /// Returns the number of tokens claimed
     ///
@@ -87,16 +344,156 @@ pub trait Bonding {
     /// * `address` - The address to claim tokens for
     fn claim(e: Env, address: Address) -> i128;
 
+    /// Claim emissions for a user to a recipient. The emissions are added to the recipient's
+    /// vote token balance, or transferred to the recipient, based on the emission payout.
+    ///
+    /// Returns the number of tokens claimed
+    ///
+    /// ### Arguments
+    /// * `address` - The address to claim tokens for
+    /// * `recipient` - The address to receive the claimed tokens
+    fn claim_to(e: Env, address: Address, recipient: Address) -> i128;
+
+    /// Claim emissions for a user as an operator the user has approved. The emissions are
+    /// claimed to the user.
+    ///
+    /// Returns the number of tokens claimed
+    ///
+    /// ### Arguments
+    /// * `operator` - The address of the operator
+    /// * `address` - The address to claim tokens for
+    ///
+    /// ### Panics
+    /// If the operator is not approved by the user
+    fn claim_for(e: Env, operator: Address, address: Address) -> i128;
+
+    /// Approve or revoke an operator to claim emissions on behalf of a user with `claim_for`
+    ///
+    /// ### Arguments
+    /// * `address` - The address of the user
+    /// * `operator` - The address of the operator
+    /// * `approved` - Whether the operator is approved
+    fn approve_operator(e: Env, address: Address, operator: Address, approved: bool);
+
+    /// Get if an operator is approved to claim emissions on behalf of a user
+    ///
+    /// ### Arguments
+    /// * `address` - The address of the user
+    /// * `operator` - The address of the operator
+    fn is_operator(e: Env, address: Address, operator: Address) -> bool;
+
     /// (Governor only) Set the emissions configuration for the vote token. Emits the tokens
-    /// evenly over the duration of the emissions period.
+    /// evenly over the duration of the emissions period. The tokens are transferred from the
+    /// governor in the token emissions are paid out in.
     ///
     /// ### Arguments
     /// * `tokens` - The number of new tokens to emit
     /// * `expiration` - When to stop emitting tokens
     fn set_emis(e: Env, tokens: i128, expiration: u64);
+
+    /// Get the emission configuration, or None if emissions have not been set
+    fn emission_config(e: Env) -> Option<EmissionConfig>;
+
+    /// Get the emission data updated to the current timestamp, or None if emissions have not
+    /// been set
+    fn emission_data(e: Env) -> Option<EmissionData>;
+
+    /// Get the emissions a user could currently claim
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    fn pending_emissions(e: Env, user: Address) -> i128;
+
+    /// Get the number of tokens currently emitted per second for each vote token, with 7
+    /// decimals. Returns 0 if emissions have expired.
+    fn emissions_per_vote_unit_per_sec(e: Env) -> i128;
+
+    /// Get how claimed emissions are paid out
+    fn get_emis_payout(e: Env) -> EmissionPayout;
+
+    /// Get the number of tokens held by the contract for emissions that have not been claimed, or
+    /// None if emissions were set before the reserve was tracked
+    fn get_emis_reserve(e: Env) -> Option<i128>;
+
+    /// (Governor only) Set how claimed emissions are paid out. The token emissions are paid out
+    /// in cannot be changed once emissions have been set.
+    ///
+    /// ### Arguments
+    /// * `payout` - The emission payout
+    fn set_emis_payout(e: Env, payout: EmissionPayout);
+
+    /// Claim emissions for a user from an emission program. The reward tokens are transferred
+    /// to the user.
+    ///
+    /// Returns the number of reward tokens claimed
+    ///
+    /// ### Arguments
+    /// * `address` - The address to claim tokens for
+    /// * `token` - The reward token of the emission program
+    fn claim_program(e: Env, address: Address, token: Address) -> i128;
+
+    /// Get the reward tokens of the emission programs. Expired programs are removed once every
+    /// holder has accrued their emissions, but can still be claimed.
+    fn get_emis_programs(e: Env) -> Vec<Address>;
+
+    /// (Governor only) Set the emissions configuration for an emission program, creating the
+    /// program if it does not exist. Emits the reward tokens evenly over the duration of the
+    /// emissions period, alongside the vote token emissions and any other programs.
+    ///
+    /// ### Arguments
+    /// * `token` - The reward token of the emission program
+    /// * `tokens` - The number of new reward tokens to emit
+    /// * `expiration` - When to stop emitting reward tokens
+    fn set_emis_program(e: Env, token: Address, tokens: i128, expiration: u64);
 }
 
-#[cfg(not(feature = "bonding"))]
+#[cfg(feature = "vote-escrow")]
+pub trait VoteEscrow {
+    /// Setup the vote escrow contract
+    ///
+    /// ### Arguments
+    /// * `token` - The address of the underlying token contract
+    /// * `governor`- The address of the Governor contract the votes apply to
+    /// * `name` - The name of the voting token
+    /// * `symbol` - The symbol of the voting token
+    fn initialize(e: Env, token: Address, governor: Address, name: String, symbol: String);
+
+    /// Lock underlying tokens in the votes contract until `unlock_ledger`. The voting power of the
+    /// lock decays linearly to zero at the unlock ledger, where a lock of `MAX_LOCK_LEDGERS` has one
+    /// vote per token.
+    ///
+    /// Adds to any existing lock, and can be used with an amount of zero to extend the existing lock.
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to deposit for
+    /// * `amount` - The amount of underlying tokens to deposit
+    /// * `unlock_ledger` - The ledger the tokens unlock at. This is rounded down to the start of its
+    ///                     lock period.
+    ///
+    /// ### Panics
+    /// If the unlock ledger is not after the current ledger, is more than `MAX_LOCK_LEDGERS` away,
+    /// or is before the existing lock's unlock ledger
+    fn deposit(e: Env, from: Address, amount: i128, unlock_ledger: u32);
+
+    /// Withdraw all underlying tokens from an expired lock
+    ///
+    /// Returns the amount of underlying tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account to withdraw for
+    ///
+    /// ### Panics
+    /// If the lock has not expired
+    fn withdraw(e: Env, from: Address) -> i128;
+
+    /// Get the lock of an account
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    fn get_lock(e: Env, account: Address) -> Lock;
+}
+
+#[cfg(not(any(feature = "bonding", feature = "vote-escrow", feature = "nft")))]
 pub trait Admin {
     /// Setup the votes contract
     ///
@@ -140,6 +537,79 @@ pub trait Admin {
     fn admin(e: Env) -> Address;
 }
 
+#[cfg(feature = "nft")]
+pub trait Nft {
+    /// Setup the NFT votes contract
+    ///
+    /// ### Arguments
+    /// * `collection` - The address of the NFT collection contract
+    /// * `governor`- The address of the Governor contract the votes apply to
+    /// * `name` - The name of the voting token
+    /// * `symbol` - The symbol of the voting token
+    fn initialize(e: Env, collection: Address, governor: Address, name: String, symbol: String);
+
+    /// Deposit an NFT from the collection into the votes contract. The account receives one
+    /// voting unit for each NFT deposited.
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account depositing the NFT
+    /// * `token_id` - The id of the NFT
+    fn deposit(e: Env, from: Address, token_id: u32);
+
+    /// Withdraw an NFT from the votes contract, removing its voting unit
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the account that deposited the NFT
+    /// * `token_id` - The id of the NFT
+    ///
+    /// ### Panics
+    /// If the NFT was not deposited by the account
+    fn withdraw(e: Env, from: Address, token_id: u32);
+
+    /// Get the account that deposited an NFT, or None if the NFT is not deposited
+    ///
+    /// ### Arguments
+    /// * `token_id` - The id of the NFT
+    fn get_owner(e: Env, token_id: u32) -> Option<Address>;
+
+    /// Get the address of the NFT collection contract
+    fn collection(e: Env) -> Address;
+}
+
+#[cfg(feature = "soulbound")]
+pub trait Soulbound {
+    /// (Admin only) Set the number of ledgers minted reputation lasts. Each mint to an account
+    /// resets the account's expiration to the current ledger plus the period. A period of 0
+    /// disables expiry for future mints.
+    ///
+    /// ### Arguments
+    /// * `period` - The number of ledgers reputation lasts after it is minted
+    fn set_expiry_period(e: Env, period: u32);
+
+    /// Get the number of ledgers minted reputation lasts, or 0 if it does not expire
+    fn get_expiry_period(e: Env) -> u32;
+
+    /// Get the ledger an account's reputation expires at, or 0 if it does not expire
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    fn get_expiration(e: Env, account: Address) -> u32;
+
+    /// Burn the balance of an account whose reputation has expired. Can be called by anyone.
+    /// Expired reputation keeps its voting power until this is called, so a keeper should call it
+    /// once an account's reputation expires. A balance locked by a vote cannot be burned until the
+    /// lock expires, so a voter's weight is not removed while a proposal they voted on is active.
+    ///
+    /// Returns the amount of tokens burned
+    ///
+    /// ### Arguments
+    /// * `account` - The address of the account
+    ///
+    /// ### Panics
+    /// If the account's reputation does not expire or has not expired yet, or the balance is locked
+    fn expire(e: Env, account: Address) -> i128;
+}
+
 #[cfg(not(feature = "sep-0041"))]
 pub trait TokenData {
     /// Returns the balance of `id`.
@@ -7,6 +7,12 @@ use crate::{
     storage,
 };
 
+#[cfg(feature = "extended-delegation")]
+use crate::{
+    checkpoints::add_abstain_supply_checkpoint, constants::BPS_SCALAR,
+    delegation::get_delegate_split,
+};
+
 /// Move voting units from one address to another
 pub fn move_voting_units(
     e: &Env,
@@ -57,3 +63,140 @@ pub fn move_voting_units(
         }
     }
 }
+
+/// Change the supply held by abstaining accounts and checkpoint the previous supply
+///
+/// ### Arguments
+/// * `amount` - The amount to add to the abstain supply, or remove if negative
+#[cfg(feature = "extended-delegation")]
+pub fn move_abstain_supply(e: &Env, vote_ledgers: &Vec<u32>, amount: i128) {
+    if amount != 0 {
+        let abstain_checkpoint = storage::get_abstain_supply(e);
+        let (_, abstain_supply) = abstain_checkpoint.to_checkpoint_data();
+        storage::set_abstain_supply(
+            e,
+            &u128::from_checkpoint_data(e, e.ledger().sequence(), abstain_supply + amount),
+        );
+        add_abstain_supply_checkpoint(e, vote_ledgers, abstain_checkpoint);
+    }
+}
+
+/// Move voting units for a change in the balance of one or two accounts. If neither account splits
+/// their votes, the units are moved directly between the accounts' delegates.
+///
+/// ### Arguments
+/// * `from` - The account whose balance is decreasing, and its balance before the change
+/// * `to` - The account whose balance is increasing, and its balance before the change
+/// * `amount` - The amount the balances are changing by
+#[cfg(feature = "extended-delegation")]
+pub fn move_balance_units(
+    e: &Env,
+    vote_ledgers: &Vec<u32>,
+    from: Option<(&Address, i128)>,
+    to: Option<(&Address, i128)>,
+    amount: i128,
+) {
+    let from_split = from.and_then(|(account, _)| storage::get_delegate_split(e, account));
+    let to_split = to.and_then(|(account, _)| storage::get_delegate_split(e, account));
+    if from_split.is_none() && to_split.is_none() {
+        let from_delegate = from.map(|(account, _)| storage::get_delegate(e, account));
+        let to_delegate = to.map(|(account, _)| storage::get_delegate(e, account));
+        move_voting_units(
+            e,
+            vote_ledgers,
+            from_delegate.as_ref(),
+            to_delegate.as_ref(),
+            amount,
+        );
+        return;
+    }
+
+    if let (Some((from, _)), Some((to, _))) = (from, to) {
+        if from == to {
+            return;
+        }
+    }
+    if let Some((account, balance)) = from {
+        let split = from_split.unwrap_or_else(|| get_delegate_split(e, account));
+        if split.is_empty() {
+            move_abstain_supply(e, vote_ledgers, -amount);
+        }
+        move_split_units(e, vote_ledgers, &split, balance, balance - amount);
+    }
+    if let Some((account, balance)) = to {
+        let split = to_split.unwrap_or_else(|| get_delegate_split(e, account));
+        if split.is_empty() {
+            move_abstain_supply(e, vote_ledgers, amount);
+        }
+        move_split_units(e, vote_ledgers, &split, balance, balance + amount);
+    }
+}
+
+/// Move voting units for a change in the balance of one or two accounts between the accounts'
+/// delegates
+///
+/// ### Arguments
+/// * `from` - The account whose balance is decreasing, and its balance before the change
+/// * `to` - The account whose balance is increasing, and its balance before the change
+/// * `amount` - The amount the balances are changing by
+#[cfg(not(feature = "extended-delegation"))]
+pub fn move_balance_units(
+    e: &Env,
+    vote_ledgers: &Vec<u32>,
+    from: Option<(&Address, i128)>,
+    to: Option<(&Address, i128)>,
+    amount: i128,
+) {
+    let from_delegate = from.map(|(account, _)| storage::get_delegate(e, account));
+    let to_delegate = to.map(|(account, _)| storage::get_delegate(e, account));
+    move_voting_units(
+        e,
+        vote_ledgers,
+        from_delegate.as_ref(),
+        to_delegate.as_ref(),
+        amount,
+    );
+}
+
+/// Move voting units for a change in balance of an account that splits its votes between
+/// delegatees. Each delegatee receives their share of the balance rounded down, and the
+/// last delegatee receives any remainder. If the account is abstaining, the split is empty
+/// and no voting units are moved.
+///
+/// ### Arguments
+/// * `split` - The delegatees and their share in basis points
+/// * `prev_balance` - The balance of the account before the change
+/// * `new_balance` - The balance of the account after the change
+#[cfg(feature = "extended-delegation")]
+pub fn move_split_units(
+    e: &Env,
+    vote_ledgers: &Vec<u32>,
+    split: &Vec<(Address, u32)>,
+    prev_balance: i128,
+    new_balance: i128,
+) {
+    if split.is_empty() {
+        return;
+    }
+    let mut prev_remaining = prev_balance;
+    let mut new_remaining = new_balance;
+    let last_index = split.len() - 1;
+    for (index, (delegatee, bps)) in split.iter().enumerate() {
+        let (prev_units, new_units) = if index as u32 == last_index {
+            (prev_remaining, new_remaining)
+        } else {
+            (
+                prev_balance * bps as i128 / BPS_SCALAR as i128,
+                new_balance * bps as i128 / BPS_SCALAR as i128,
+            )
+        };
+        prev_remaining -= prev_units;
+        new_remaining -= new_units;
+        let (from, to) = if new_units < prev_units {
+            (Some(&delegatee), None)
+        } else {
+            (None, Some(&delegatee))
+        };
+        move_voting_units(e, vote_ledgers, from, to, (new_units - prev_units).abs());
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "balance-checkpoints")]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, LedgerInfo},
+    token::StellarAssetClient,
+    Address, Env, Error, String,
+};
+use soroban_votes::{TokenVotes, TokenVotesClient};
+
+const ONE_DAY_LEDGERS: u32 = 17280;
+
+fn jump(e: &Env, ledgers: u32) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp().saturating_add(ledgers as u64 * 5),
+        protocol_version: 20,
+        sequence_number: e.ledger().sequence().saturating_add(ledgers),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 10 * ONE_DAY_LEDGERS,
+        min_persistent_entry_ttl: 10 * ONE_DAY_LEDGERS,
+        max_entry_ttl: 365 * ONE_DAY_LEDGERS,
+    });
+}
+
+#[test]
+fn test_get_past_balance() {
+    let e = Env::default();
+    e.mock_all_auths();
+    jump(&e, 100);
+
+    let bombadil = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let governor = Address::generate(&e);
+
+    let token_id = e.register_stellar_asset_contract(bombadil);
+    let token_client = StellarAssetClient::new(&e, &token_id);
+    let votes_id = e.register_contract(None, TokenVotes {});
+    let votes_client = TokenVotesClient::new(&e, &votes_id);
+    votes_client.initialize(
+        &token_id,
+        &governor,
+        &String::from_str(&e, "Test Token"),
+        &String::from_str(&e, "TEST"),
+    );
+
+    let cur_ledger = e.ledger().sequence();
+    votes_client.set_vote_sequence(&(cur_ledger + 99));
+    votes_client.set_vote_sequence(&(cur_ledger + 199));
+
+    let initial_balance = 100_000 * 10i128.pow(7);
+    token_client.mint(&samwise, &initial_balance);
+
+    let deposit_amount = 1_000 * 10i128.pow(7);
+    votes_client.deposit(&samwise, &deposit_amount);
+
+    jump(&e, 100);
+
+    // delegating moves votes but not the balance
+    votes_client.delegate(&samwise, &frodo);
+    let withdraw_amount = 100 * 10i128.pow(7);
+    votes_client.withdraw(&samwise, &withdraw_amount);
+
+    jump(&e, 50);
+
+    // multiple changes between vote ledgers only keep the last
+    votes_client.deposit(&samwise, &withdraw_amount);
+    jump(&e, 10);
+    votes_client.deposit(&samwise, &withdraw_amount);
+
+    jump(&e, 100);
+
+    votes_client.withdraw(&samwise, &deposit_amount);
+    let last_ledger = e.ledger().sequence();
+
+    jump(&e, 1);
+
+    assert_eq!(
+        votes_client.get_past_balance(&samwise, &(cur_ledger + 99)),
+        deposit_amount
+    );
+    assert_eq!(
+        votes_client.get_past_votes(&samwise, &(cur_ledger + 199)),
+        0
+    );
+    assert_eq!(
+        votes_client.get_past_balance(&samwise, &(cur_ledger + 199)),
+        deposit_amount + withdraw_amount
+    );
+    assert_eq!(
+        votes_client.get_past_balance(&samwise, &last_ledger),
+        withdraw_amount
+    );
+    assert_eq!(votes_client.balance(&samwise), withdraw_amount);
+    assert_eq!(
+        votes_client.get_past_balance(&frodo, &(cur_ledger + 199)),
+        0
+    );
+
+    // sequence must be closed
+    let result = votes_client.try_get_past_balance(&samwise, &e.ledger().sequence());
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+}